@@ -8,33 +8,37 @@ use anyhow::{bail, Error, Result as AnyResult};
 use cosmwasm_std::{
     coin, coins,
     testing::{MockApi, MockStorage},
-    to_json_binary, Addr, BankMsg, BlockInfo, Coin, Decimal, Empty, Int64, Querier, StdError,
-    StdResult, Storage,
+    to_json_binary, Addr, BankMsg, BlockInfo, Coin, Decimal, Empty, Int64, Order, Querier,
+    StdError, StdResult, Storage,
 };
 use cosmwasm_std::{Int128, SignedDecimal, Uint128};
 use cw_multi_test::{App, AppResponse, BankKeeper, BankSudo, BasicAppBuilder, Module, WasmKeeper};
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use elys_bindings::{
+    amm,
     msg_resp::{
-        AmmSwapByDenomResponse, AmmSwapExactAmountInResp, MsgResponse, PerpetualCloseResponse,
-        PerpetualOpenResponse,
+        AmmSwapByDenomResponse, AmmSwapExactAmountInResp, MsgExitPoolResponse, MsgJoinPoolResponse,
+        MsgResponse, PerpetualCloseResponse, PerpetualOpenResponse,
     },
     query_resp::{
-        AmmSwapEstimationByDenomResponse, AmmSwapEstimationResponse, AuthAddressesResponse,
-        BalanceBorrowed, CoinNeg, Commitments, Entry, EstakingRewardsResponse,
-        LeveragelpIsWhitelistedResponse, LeveragelpParams, LeveragelpParamsResponse,
-        LeveragelpStatusResponse, LeveragelpWhitelistResponse, MasterchefUserPendingRewardResponse,
-        OracleAssetInfoResponse, PerpetualGetPositionsForAddressResponse, PerpetualMtpResponse,
+        AmmGetPoolResponse, AmmSwapEstimationByDenomResponse, AmmSwapEstimationResponse,
+        AuthAddressesResponse, BalanceBorrowed, CoinNeg, Commitments, DelegationDelegatorReward,
+        Entry, EstakingRewardsResponse, LeveragelpIsWhitelistedResponse, LeveragelpParams,
+        LeveragelpParamsResponse, LeveragelpPositionsResponseRaw, LeveragelpStatusResponse,
+        LeveragelpWhitelistResponse, MasterchefUserPendingRewardData,
+        MasterchefUserPendingRewardResponse, OracleAssetInfoResponse,
+        PerpetualGetPositionsForAddressResponse, PerpetualMtpResponse,
         PerpetualOpenEstimationRawResponse, PerpetualParamsRaw, PerpetualParamsResponseRaw,
-        PerpetualQueryPositionsResponse, PoolApr, QueryAprResponse, QueryAprsResponse,
-        QueryGetEntryAllResponse, QueryGetEntryResponse, QueryGetPriceResponse,
-        QueryPoolAprsResponse, QueryShowCommitmentsResponse, QueryStableStakeAprResponse,
-        QueryStakedPositionResponse, QueryUnstakedPositionResponse, QueryVestingInfoResponse,
-        StableStakeParamsData, StableStakeParamsResp, TierCalculateDiscountResponse,
+        PerpetualQueryPositionsResponse, PoolApr, PoolResp, QueryAprResponse, QueryAprsResponse,
+        QueryEarnPoolResponse, QueryGetEntryAllResponse, QueryGetEntryResponse,
+        QueryGetPriceResponse, QueryPoolAprsResponse, QueryShowCommitmentsResponse,
+        QueryStableStakeAprResponse, QueryStakedPositionResponse, QueryUnstakedPositionResponse,
+        QueryVestingInfoResponse, StableStakeParamsData, StableStakeParamsResp,
+        TierCalculateDiscountResponse, Validator,
     },
     types::{
-        BalanceAvailable, Mtp, MtpAndPrice, OracleAssetInfo, PageResponse, Price,
-        SwapAmountInRoute, SwapAmountOutRoute,
+        AmmPool, BalanceAvailable, Mtp, MtpAndPrice, OracleAssetInfo, PageResponse, PoolExtraInfo,
+        Price, SwapAmountInRoute, SwapAmountOutRoute,
     },
     ElysMsg, ElysQuery,
 };
@@ -47,6 +51,106 @@ pub const BLOCK_TIME: u64 = 5;
 pub const PERPETUAL_OPENED_POSITION: Item<Vec<Mtp>> = Item::new("perpetual_opened_position");
 pub const LAST_MODULE_USED: Item<Option<String>> = Item::new("last_module_used");
 pub const ACCOUNT: Item<Vec<String>> = Item::new("account");
+pub const ESTAKING_REWARDS: Map<&str, Vec<DelegationDelegatorReward>> =
+    Map::new("estaking_rewards");
+pub const MASTERCHEF_REWARDS: Map<&str, Vec<MasterchefUserPendingRewardData>> =
+    Map::new("masterchef_rewards");
+pub const AMM_POOLS: Map<u64, AmmPool> = Map::new("amm_pools");
+
+// sums the rewards per denom
+fn total_rewards<'a>(rewards: impl Iterator<Item = &'a Vec<Coin>>) -> StdResult<Vec<Coin>> {
+    let mut total = cosmwasm_std::Coins::default();
+    for coin in rewards.flatten() {
+        total.add(coin.clone())?;
+    }
+    Ok(total.into_vec())
+}
+
+// the discount of the user applies to the swap fee of the pool
+fn with_discount(mut pool: AmmPool, discount: Decimal) -> AmmPool {
+    pool.pool_params.swap_fee *= Decimal::one() - discount.min(Decimal::one());
+    pool
+}
+
+fn pool_balance(pool: &AmmPool, denom: &str) -> Uint128 {
+    pool.pool_assets
+        .iter()
+        .find(|asset| asset.token.denom == denom)
+        .map(|asset| asset.token.amount)
+        .unwrap_or_default()
+}
+
+// first registered pool holding both denoms
+fn amm_pool_of_pair(
+    storage: &dyn Storage,
+    denom_in: &str,
+    denom_out: &str,
+) -> StdResult<Option<AmmPool>> {
+    for pool in AMM_POOLS.range(storage, None, None, Order::Ascending) {
+        let (_, pool) = pool?;
+        if !pool_balance(&pool, denom_in).is_zero() && !pool_balance(&pool, denom_out).is_zero() {
+            return Ok(Some(pool));
+        }
+    }
+    Ok(None)
+}
+
+fn oracle_price(prices: &[Price], denom: &str) -> StdResult<Decimal> {
+    prices
+        .iter()
+        .find(|price| price.asset == denom)
+        .map(|price| price.price)
+        .ok_or_else(|| StdError::not_found(format!("price of {}", denom)))
+}
+
+// estimation of a swap along `routes` with `amm::math`, None when a pool of
+// the routes is not registered
+fn amm_swap_estimation(
+    storage: &dyn Storage,
+    prices: &[Price],
+    routes: &[SwapAmountInRoute],
+    token_in: &Coin,
+    discount: Decimal,
+) -> StdResult<Option<AmmSwapEstimationResponse>> {
+    let mut pools = vec![];
+    for route in routes {
+        match AMM_POOLS.may_load(storage, route.pool_id)? {
+            Some(pool) => pools.push(with_discount(pool, discount)),
+            None => return Ok(None),
+        }
+    }
+
+    let mut token_out = token_in.clone();
+    let mut spot_price = Decimal::one();
+    let mut swap_fee = Decimal::zero();
+    let mut weight_balance_ratio = Decimal::zero();
+    let mut slippage = Decimal::zero();
+    let mut available_liquidity = token_in.clone();
+    for (route, pool) in routes.iter().zip(pools.iter()) {
+        let estimation =
+            amm::math::swap_out_given_in(pool, prices, &token_out, &route.token_out_denom)?;
+        spot_price = spot_price.checked_mul(estimation.spot_price)?;
+        swap_fee = swap_fee.checked_add(estimation.swap_fee)?;
+        weight_balance_ratio = weight_balance_ratio.checked_add(estimation.weight_breaking_fee)?;
+        slippage = slippage.checked_add(estimation.slippage)?;
+        available_liquidity = coin(
+            pool_balance(pool, &route.token_out_denom).u128(),
+            &route.token_out_denom,
+        );
+        token_out = estimation.token;
+    }
+
+    Ok(Some(AmmSwapEstimationResponse {
+        spot_price,
+        token_out,
+        swap_fee: SignedDecimal::try_from(swap_fee)
+            .map_err(|e| StdError::generic_err(e.to_string()))?,
+        discount,
+        available_liquidity,
+        slippage,
+        weight_balance_ratio,
+    }))
+}
 
 pub struct ElysModule {}
 
@@ -95,6 +199,34 @@ impl ElysModule {
     pub fn get_balance(&self, store: &mut dyn Storage, mtps: &Vec<Mtp>) -> StdResult<()> {
         PERPETUAL_OPENED_POSITION.save(store, mtps)
     }
+
+    /// Pending estaking rewards of `address`, minted to it once withdrawn.
+    pub fn set_estaking_rewards(
+        &self,
+        store: &mut dyn Storage,
+        address: &str,
+        rewards: Vec<DelegationDelegatorReward>,
+    ) -> StdResult<()> {
+        ESTAKING_REWARDS.save(store, address, &rewards)
+    }
+
+    /// Pending masterchef rewards of `address`, minted to it once claimed.
+    pub fn set_masterchef_rewards(
+        &self,
+        store: &mut dyn Storage,
+        address: &str,
+        rewards: Vec<MasterchefUserPendingRewardData>,
+    ) -> StdResult<()> {
+        MASTERCHEF_REWARDS.save(store, address, &rewards)
+    }
+
+    /// Pools of the amm module, their estimations use `elys_bindings::amm::math`.
+    pub fn set_amm_pools(&self, store: &mut dyn Storage, pools: &Vec<AmmPool>) -> StdResult<()> {
+        for pool in pools {
+            AMM_POOLS.save(store, pool.pool_id, pool)?;
+        }
+        Ok(())
+    }
 }
 
 impl Module for ElysModule {
@@ -126,9 +258,12 @@ impl Module for ElysModule {
                 Ok(to_json_binary(&resp)?)
             }
 
-            ElysQuery::EstakingRewards { .. } => {
-                // TODO: remove default instead proper mock
-                Ok(to_json_binary(&EstakingRewardsResponse::default())?)
+            ElysQuery::EstakingRewards { address } => {
+                let rewards = ESTAKING_REWARDS
+                    .may_load(storage, &address)?
+                    .unwrap_or_default();
+                let total = total_rewards(rewards.iter().map(|reward| &reward.reward))?;
+                Ok(to_json_binary(&EstakingRewardsResponse { rewards, total })?)
             }
             ElysQuery::LeveragelpQueryPositions { .. } => todo!("LeveragelpQueryPositions"),
             ElysQuery::LeveragelpQueryPositionsByPool { .. } => {
@@ -144,7 +279,11 @@ impl Module for ElysModule {
             }
 
             ElysQuery::LeveragelpQueryPositionsForAddress { .. } => {
-                todo!("LeveragelpQueryPositionsForAddress")
+                // no leveragelp position is opened in the mock
+                Ok(to_json_binary(&LeveragelpPositionsResponseRaw {
+                    positions: None,
+                    pagination: None,
+                })?)
             }
 
             ElysQuery::TierCalculateDiscount { .. } => {
@@ -176,9 +315,49 @@ impl Module for ElysModule {
             ElysQuery::LeveragelpCloseEstimation { .. } => todo!("LeveragelpCloseEstimation"),
             ElysQuery::LeveragelpOpenEstimation { .. } => todo!("LeveragelpOpenEstimation"),
 
-            ElysQuery::AmmEarnMiningPoolAll { .. } => todo!("AmmEarnMiningPoolAll"),
-            ElysQuery::AmmJoinPoolEstimation { .. } => todo!("AmmJoinPoolEstimation"),
-            ElysQuery::AmmExitPoolEstimation { .. } => todo!("AmmJoinPoolEstimation"),
+            ElysQuery::AmmEarnMiningPoolAll { pool_ids, .. } => {
+                // Every pool is worth 1_000_000 USD for 1_000_000 shares, so a share is worth 1 USD.
+                let resp = QueryEarnPoolResponse {
+                    pools: Some(
+                        pool_ids
+                            .unwrap_or_default()
+                            .iter()
+                            .map(|id| PoolResp {
+                                pool_id: *id as i64,
+                                tvl: Decimal::from_atomics(Uint128::new(1_000_000), 0).unwrap(),
+                                total_shares: coin(
+                                    1_000_000_000_000_000_000_000_000,
+                                    format!("amm/pool/{}", id),
+                                ),
+                                reward_coins: vec![],
+                                ..Default::default()
+                            })
+                            .collect(),
+                    ),
+                };
+                Ok(to_json_binary(&resp)?)
+            }
+            ElysQuery::AmmJoinPoolEstimation {
+                pool_id,
+                amounts_in,
+            } => {
+                let pool = AMM_POOLS.load(storage, pool_id)?;
+                Ok(to_json_binary(&amm::math::join_pool_estimation(
+                    &pool,
+                    &amounts_in,
+                )?)?)
+            }
+            ElysQuery::AmmExitPoolEstimation {
+                pool_id,
+                share_amount_in,
+                ..
+            } => {
+                let pool = AMM_POOLS.load(storage, pool_id)?;
+                Ok(to_json_binary(&amm::math::exit_pool_estimation(
+                    &pool,
+                    share_amount_in,
+                )?)?)
+            }
             ElysQuery::CommitmentAllValidators { .. } => todo!("CommitmentAllValidators"),
             ElysQuery::CommitmentDelegations { .. } => todo!("CommitmentDelegations"),
             ElysQuery::CommitmentDelegatorValidators { .. } => {
@@ -230,16 +409,16 @@ impl Module for ElysModule {
                 token_in,
                 discount,
             } => {
-                let prices = &self.get_all_price(storage)?;
-                let price_in = prices
-                    .iter()
-                    .find(|price| price.asset == token_in.denom)
-                    .unwrap();
-                let price_out = prices
-                    .iter()
-                    .find(|price| price.asset == routes[0].token_out_denom)
-                    .unwrap();
-                let spot_price = price_in.price / price_out.price;
+                let prices = PRICES.may_load(storage)?.unwrap_or_default();
+                if let Some(resp) =
+                    amm_swap_estimation(storage, &prices, &routes, &token_in, discount)?
+                {
+                    return Ok(to_json_binary(&resp)?);
+                }
+
+                // pools that are not registered swap at the oracle price
+                let spot_price = oracle_price(&prices, &token_in.denom)?
+                    / oracle_price(&prices, &routes[0].token_out_denom)?;
                 let token_out_amount =
                     (Decimal::from_atomics(token_in.amount, spot_price.decimal_places())?
                         * spot_price)
@@ -256,7 +435,16 @@ impl Module for ElysModule {
                     weight_balance_ratio: Decimal::zero(),
                 })?)
             }
-            ElysQuery::AmmPool { .. } => todo!("not implemented"),
+            ElysQuery::AmmPool { pool_id } => {
+                let pool = AMM_POOLS.load(storage, pool_id)?;
+                Ok(to_json_binary(&AmmGetPoolResponse {
+                    pool,
+                    extra_info: PoolExtraInfo {
+                        tvl: Decimal::zero(),
+                        lp_token_price: Decimal::zero(),
+                    },
+                })?)
+            }
             ElysQuery::AmmPoolAll { .. } => todo!("not implemented"),
             ElysQuery::AmmSwapEstimationByDenom {
                 amount,
@@ -264,14 +452,52 @@ impl Module for ElysModule {
                 denom_out,
                 discount,
             } => {
-                let prices = &self.get_all_price(storage)?;
-                let price_in = prices.iter().find(|price| price.asset == denom_in).unwrap();
-                let price_out = prices
-                    .iter()
-                    .find(|price| price.asset == denom_out)
-                    .unwrap();
-                let spot_price = price_in.price / price_out.price;
+                let prices = PRICES.may_load(storage)?.unwrap_or_default();
+
+                if let Some(pool) = amm_pool_of_pair(storage, &denom_in, &denom_out)? {
+                    let pool = with_discount(pool, discount);
+                    let (estimation, in_route, out_route) = if amount.denom == denom_in {
+                        (
+                            amm::math::swap_out_given_in(&pool, &prices, &amount, &denom_out)?,
+                            Some(vec![SwapAmountInRoute {
+                                pool_id: pool.pool_id,
+                                token_out_denom: denom_out.clone(),
+                            }]),
+                            None,
+                        )
+                    } else {
+                        (
+                            amm::math::swap_in_given_out(&pool, &prices, &amount, &denom_in)?,
+                            None,
+                            Some(vec![SwapAmountOutRoute {
+                                pool_id: pool.pool_id,
+                                token_in_denom: denom_in.clone(),
+                            }]),
+                        )
+                    };
+
+                    return Ok(to_json_binary(&AmmSwapEstimationByDenomResponse {
+                        in_route,
+                        out_route,
+                        spot_price: estimation.spot_price,
+                        amount: estimation.token,
+                        discount: SignedDecimal::try_from(discount)?,
+                        swap_fee: SignedDecimal::try_from(estimation.swap_fee)?,
+                        available_liquidity: coin(
+                            pool_balance(&pool, &denom_out).u128(),
+                            denom_out,
+                        ),
+                        weight_balance_ratio: SignedDecimal::try_from(
+                            estimation.weight_breaking_fee,
+                        )?,
+                        price_impact: SignedDecimal::zero(),
+                        slippage: estimation.slippage,
+                    })?);
+                }
 
+                // pairs without a registered pool swap at the oracle price
+                let spot_price =
+                    oracle_price(&prices, &denom_in)? / oracle_price(&prices, &denom_out)?;
                 let token_estimation = if amount.denom == denom_in {
                     coin(
                         (Decimal::from_atomics(amount.amount, spot_price.decimal_places())?
@@ -613,11 +839,11 @@ impl Module for ElysModule {
                 let price_in = prices
                     .iter()
                     .find(|price| price.asset == token_in.denom)
-                    .unwrap();
+                    .ok_or_else(|| StdError::not_found(format!("price of {}", token_in.denom)))?;
                 let price_out = prices
                     .iter()
                     .find(|price| price.asset == "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string())
-                    .unwrap();
+                    .ok_or_else(|| StdError::not_found("price of usdc"))?;
                 let spot_price = price_in.price / price_out.price;
 
                 let resp = spot_price;
@@ -661,11 +887,15 @@ impl Module for ElysModule {
             }
             ElysQuery::MasterchefParams {} => todo!("MasterchefParams"),
             ElysQuery::MasterchefPoolInfo { .. } => todo!("MasterchefPool"),
-            ElysQuery::MasterchefUserPendingReward { .. } => {
-                // TODO: remove default instead proper mock
-                Ok(to_json_binary(
-                    &MasterchefUserPendingRewardResponse::default(),
-                )?)
+            ElysQuery::MasterchefUserPendingReward { user } => {
+                let rewards = MASTERCHEF_REWARDS
+                    .may_load(storage, &user)?
+                    .unwrap_or_default();
+                let total_rewards = total_rewards(rewards.iter().map(|reward| &reward.reward))?;
+                Ok(to_json_binary(&MasterchefUserPendingRewardResponse {
+                    rewards,
+                    total_rewards,
+                })?)
             }
             ElysQuery::MasterchefPoolAprs { pool_ids } => {
                 let resp = QueryPoolAprsResponse {
@@ -716,10 +946,68 @@ impl Module for ElysModule {
         QueryC: cosmwasm_std::CustomQuery + serde::de::DeserializeOwned + 'static,
     {
         match msg {
-            ElysMsg::EstakingWithdrawElysStakingRewards { .. } => {
-                todo!("EstakingWithdrawElysStakingRewards")
+            ElysMsg::EstakingWithdrawElysStakingRewards { delegator_address } => {
+                LAST_MODULE_USED.save(
+                    storage,
+                    &Some("EstakingWithdrawElysStakingRewards".to_string()),
+                )?;
+                let eden_validators = [
+                    Validator::Eden.to_string(),
+                    Validator::EdenBoost.to_string(),
+                ];
+                let (withdrawn, left): (Vec<_>, Vec<_>) = ESTAKING_REWARDS
+                    .may_load(storage, &delegator_address)?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .partition(|reward| !eden_validators.contains(&reward.validator_address));
+                ESTAKING_REWARDS.save(storage, &delegator_address, &left)?;
+
+                let amount = total_rewards(withdrawn.iter().map(|reward| &reward.reward))?;
+                if !amount.is_empty() {
+                    let mint = BankSudo::Mint {
+                        to_address: delegator_address,
+                        amount,
+                    };
+                    router.sudo(api, storage, block, mint.into())?;
+                }
+
+                let data = to_json_binary(&MsgResponse {
+                    result: "Ok".to_string(),
+                })?;
+                Ok(AppResponse {
+                    events: vec![],
+                    data: Some(data),
+                })
+            }
+            ElysMsg::EstakingWithdrawReward {
+                validator_address,
+                delegator_address,
+            } => {
+                LAST_MODULE_USED.save(storage, &Some("EstakingWithdrawReward".to_string()))?;
+                let (withdrawn, left): (Vec<_>, Vec<_>) = ESTAKING_REWARDS
+                    .may_load(storage, &delegator_address)?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .partition(|reward| reward.validator_address == validator_address);
+                ESTAKING_REWARDS.save(storage, &delegator_address, &left)?;
+
+                let amount = total_rewards(withdrawn.iter().map(|reward| &reward.reward))?;
+                if !amount.is_empty() {
+                    let mint = BankSudo::Mint {
+                        to_address: delegator_address,
+                        amount,
+                    };
+                    router.sudo(api, storage, block, mint.into())?;
+                }
+
+                let data = to_json_binary(&MsgResponse {
+                    result: "Ok".to_string(),
+                })?;
+                Ok(AppResponse {
+                    events: vec![],
+                    data: Some(data),
+                })
             }
-            ElysMsg::EstakingWithdrawReward { .. } => todo!("EstakingWithdrawReward"),
             ElysMsg::AmmSwapExactAmountIn {
                 sender,
                 routes,
@@ -904,6 +1192,49 @@ impl Module for ElysModule {
                 PERPETUAL_OPENED_POSITION.save(storage, &mtps)?;
                 Ok(resp)
             }
+            ElysMsg::PerpetualUpdateTakeProfitPrice {
+                id, price, owner, ..
+            } => {
+                LAST_MODULE_USED
+                    .save(storage, &Some("PerpetualUpdateTakeProfitPrice".to_string()))?;
+                let mut mtps: Vec<Mtp> = PERPETUAL_OPENED_POSITION.load(storage)?;
+
+                let mtp = mtps
+                    .iter_mut()
+                    .find(|mtp| mtp.address.as_str() == owner.as_str() && mtp.id == id)
+                    .expect("mtp not found");
+                mtp.take_profit_price = price;
+
+                PERPETUAL_OPENED_POSITION.save(storage, &mtps)?;
+                let data = to_json_binary(&MsgResponse {
+                    result: "Ok".to_string(),
+                })?;
+                Ok(AppResponse {
+                    events: vec![],
+                    data: Some(data),
+                })
+            }
+            ElysMsg::PerpetualUpdateStopLoss {
+                id, price, owner, ..
+            } => {
+                LAST_MODULE_USED.save(storage, &Some("PerpetualUpdateStopLoss".to_string()))?;
+                let mut mtps: Vec<Mtp> = PERPETUAL_OPENED_POSITION.load(storage)?;
+
+                let mtp = mtps
+                    .iter_mut()
+                    .find(|mtp| mtp.address.as_str() == owner.as_str() && mtp.id == id)
+                    .expect("mtp not found");
+                mtp.stop_loss_price = price;
+
+                PERPETUAL_OPENED_POSITION.save(storage, &mtps)?;
+                let data = to_json_binary(&MsgResponse {
+                    result: "Ok".to_string(),
+                })?;
+                Ok(AppResponse {
+                    events: vec![],
+                    data: Some(data),
+                })
+            }
             ElysMsg::AmmSwapByDenom {
                 sender,
                 amount,
@@ -1031,20 +1362,27 @@ impl Module for ElysModule {
                     data: Some(data),
                 })
             }
-            ElysMsg::AmmJoinPool { .. } => {
+            ElysMsg::AmmJoinPool {
+                max_amounts_in,
+                share_amount_out,
+                ..
+            } => {
                 LAST_MODULE_USED.save(storage, &Some("AmmJoin".to_string()))?;
-                let data = to_json_binary(&MsgResponse {
-                    result: "Ok".to_string(),
+                let data = to_json_binary(&MsgJoinPoolResponse {
+                    share_amount_out: Int128::new(share_amount_out.u128() as i128),
+                    token_in: max_amounts_in,
                 })?;
                 Ok(AppResponse {
                     events: vec![],
                     data: Some(data),
                 })
             }
-            ElysMsg::AmmExitPool { .. } => {
+            ElysMsg::AmmExitPool {
+                min_amounts_out, ..
+            } => {
                 LAST_MODULE_USED.save(storage, &Some("AmmExit".to_string()))?;
-                let data = to_json_binary(&MsgResponse {
-                    result: "Ok".to_string(),
+                let data = to_json_binary(&MsgExitPoolResponse {
+                    token_out: min_amounts_out,
                 })?;
                 Ok(AppResponse {
                     events: vec![],
@@ -1092,8 +1430,23 @@ impl Module for ElysModule {
                     data: Some(data),
                 })
             }
-            ElysMsg::MasterchefClaimRewards { .. } => {
+            ElysMsg::MasterchefClaimRewards { sender, pool_ids } => {
                 LAST_MODULE_USED.save(storage, &Some("MasterchefClaimRewards".to_string()))?;
+                let (claimed, left): (Vec<_>, Vec<_>) = MASTERCHEF_REWARDS
+                    .may_load(storage, &sender)?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .partition(|reward| pool_ids.contains(&reward.pool_id));
+                MASTERCHEF_REWARDS.save(storage, &sender, &left)?;
+
+                let amount = total_rewards(claimed.iter().map(|reward| &reward.reward))?;
+                if !amount.is_empty() {
+                    let mint = BankSudo::Mint {
+                        to_address: sender,
+                        amount,
+                    };
+                    router.sudo(api, storage, block, mint.into())?;
+                }
                 let data = to_json_binary(&MsgResponse {
                     result: "Ok".to_string(),
                 })?;
@@ -1103,7 +1456,47 @@ impl Module for ElysModule {
                 })
             }
             ElysMsg::LeveragelpClaimRewards { .. } => todo!(),
-            ElysMsg::PerpetualAddCollateral { .. } => todo!(),
+            ElysMsg::PerpetualAddCollateral {
+                creator,
+                id,
+                amount,
+                owner,
+            } => {
+                LAST_MODULE_USED.save(storage, &Some("PerpetualAddCollateral".to_string()))?;
+                let mut mtps: Vec<Mtp> = PERPETUAL_OPENED_POSITION.load(storage)?;
+
+                let mtp = match mtps
+                    .iter_mut()
+                    .find(|mtp| mtp.address.as_str() == owner.as_str() && mtp.id == id)
+                {
+                    Some(mtp) => mtp,
+                    None => bail!(StdError::not_found("perpetual trading position")),
+                };
+                mtp.collateral = mtp
+                    .collateral
+                    .checked_add(Int128::new(amount.u128() as i128))?;
+
+                let burn_msg = BankMsg::Burn {
+                    amount: coins(amount.u128(), mtp.collateral_asset.clone()),
+                };
+                PERPETUAL_OPENED_POSITION.save(storage, &mtps)?;
+
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    Addr::unchecked(creator),
+                    burn_msg.into(),
+                )?;
+
+                let data = to_json_binary(&MsgResponse {
+                    result: "Ok".to_string(),
+                })?;
+                Ok(AppResponse {
+                    events: vec![],
+                    data: Some(data),
+                })
+            }
         }
     }
 
@@ -1,16 +1,22 @@
 use cosmwasm_std::{
-    coin, coins, Addr, Coin, Decimal, Int128, Int64, SignedDecimal, SignedDecimal256, StdError,
-    Uint128,
+    coin, coins, from_json, Addr, Coin, Decimal, Int128, Int64, SignedDecimal, SignedDecimal256,
+    StdError, Uint128,
 };
 use cw_multi_test::Executor;
+use elys_bindings::amm::math::SwapEstimation;
 use elys_bindings::{
     query_resp::{
-        AmmSwapEstimationResponse, AuthAddressesResponse, OracleAssetInfoResponse,
-        PerpetualMtpResponse, PerpetualQueryPositionsResponse,
+        AmmGetPoolResponse, AmmSwapEstimationByDenomResponse, AmmSwapEstimationResponse,
+        AuthAddressesResponse, OracleAssetInfoResponse, PerpetualMtpResponse,
+        PerpetualQueryPositionsResponse, QueryExitPoolEstimationResponse,
+        QueryJoinPoolEstimationResponse,
+    },
+    types::{
+        AmmPool, Mtp, OracleAssetInfo, PageRequest, PerpetualPosition, Price, SwapAmountInRoute,
     },
-    types::{Mtp, OracleAssetInfo, PageRequest, PerpetualPosition, Price, SwapAmountInRoute},
     ElysMsg, ElysQuery,
 };
+use serde::Deserialize;
 
 use super::multitest::*;
 
@@ -496,3 +502,140 @@ fn auth_account() {
     assert_eq!(resp.addresses[0], wallets[0].0);
     assert_eq!(resp.addresses[1], wallets[1].0);
 }
+
+#[derive(Deserialize)]
+struct SwapCase {
+    kind: String,
+    token: Coin,
+    denom: String,
+    expected: SwapEstimation,
+}
+
+#[derive(Deserialize)]
+struct JoinCase {
+    max_amounts_in: Vec<Coin>,
+    expected: QueryJoinPoolEstimationResponse,
+}
+
+#[derive(Deserialize)]
+struct ExitCase {
+    share_amount_in: Uint128,
+    expected: QueryExitPoolEstimationResponse,
+}
+
+// the synthetic fixtures of `elys_bindings::amm::math`, the mock estimates with
+// the same math
+#[derive(Deserialize)]
+struct PoolFixtures {
+    cases: Vec<PoolFixture>,
+}
+
+#[derive(Deserialize)]
+struct PoolFixture {
+    name: String,
+    pool: AmmPool,
+    prices: Vec<Price>,
+    swaps: Vec<SwapCase>,
+    join: Option<JoinCase>,
+    exit: Option<ExitCase>,
+}
+
+#[test]
+fn amm_pool_estimations() {
+    let PoolFixtures { cases: fixtures } =
+        from_json(include_str!("../../bindings/src/amm/fixtures/pools.json")).unwrap();
+    let pools: Vec<AmmPool> = fixtures
+        .iter()
+        .map(|fixture| fixture.pool.clone())
+        .collect();
+
+    let mut app = ElysApp::new();
+    app.init_modules(|router, _, storage| router.custom.set_amm_pools(storage, &pools))
+        .unwrap();
+
+    for fixture in fixtures {
+        let pool_id = fixture.pool.pool_id;
+        let pool: AmmGetPoolResponse = app
+            .wrap()
+            .query(&ElysQuery::amm_get_pool(pool_id).into())
+            .unwrap();
+        assert_eq!(pool.pool, fixture.pool, "{}", fixture.name);
+
+        if let Some(join) = fixture.join {
+            let estimation: QueryJoinPoolEstimationResponse = app
+                .wrap()
+                .query(&ElysQuery::join_pool_estimation(pool_id, join.max_amounts_in).into())
+                .unwrap();
+            assert_eq!(estimation, join.expected, "{}", fixture.name);
+        }
+        if let Some(exit) = fixture.exit {
+            let estimation: QueryExitPoolEstimationResponse = app
+                .wrap()
+                .query(
+                    &ElysQuery::exit_pool_estimation(pool_id, exit.share_amount_in, "".to_string())
+                        .into(),
+                )
+                .unwrap();
+            assert_eq!(estimation, exit.expected, "{}", fixture.name);
+        }
+    }
+}
+
+#[test]
+fn amm_swap_estimations() {
+    let PoolFixtures { cases: fixtures } =
+        from_json(include_str!("../../bindings/src/amm/fixtures/pools.json")).unwrap();
+
+    for fixture in fixtures {
+        // one pool per app, as the estimation by denom picks the first pool of the pair
+        let mut app = ElysApp::new();
+        app.init_modules(|router, _, storage| {
+            router.custom.set_prices(storage, &fixture.prices)?;
+            router
+                .custom
+                .set_amm_pools(storage, &vec![fixture.pool.clone()])
+        })
+        .unwrap();
+        let pool_id = fixture.pool.pool_id;
+
+        for swap in fixture.swaps {
+            let name = format!("{} {}", fixture.name, swap.kind);
+            let (denom_in, denom_out) = match swap.kind.as_str() {
+                "out_given_in" => {
+                    let estimation: AmmSwapEstimationResponse = app
+                        .wrap()
+                        .query(
+                            &ElysQuery::amm_swap_estimation(
+                                vec![SwapAmountInRoute::new(pool_id, &swap.denom)],
+                                swap.token.clone(),
+                                Decimal::zero(),
+                            )
+                            .into(),
+                        )
+                        .unwrap();
+                    assert_eq!(estimation.token_out, swap.expected.token, "{name}");
+                    assert_eq!(estimation.spot_price, swap.expected.spot_price, "{name}");
+                    (swap.token.denom.clone(), swap.denom.clone())
+                }
+                "in_given_out" => (swap.denom.clone(), swap.token.denom.clone()),
+                // the mock does not know the curve parameters of stable swap pools
+                _ => continue,
+            };
+
+            let estimation: AmmSwapEstimationByDenomResponse = app
+                .wrap()
+                .query(
+                    &ElysQuery::amm_swap_estimation_by_denom(
+                        swap.token.clone(),
+                        denom_in,
+                        denom_out,
+                        Decimal::zero(),
+                    )
+                    .into(),
+                )
+                .unwrap();
+            assert_eq!(estimation.amount, swap.expected.token, "{name}");
+            assert_eq!(estimation.slippage, swap.expected.slippage, "{name}");
+        }
+    }
+}
@@ -0,0 +1,830 @@
+//! Pure AMM pool math, so that contracts and off-chain routers can estimate
+//! swaps, joins and exits from `AmmPool` data without a chain round-trip. It
+//! follows the formulas of the amm module but is only tested against synthetic
+//! fixtures, not against outputs recorded from a chain.
+//! Decimals are truncated at every step like `cosmwasm_std::Decimal` does.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Decimal, SignedDecimal, StdError, StdResult, Uint128, Uint256};
+
+use crate::query_resp::{QueryExitPoolEstimationResponse, QueryJoinPoolEstimationResponse};
+use crate::types::{AmmPool, PoolAsset, Price};
+
+// precision of the series used for fractional exponents
+const POW_PRECISION: Decimal = Decimal::raw(10_000_000_000);
+// the weight breaking fee never takes the whole swap
+const MAX_WEIGHT_BREAKING_FEE: Decimal = Decimal::percent(99);
+const STABLE_SWAP_MAX_ITERATIONS: usize = 255;
+
+fn generic_err(e: impl ToString) -> StdError {
+    StdError::generic_err(e.to_string())
+}
+
+/// Estimated swap: `token` is the amount out for an exact amount in and the
+/// amount in for an exact amount out.
+#[cw_serde]
+pub struct SwapEstimation {
+    pub token: Coin,
+    pub spot_price: Decimal, // amount out for one unit in, before fees and slippage
+    pub swap_fee: Decimal,
+    pub weight_breaking_fee: Decimal,
+    pub slippage: Decimal,
+}
+
+fn abs_diff_with_sign(a: Decimal, b: Decimal) -> (Decimal, bool) {
+    if a >= b {
+        (a - b, false)
+    } else {
+        (b - a, true)
+    }
+}
+
+/// `base` to the power of `exp`, the fractional part of the exponent is
+/// approximated with a binomial series, so `base` must be lower than two.
+pub fn pow(base: Decimal, exp: Decimal) -> StdResult<Decimal> {
+    if base.is_zero() || base >= Decimal::from_atomics(2u128, 0).map_err(generic_err)? {
+        return Err(StdError::generic_err(format!(
+            "pow: base {} must be between 0 and 2",
+            base
+        )));
+    }
+
+    let integer = exp.to_uint_floor();
+    let fractional = exp - Decimal::from_atomics(integer, 0).map_err(generic_err)?;
+    let integer_pow = base
+        .checked_pow(u32::try_from(integer.u128()).map_err(generic_err)?)
+        .map_err(generic_err)?;
+    if fractional.is_zero() {
+        return Ok(integer_pow);
+    }
+
+    Ok(integer_pow.checked_mul(pow_approx(base, fractional)?)?)
+}
+
+fn pow_approx(base: Decimal, exp: Decimal) -> StdResult<Decimal> {
+    let (x, x_negative) = abs_diff_with_sign(base, Decimal::one());
+    let mut term = Decimal::one();
+    let mut sum = Decimal::one();
+    let mut negative = false;
+
+    let mut k = 1u128;
+    while term >= POW_PRECISION {
+        let big_k = Decimal::from_atomics(k, 0).map_err(generic_err)?;
+        let (c, c_negative) = abs_diff_with_sign(exp, big_k - Decimal::one());
+        term = term
+            .checked_mul(c.checked_mul(x)?)?
+            .checked_div(big_k)
+            .map_err(generic_err)?;
+        if term.is_zero() {
+            break;
+        }
+        if x_negative {
+            negative = !negative;
+        }
+        if c_negative {
+            negative = !negative;
+        }
+        sum = if negative {
+            sum.checked_sub(term)?
+        } else {
+            sum.checked_add(term)?
+        };
+        k += 1;
+    }
+
+    Ok(sum)
+}
+
+fn pool_asset<'a>(pool: &'a AmmPool, denom: &str) -> StdResult<&'a PoolAsset> {
+    pool.pool_assets
+        .iter()
+        .find(|asset| asset.token.denom == denom)
+        .ok_or_else(|| StdError::not_found(format!("{} in pool {}", denom, pool.pool_id)))
+}
+
+fn pool_asset_index(pool: &AmmPool, denom: &str) -> StdResult<usize> {
+    pool.pool_assets
+        .iter()
+        .position(|asset| asset.token.denom == denom)
+        .ok_or_else(|| StdError::not_found(format!("{} in pool {}", denom, pool.pool_id)))
+}
+
+fn oracle_price(prices: &[Price], denom: &str) -> StdResult<Decimal> {
+    match prices.iter().find(|price| price.asset == denom) {
+        Some(price) if !price.price.is_zero() => Ok(price.price),
+        _ => Err(StdError::not_found(format!("price of {}", denom))),
+    }
+}
+
+/// Amount out of a balancer swap, `exponent` is the weight in over the weight out.
+fn balancer_out(
+    balance_in: Uint128,
+    balance_out: Uint128,
+    exponent: Decimal,
+    amount_in: Uint128,
+) -> StdResult<Uint128> {
+    let y = Decimal::from_ratio(balance_in, balance_in.checked_add(amount_in)?);
+    let remaining = pow(y, exponent)?;
+    balance_out
+        .checked_mul_floor(Decimal::one() - remaining)
+        .map_err(generic_err)
+}
+
+/// Amount in of a balancer swap, `exponent` is the weight out over the weight in.
+fn balancer_in(
+    balance_in: Uint128,
+    balance_out: Uint128,
+    exponent: Decimal,
+    amount_out: Uint128,
+) -> StdResult<Uint128> {
+    if amount_out >= balance_out {
+        return Err(StdError::generic_err("token out: not enough liquidity"));
+    }
+    let y = Decimal::from_ratio(balance_out, balance_out - amount_out);
+    let growth = pow(y, exponent)?;
+    balance_in
+        .checked_mul_ceil(growth - Decimal::one())
+        .map_err(generic_err)
+}
+
+fn target_weight(pool: &AmmPool, denom: &str) -> StdResult<Decimal> {
+    let total_weight: Uint128 = pool.pool_assets.iter().map(|asset| asset.weight).sum();
+    Ok(Decimal::from_ratio(
+        pool_asset(pool, denom)?.weight,
+        total_weight,
+    ))
+}
+
+/// Share of the pool value held in each asset, in the order of the pool assets.
+fn oracle_weights(
+    pool: &AmmPool,
+    prices: &[Price],
+    balances: &[Uint128],
+) -> StdResult<Vec<Decimal>> {
+    let values = pool
+        .pool_assets
+        .iter()
+        .zip(balances)
+        .map(|(asset, balance)| {
+            Ok(Decimal::from_atomics(*balance, 0)
+                .map_err(generic_err)?
+                .checked_mul(oracle_price(prices, &asset.token.denom)?)?)
+        })
+        .collect::<StdResult<Vec<Decimal>>>()?;
+    let total = values
+        .iter()
+        .try_fold(Decimal::zero(), |total, value| total.checked_add(*value))?;
+    if total.is_zero() {
+        return Err(StdError::generic_err("pool: has no liquidity"));
+    }
+
+    values
+        .into_iter()
+        .map(|value| value.checked_div(total).map_err(generic_err))
+        .collect()
+}
+
+/// Mean distance between the oracle weights and the target weights of the pool.
+fn weight_distance(pool: &AmmPool, weights: &[Decimal]) -> StdResult<Decimal> {
+    let distance = pool.pool_assets.iter().zip(weights).try_fold(
+        Decimal::zero(),
+        |distance, (asset, weight)| {
+            let target = target_weight(pool, &asset.token.denom)?;
+            distance
+                .checked_add(abs_diff_with_sign(*weight, target).0)
+                .map_err(generic_err)
+        },
+    )?;
+    Ok(distance / Decimal::from_atomics(pool.pool_assets.len() as u128, 0).map_err(generic_err)?)
+}
+
+/// Fee charged on swaps that move an oracle pool further from its target
+/// weights: `multiplier * (weight_in * target_out / (weight_out * target_in)) ^ exponent`
+/// with the weights after the swap.
+pub fn weight_breaking_fee(
+    pool: &AmmPool,
+    prices: &[Price],
+    (denom_in, amount_in): (&str, Uint128),
+    (denom_out, amount_out): (&str, Uint128),
+) -> StdResult<Decimal> {
+    let params = &pool.pool_params;
+    if params.weight_breaking_fee_multiplier.is_zero() {
+        return Ok(Decimal::zero());
+    }
+
+    let before: Vec<Uint128> = pool
+        .pool_assets
+        .iter()
+        .map(|asset| asset.token.amount)
+        .collect();
+    let after = pool
+        .pool_assets
+        .iter()
+        .map(|asset| {
+            if asset.token.denom == denom_in {
+                asset
+                    .token
+                    .amount
+                    .checked_add(amount_in)
+                    .map_err(generic_err)
+            } else if asset.token.denom == denom_out {
+                asset
+                    .token
+                    .amount
+                    .checked_sub(amount_out)
+                    .map_err(generic_err)
+            } else {
+                Ok(asset.token.amount)
+            }
+        })
+        .collect::<StdResult<Vec<Uint128>>>()?;
+
+    let weights_before = oracle_weights(pool, prices, &before)?;
+    let weights_after = oracle_weights(pool, prices, &after)?;
+    let distance_after = weight_distance(pool, &weights_after)?;
+    if distance_after <= weight_distance(pool, &weights_before)?
+        || distance_after <= params.threshold_weight_difference
+    {
+        return Ok(Decimal::zero());
+    }
+
+    let weight_in = weights_after[pool_asset_index(pool, denom_in)?];
+    let weight_out = weights_after[pool_asset_index(pool, denom_out)?];
+    if weight_in.is_zero() || weight_out.is_zero() {
+        return Ok(Decimal::zero());
+    }
+
+    let ratio = weight_in
+        .checked_mul(target_weight(pool, denom_out)?)?
+        .checked_div(weight_out.checked_mul(target_weight(pool, denom_in)?)?)
+        .map_err(generic_err)?;
+    let fee = params
+        .weight_breaking_fee_multiplier
+        .checked_mul(pow(ratio, params.weight_breaking_fee_exponent)?)?;
+
+    Ok(fee.min(MAX_WEIGHT_BREAKING_FEE))
+}
+
+fn slippage(expected: Uint128, actual: Uint128) -> Decimal {
+    if expected.is_zero() || actual >= expected {
+        return Decimal::zero();
+    }
+    Decimal::one() - Decimal::from_ratio(actual, expected)
+}
+
+/// Amount of `denom_out` received for `token_in`. Oracle pools price the swap
+/// with `prices` and take their slippage from the external liquidity ratio,
+/// the other pools follow their weights.
+pub fn swap_out_given_in(
+    pool: &AmmPool,
+    prices: &[Price],
+    token_in: &Coin,
+    denom_out: &str,
+) -> StdResult<SwapEstimation> {
+    let asset_in = pool_asset(pool, &token_in.denom)?;
+    let asset_out = pool_asset(pool, denom_out)?;
+    let (balance_in, balance_out) = (asset_in.token.amount, asset_out.token.amount);
+    let swap_fee = pool.pool_params.swap_fee;
+
+    if !pool.pool_params.use_oracle.unwrap_or(false) {
+        let exponent = Decimal::from_ratio(asset_in.weight, asset_out.weight);
+        let spot_price = Decimal::from_ratio(balance_out, balance_in)
+            .checked_mul(exponent)
+            .map_err(generic_err)?;
+        let amount_in = token_in
+            .amount
+            .checked_mul_floor(Decimal::one() - swap_fee)
+            .map_err(generic_err)?;
+        let amount_out = balancer_out(balance_in, balance_out, exponent, amount_in)?;
+        let expected = amount_in
+            .checked_mul_floor(spot_price)
+            .map_err(generic_err)?;
+
+        return Ok(SwapEstimation {
+            token: Coin::new(amount_out.u128(), denom_out),
+            spot_price,
+            swap_fee,
+            weight_breaking_fee: Decimal::zero(),
+            slippage: slippage(expected, amount_out),
+        });
+    }
+
+    let price_in = oracle_price(prices, &token_in.denom)?;
+    let price_out = oracle_price(prices, denom_out)?;
+    let spot_price = price_in.checked_div(price_out).map_err(generic_err)?;
+    let external_liquidity_ratio = pool.pool_params.external_liquidity_ratio;
+    if external_liquidity_ratio.is_zero() {
+        return Err(StdError::generic_err(
+            "pool: external liquidity ratio is zero",
+        ));
+    }
+
+    // the slippage of the pool balances weighted by value, scaled by the liquidity outside the pool
+    let exponent = Decimal::from_ratio(balance_in, balance_out)
+        .checked_mul(spot_price)
+        .map_err(generic_err)?;
+    let resized_in = token_in
+        .amount
+        .checked_div_floor(external_liquidity_ratio)
+        .map_err(generic_err)?;
+    let resized_out = balancer_out(balance_in, balance_out, exponent, resized_in)?;
+    let slippage_amount = resized_in
+        .checked_mul_floor(spot_price)
+        .map_err(generic_err)?
+        .saturating_sub(resized_out)
+        .checked_mul_floor(external_liquidity_ratio)
+        .map_err(generic_err)?;
+
+    let oracle_out = token_in
+        .amount
+        .checked_mul_floor(spot_price)
+        .map_err(generic_err)?;
+    let amount_out = oracle_out.checked_sub(slippage_amount)?;
+    let weight_breaking_fee = weight_breaking_fee(
+        pool,
+        prices,
+        (&token_in.denom, token_in.amount),
+        (denom_out, amount_out),
+    )?;
+    let amount_out = amount_out
+        .checked_mul_floor(Decimal::one() - weight_breaking_fee)
+        .map_err(generic_err)?
+        .checked_mul_floor(Decimal::one() - swap_fee)
+        .map_err(generic_err)?;
+
+    Ok(SwapEstimation {
+        token: Coin::new(amount_out.u128(), denom_out),
+        spot_price,
+        swap_fee,
+        weight_breaking_fee,
+        slippage: slippage(oracle_out, oracle_out - slippage_amount),
+    })
+}
+
+/// Amount of `denom_in` needed to receive `token_out`, see `swap_out_given_in`.
+pub fn swap_in_given_out(
+    pool: &AmmPool,
+    prices: &[Price],
+    token_out: &Coin,
+    denom_in: &str,
+) -> StdResult<SwapEstimation> {
+    let asset_in = pool_asset(pool, denom_in)?;
+    let asset_out = pool_asset(pool, &token_out.denom)?;
+    let (balance_in, balance_out) = (asset_in.token.amount, asset_out.token.amount);
+    let swap_fee = pool.pool_params.swap_fee;
+
+    if !pool.pool_params.use_oracle.unwrap_or(false) {
+        let exponent = Decimal::from_ratio(asset_out.weight, asset_in.weight);
+        let spot_price = Decimal::from_ratio(balance_out, balance_in)
+            .checked_div(exponent)
+            .map_err(generic_err)?;
+        let amount_in = balancer_in(balance_in, balance_out, exponent, token_out.amount)?;
+        let expected = token_out
+            .amount
+            .checked_div_floor(spot_price)
+            .map_err(generic_err)?;
+        let amount_in_with_fee = amount_in
+            .checked_div_ceil(Decimal::one() - swap_fee)
+            .map_err(generic_err)?;
+
+        return Ok(SwapEstimation {
+            token: Coin::new(amount_in_with_fee.u128(), denom_in),
+            spot_price,
+            swap_fee,
+            weight_breaking_fee: Decimal::zero(),
+            slippage: slippage(amount_in, expected),
+        });
+    }
+
+    let price_in = oracle_price(prices, denom_in)?;
+    let price_out = oracle_price(prices, &token_out.denom)?;
+    let spot_price = price_in.checked_div(price_out).map_err(generic_err)?;
+    let external_liquidity_ratio = pool.pool_params.external_liquidity_ratio;
+    if external_liquidity_ratio.is_zero() {
+        return Err(StdError::generic_err(
+            "pool: external liquidity ratio is zero",
+        ));
+    }
+
+    let exponent = Decimal::from_ratio(balance_out, balance_in)
+        .checked_div(spot_price)
+        .map_err(generic_err)?;
+    let resized_out = token_out
+        .amount
+        .checked_div_floor(external_liquidity_ratio)
+        .map_err(generic_err)?;
+    let resized_in = balancer_in(balance_in, balance_out, exponent, resized_out)?;
+    let slippage_amount = resized_in
+        .saturating_sub(
+            resized_out
+                .checked_div_ceil(spot_price)
+                .map_err(generic_err)?,
+        )
+        .checked_mul_ceil(external_liquidity_ratio)
+        .map_err(generic_err)?;
+
+    let oracle_in = token_out
+        .amount
+        .checked_div_ceil(spot_price)
+        .map_err(generic_err)?;
+    let amount_in = oracle_in.checked_add(slippage_amount)?;
+    let weight_breaking_fee = weight_breaking_fee(
+        pool,
+        prices,
+        (denom_in, amount_in),
+        (&token_out.denom, token_out.amount),
+    )?;
+    let amount_in = amount_in
+        .checked_div_ceil(Decimal::one() - weight_breaking_fee)
+        .map_err(generic_err)?
+        .checked_div_ceil(Decimal::one() - swap_fee)
+        .map_err(generic_err)?;
+
+    Ok(SwapEstimation {
+        token: Coin::new(amount_in.u128(), denom_in),
+        spot_price,
+        swap_fee,
+        weight_breaking_fee,
+        slippage: slippage(oracle_in + slippage_amount, oracle_in),
+    })
+}
+
+/// StableSwap invariant of the balances, with `amplification` as the
+/// amplification coefficient of the curve.
+fn stable_swap_d(balances: &[Uint256], amplification: Uint256) -> StdResult<Uint256> {
+    let n = Uint256::from(balances.len() as u128);
+    let sum = balances
+        .iter()
+        .try_fold(Uint256::zero(), |sum, balance| sum.checked_add(*balance))?;
+    if sum.is_zero() {
+        return Ok(Uint256::zero());
+    }
+
+    let ann = amplification.checked_mul(n)?;
+    let mut d = sum;
+    for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+        let mut d_p = d;
+        for balance in balances {
+            d_p = d_p.checked_mul(d)?.checked_div(balance.checked_mul(n)?)?;
+        }
+        let previous = d;
+        d = ann
+            .checked_mul(sum)?
+            .checked_add(d_p.checked_mul(n)?)?
+            .checked_mul(d)?
+            .checked_div(
+                ann.checked_sub(Uint256::one())?
+                    .checked_mul(d)?
+                    .checked_add(n.checked_add(Uint256::one())?.checked_mul(d_p)?)?,
+            )?;
+        if d.abs_diff(previous) <= Uint256::one() {
+            return Ok(d);
+        }
+    }
+
+    Err(StdError::generic_err(
+        "stable swap: invariant did not converge",
+    ))
+}
+
+/// Balance of asset `j` keeping the invariant once asset `i` has the balance `x`.
+fn stable_swap_y(
+    balances: &[Uint256],
+    amplification: Uint256,
+    (i, j): (usize, usize),
+    x: Uint256,
+) -> StdResult<Uint256> {
+    let n = Uint256::from(balances.len() as u128);
+    let d = stable_swap_d(balances, amplification)?;
+    let ann = amplification.checked_mul(n)?;
+
+    let mut c = d;
+    let mut sum = Uint256::zero();
+    for (k, balance) in balances.iter().enumerate() {
+        let balance = if k == i {
+            x
+        } else if k != j {
+            *balance
+        } else {
+            continue;
+        };
+        sum = sum.checked_add(balance)?;
+        c = c.checked_mul(d)?.checked_div(balance.checked_mul(n)?)?;
+    }
+    c = c.checked_mul(d)?.checked_div(ann.checked_mul(n)?)?;
+    let b = sum.checked_add(d.checked_div(ann)?)?;
+
+    let mut y = d;
+    for _ in 0..STABLE_SWAP_MAX_ITERATIONS {
+        let previous = y;
+        y = y.checked_mul(y)?.checked_add(c)?.checked_div(
+            y.checked_mul(Uint256::from(2u128))?
+                .checked_add(b)?
+                .checked_sub(d)?,
+        )?;
+        if y.abs_diff(previous) <= Uint256::one() {
+            return Ok(y);
+        }
+    }
+
+    Err(StdError::generic_err(
+        "stable swap: balance did not converge",
+    ))
+}
+
+/// Parameters of a StableSwap curve. The amm module does not report them with
+/// the pool, so they are given next to it.
+#[cw_serde]
+pub struct StableSwapParams {
+    pub amplification: u64,
+}
+
+impl StableSwapParams {
+    fn amplification(&self) -> StdResult<Uint256> {
+        if self.amplification == 0 {
+            return Err(StdError::generic_err(
+                "stable swap: amplification must be positive",
+            ));
+        }
+        Ok(Uint256::from(self.amplification))
+    }
+}
+
+fn stable_swap_balances(
+    pool: &AmmPool,
+    denom_in: &str,
+    denom_out: &str,
+) -> StdResult<(Vec<Uint256>, usize, usize)> {
+    let balances = pool
+        .pool_assets
+        .iter()
+        .map(|asset| Uint256::from(asset.token.amount))
+        .collect();
+    Ok((
+        balances,
+        pool_asset_index(pool, denom_in)?,
+        pool_asset_index(pool, denom_out)?,
+    ))
+}
+
+/// Amount of `denom_out` received for `token_in` on a StableSwap curve, the
+/// balances of the pool are compared as they are.
+pub fn stable_swap_out_given_in(
+    pool: &AmmPool,
+    params: &StableSwapParams,
+    token_in: &Coin,
+    denom_out: &str,
+) -> StdResult<SwapEstimation> {
+    let (balances, i, j) = stable_swap_balances(pool, &token_in.denom, denom_out)?;
+    let amplification = params.amplification()?;
+
+    let x = balances[i].checked_add(token_in.amount.into())?;
+    let y = stable_swap_y(&balances, amplification, (i, j), x)?;
+    let amount_out = Uint128::try_from(
+        balances[j]
+            .checked_sub(y)?
+            .checked_sub(Uint256::one())
+            .unwrap_or_default(),
+    )?;
+
+    let swap_fee = pool.pool_params.swap_fee;
+    Ok(SwapEstimation {
+        token: Coin::new(
+            amount_out
+                .checked_mul_floor(Decimal::one() - swap_fee)
+                .map_err(generic_err)?
+                .u128(),
+            denom_out,
+        ),
+        spot_price: Decimal::one(),
+        swap_fee,
+        weight_breaking_fee: Decimal::zero(),
+        slippage: slippage(token_in.amount, amount_out),
+    })
+}
+
+/// Amount of `denom_in` needed to receive `token_out` on a StableSwap curve.
+pub fn stable_swap_in_given_out(
+    pool: &AmmPool,
+    params: &StableSwapParams,
+    token_out: &Coin,
+    denom_in: &str,
+) -> StdResult<SwapEstimation> {
+    let (balances, i, j) = stable_swap_balances(pool, denom_in, &token_out.denom)?;
+    let amplification = params.amplification()?;
+
+    let swap_fee = pool.pool_params.swap_fee;
+    let amount_out = token_out
+        .amount
+        .checked_div_ceil(Decimal::one() - swap_fee)
+        .map_err(generic_err)?;
+    if Uint256::from(amount_out) >= balances[j] {
+        return Err(StdError::generic_err("token out: not enough liquidity"));
+    }
+
+    let y = balances[j].checked_sub(amount_out.into())?;
+    let x = stable_swap_y(&balances, amplification, (j, i), y)?;
+    let amount_in = Uint128::try_from(x.checked_sub(balances[i])?.checked_add(Uint256::one())?)?;
+
+    Ok(SwapEstimation {
+        token: Coin::new(amount_in.u128(), denom_in),
+        spot_price: Decimal::one(),
+        swap_fee,
+        weight_breaking_fee: Decimal::zero(),
+        slippage: slippage(amount_in, amount_out),
+    })
+}
+
+/// Shares received for joining the pool with at most `max_amounts_in`, in the
+/// proportions of the pool.
+pub fn join_pool_estimation(
+    pool: &AmmPool,
+    max_amounts_in: &[Coin],
+) -> StdResult<QueryJoinPoolEstimationResponse> {
+    let ratio = pool
+        .pool_assets
+        .iter()
+        .map(|asset| {
+            let amount = max_amounts_in
+                .iter()
+                .find(|coin| coin.denom == asset.token.denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default();
+            Decimal::checked_from_ratio(amount, asset.token.amount).map_err(generic_err)
+        })
+        .try_fold(None, |min: Option<Decimal>, ratio| {
+            let ratio = ratio?;
+            StdResult::Ok(Some(min.map_or(ratio, |min| min.min(ratio))))
+        })?
+        .unwrap_or_default();
+    if ratio.is_zero() {
+        return Err(StdError::generic_err(
+            "max_amounts_in: every asset of the pool must be provided",
+        ));
+    }
+
+    let amounts_in = pool
+        .pool_assets
+        .iter()
+        .map(|asset| {
+            let max_amount = max_amounts_in
+                .iter()
+                .find(|coin| coin.denom == asset.token.denom)
+                .map(|coin| coin.amount)
+                .unwrap_or_default();
+            let amount = asset
+                .token
+                .amount
+                .checked_mul_ceil(ratio)
+                .map_err(generic_err)?;
+            Ok(Coin::new(amount.min(max_amount).u128(), &asset.token.denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+    let share_amount_out = pool
+        .total_shares
+        .amount
+        .checked_mul_floor(ratio)
+        .map_err(generic_err)?;
+
+    Ok(QueryJoinPoolEstimationResponse {
+        amounts_in,
+        share_amount_out: Coin::new(share_amount_out.u128(), &pool.total_shares.denom),
+        slippage: Decimal::zero(),
+        weight_balance_ratio: SignedDecimal::zero(),
+    })
+}
+
+/// Assets received for `share_amount_in` shares, after the exit fee.
+pub fn exit_pool_estimation(
+    pool: &AmmPool,
+    share_amount_in: Uint128,
+) -> StdResult<QueryExitPoolEstimationResponse> {
+    if share_amount_in > pool.total_shares.amount {
+        return Err(StdError::generic_err(
+            "share_amount_in: exceeds the pool shares",
+        ));
+    }
+
+    let ratio = Decimal::checked_from_ratio(share_amount_in, pool.total_shares.amount)
+        .map_err(generic_err)?;
+    let exit_fee = pool.pool_params.exit_fee;
+    let amounts_out = pool
+        .pool_assets
+        .iter()
+        .map(|asset| {
+            let amount = asset
+                .token
+                .amount
+                .checked_mul_floor(ratio)
+                .map_err(generic_err)?
+                .checked_mul_floor(Decimal::one() - exit_fee)
+                .map_err(generic_err)?;
+            Ok(Coin::new(amount.u128(), &asset.token.denom))
+        })
+        .collect::<StdResult<Vec<Coin>>>()?;
+
+    Ok(QueryExitPoolEstimationResponse { amounts_out })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::from_json;
+    use std::str::FromStr;
+
+    #[cw_serde]
+    struct SwapCase {
+        kind: String,
+        token: Coin,
+        denom: String,
+        expected: SwapEstimation,
+    }
+
+    #[cw_serde]
+    struct JoinCase {
+        max_amounts_in: Vec<Coin>,
+        expected: QueryJoinPoolEstimationResponse,
+    }
+
+    #[cw_serde]
+    struct ExitCase {
+        share_amount_in: Uint128,
+        expected: QueryExitPoolEstimationResponse,
+    }
+
+    // synthetic pools in the layout of the amm pool query, with hand-computed
+    // estimations
+    #[cw_serde]
+    struct Fixtures {
+        source: String,
+        cases: Vec<Fixture>,
+    }
+
+    #[cw_serde]
+    struct Fixture {
+        name: String,
+        pool: AmmPool,
+        stable_swap: Option<StableSwapParams>,
+        prices: Vec<Price>,
+        swaps: Vec<SwapCase>,
+        join: Option<JoinCase>,
+        exit: Option<ExitCase>,
+    }
+
+    #[test]
+    fn fixtures() {
+        let fixtures: Fixtures = from_json(include_str!("fixtures/pools.json")).unwrap();
+        assert!(fixtures.source.starts_with("synthetic"));
+
+        for fixture in fixtures.cases {
+            let Fixture { pool, prices, .. } = &fixture;
+            let stable_swap = || fixture.stable_swap.as_ref().unwrap();
+
+            for swap in &fixture.swaps {
+                let name = format!("{} {}", fixture.name, swap.kind);
+                let estimation = match swap.kind.as_str() {
+                    "out_given_in" => swap_out_given_in(pool, prices, &swap.token, &swap.denom),
+                    "in_given_out" => swap_in_given_out(pool, prices, &swap.token, &swap.denom),
+                    "stable_out_given_in" => {
+                        stable_swap_out_given_in(pool, stable_swap(), &swap.token, &swap.denom)
+                    }
+                    "stable_in_given_out" => {
+                        stable_swap_in_given_out(pool, stable_swap(), &swap.token, &swap.denom)
+                    }
+                    kind => panic!("unknown swap kind {kind}"),
+                };
+                assert_eq!(estimation.unwrap(), swap.expected, "{name}");
+            }
+
+            if let Some(join) = &fixture.join {
+                assert_eq!(
+                    join_pool_estimation(pool, &join.max_amounts_in).unwrap(),
+                    join.expected,
+                    "{}",
+                    fixture.name
+                );
+            }
+            if let Some(exit) = &fixture.exit {
+                assert_eq!(
+                    exit_pool_estimation(pool, exit.share_amount_in).unwrap(),
+                    exit.expected,
+                    "{}",
+                    fixture.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn pow_fractional_exponent() {
+        let sqrt = pow(Decimal::percent(50), Decimal::percent(50)).unwrap();
+        let expected = Decimal::from_str("0.707106781186547524").unwrap();
+        assert!(sqrt.abs_diff(expected) < POW_PRECISION);
+
+        assert_eq!(
+            pow(Decimal::percent(150), Decimal::from_str("2").unwrap()).unwrap(),
+            Decimal::from_str("2.25").unwrap()
+        );
+        pow(Decimal::from_str("2").unwrap(), Decimal::one()).unwrap_err();
+    }
+}
@@ -0,0 +1,114 @@
+use cosmwasm_std::{
+    CheckedFromRatioError, ConversionOverflowError, Decimal, DecimalRangeExceeded, OverflowError,
+    SignedDecimal, StdError,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ElysBindingsError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+    #[error("price not found for {denom}")]
+    PriceNotFound { denom: String },
+    #[error("asset profile not found for {denom}")]
+    AssetProfileNotFound { denom: String },
+    #[error("invalid perpetual position: {value}")]
+    InvalidPosition { value: String },
+    #[error("decimal conversion: {msg}")]
+    DecimalConversion { msg: String },
+    #[error("{0}")]
+    Overflow(#[from] OverflowError),
+    #[error("{kind} not found")]
+    NotFound { kind: String },
+    #[error("{field} must be positive")]
+    InvalidAmount { field: String },
+    #[error("{field} denom must not be empty")]
+    InvalidDenom { field: String },
+    #[error("leverage {leverage} must be greater than 1 and at most {leverage_max}")]
+    InvalidLeverage {
+        leverage: SignedDecimal,
+        leverage_max: Decimal,
+    },
+}
+
+impl ElysBindingsError {
+    /// Whether the failure comes from a price the chain cannot provide, as
+    /// opposed to an arithmetic or query failure.
+    pub fn is_price_missing(&self) -> bool {
+        matches!(
+            self,
+            Self::PriceNotFound { .. } | Self::AssetProfileNotFound { .. }
+        )
+    }
+}
+
+impl From<DecimalRangeExceeded> for ElysBindingsError {
+    fn from(e: DecimalRangeExceeded) -> Self {
+        Self::DecimalConversion { msg: e.to_string() }
+    }
+}
+
+impl From<CheckedFromRatioError> for ElysBindingsError {
+    fn from(e: CheckedFromRatioError) -> Self {
+        Self::DecimalConversion { msg: e.to_string() }
+    }
+}
+
+impl From<ConversionOverflowError> for ElysBindingsError {
+    fn from(e: ConversionOverflowError) -> Self {
+        Self::DecimalConversion { msg: e.to_string() }
+    }
+}
+
+// keeps the kind of failure in the StdError variants where one matches
+impl From<ElysBindingsError> for StdError {
+    fn from(e: ElysBindingsError) -> Self {
+        match e {
+            ElysBindingsError::Std(e) => e,
+            ElysBindingsError::PriceNotFound { denom } => {
+                StdError::not_found(format!("price of {}", denom))
+            }
+            ElysBindingsError::AssetProfileNotFound { denom } => {
+                StdError::not_found(format!("asset profile of {}", denom))
+            }
+            ElysBindingsError::InvalidPosition { value } => {
+                StdError::parse_err("PerpetualPosition", format!("invalid value {}", value))
+            }
+            ElysBindingsError::Overflow(e) => StdError::overflow(e),
+            ElysBindingsError::NotFound { kind } => StdError::not_found(kind),
+            e @ (ElysBindingsError::DecimalConversion { .. }
+            | ElysBindingsError::InvalidAmount { .. }
+            | ElysBindingsError::InvalidDenom { .. }
+            | ElysBindingsError::InvalidLeverage { .. }) => StdError::generic_err(e.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::{OverflowOperation, Uint128};
+
+    #[test]
+    fn into_std_error() {
+        let err: StdError = ElysBindingsError::PriceNotFound {
+            denom: "uatom".to_string(),
+        }
+        .into();
+        assert_eq!(err, StdError::not_found("price of uatom"));
+
+        let err: StdError = ElysBindingsError::InvalidPosition {
+            value: "3".to_string(),
+        }
+        .into();
+        assert!(matches!(err, StdError::ParseErr { .. }));
+
+        let overflow = || OverflowError::new(OverflowOperation::Add, Uint128::MAX, 1u128);
+        let err: StdError = ElysBindingsError::from(overflow()).into();
+        assert_eq!(err, StdError::overflow(overflow()));
+
+        // std errors come back unchanged
+        let err: StdError = ElysBindingsError::from(StdError::generic_err("unknown")).into();
+        assert_eq!(err, StdError::generic_err("unknown"));
+    }
+}
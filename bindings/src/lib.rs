@@ -1,5 +1,9 @@
+pub mod amm;
+mod error;
 mod msg;
+pub mod msg_builder;
 pub mod msg_resp;
+pub mod perpetual;
 mod querier;
 mod query;
 pub mod query_resp;
@@ -8,6 +12,7 @@ mod test;
 
 pub mod types;
 
+pub use error::ElysBindingsError;
 pub use msg::*;
 pub use querier::ElysQuerier;
 pub use query::*;
@@ -152,6 +152,20 @@ pub enum ElysMsg {
         amount: Uint128,
         owner: String,
     },
+
+    PerpetualUpdateTakeProfitPrice {
+        creator: String,
+        id: u64,
+        price: SignedDecimal256,
+        owner: String,
+    },
+
+    PerpetualUpdateStopLoss {
+        creator: String,
+        id: u64,
+        price: SignedDecimal,
+        owner: String,
+    },
 }
 
 impl ElysMsg {
@@ -418,6 +432,34 @@ impl ElysMsg {
             owner: owner.into(),
         }
     }
+
+    pub fn perpetual_update_take_profit_price(
+        creator: impl Into<String>,
+        id: u64,
+        price: SignedDecimal256,
+        owner: impl Into<String>,
+    ) -> Self {
+        Self::PerpetualUpdateTakeProfitPrice {
+            creator: creator.into(),
+            id,
+            price,
+            owner: owner.into(),
+        }
+    }
+
+    pub fn perpetual_update_stop_loss(
+        creator: impl Into<String>,
+        id: u64,
+        price: SignedDecimal,
+        owner: impl Into<String>,
+    ) -> Self {
+        Self::PerpetualUpdateStopLoss {
+            creator: creator.into(),
+            id,
+            price,
+            owner: owner.into(),
+        }
+    }
 }
 
 impl From<ElysMsg> for CosmosMsg<ElysMsg> {
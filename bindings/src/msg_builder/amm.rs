@@ -0,0 +1,120 @@
+use cosmwasm_std::{Addr, Api, Coin, CosmosMsg, Decimal, Int128, Uint128};
+
+use super::{positive_coin, positive_uint};
+use crate::{types::SwapAmountInRoute, ElysBindingsError, ElysMsg};
+
+pub struct AmmMsgBuilder<'a> {
+    api: &'a dyn Api,
+    sender: Addr,
+}
+
+impl<'a> AmmMsgBuilder<'a> {
+    pub fn new(api: &'a dyn Api, sender: impl Into<String>) -> Result<Self, ElysBindingsError> {
+        let sender = api.addr_validate(&sender.into())?;
+        Ok(Self { api, sender })
+    }
+
+    pub fn swap_exact_amount_in(
+        &self,
+        token_in: Coin,
+        routes: &Vec<SwapAmountInRoute>,
+        token_out_min_amount: Int128,
+        discount: Decimal,
+        recipient: impl Into<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let token_in = positive_coin("token_in", token_in)?;
+        if token_out_min_amount < Int128::zero() {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "token_out_min_amount".to_string(),
+            });
+        }
+        let recipient = self.api.addr_validate(&recipient.into())?;
+
+        Ok(ElysMsg::amm_swap_exact_amount_in(
+            &self.sender,
+            &token_in,
+            routes,
+            token_out_min_amount,
+            discount,
+            recipient,
+        )
+        .into())
+    }
+
+    pub fn swap_by_denom(
+        &self,
+        amount: Coin,
+        (min_amount, max_amount): (Coin, Coin),
+        out_denom: impl Into<String>,
+        discount: Decimal,
+        recipient: impl Into<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_coin("amount", amount)?;
+        let recipient = self.api.addr_validate(&recipient.into())?;
+        let in_denom = amount.denom.clone();
+
+        Ok(ElysMsg::swap_by_denom(
+            &self.sender,
+            amount,
+            min_amount,
+            max_amount,
+            in_denom,
+            out_denom,
+            discount,
+            recipient,
+        )
+        .into())
+    }
+
+    pub fn join_pool(
+        &self,
+        pool_id: u64,
+        max_amounts_in: Vec<Coin>,
+        share_amount_out: Uint128,
+        no_remaining: bool,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let max_amounts_in = max_amounts_in
+            .into_iter()
+            .map(|coin| positive_coin("max_amounts_in", coin))
+            .collect::<Result<Vec<Coin>, ElysBindingsError>>()?;
+        positive_uint("share_amount_out", share_amount_out)?;
+
+        Ok(ElysMsg::amm_join_pool(
+            self.sender.to_string(),
+            pool_id,
+            max_amounts_in,
+            share_amount_out,
+            no_remaining,
+        )
+        .into())
+    }
+
+    pub fn exit_pool(
+        &self,
+        pool_id: u64,
+        min_amounts_out: Vec<Coin>,
+        share_amount_in: Uint128,
+        token_out_denom: impl Into<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let min_amounts_out = min_amounts_out
+            .into_iter()
+            .map(|coin| positive_coin("min_amounts_out", coin))
+            .collect::<Result<Vec<Coin>, ElysBindingsError>>()?;
+        positive_uint("share_amount_in", share_amount_in)?;
+        let token_out_denom = token_out_denom.into();
+        if token_out_denom.is_empty() {
+            return Err(ElysBindingsError::InvalidDenom {
+                field: "token_out_denom".to_string(),
+            });
+        }
+
+        Ok(ElysMsg::amm_exit_pool(
+            self.sender.to_string(),
+            pool_id,
+            min_amounts_out,
+            share_amount_in,
+            token_out_denom,
+        )
+        .into())
+    }
+}
@@ -0,0 +1,75 @@
+use cosmwasm_std::{Addr, Api, CosmosMsg, Int128};
+
+use super::positive_int;
+use crate::{ElysBindingsError, ElysMsg};
+
+pub struct CommitmentMsgBuilder {
+    creator: Addr,
+}
+
+impl CommitmentMsgBuilder {
+    pub fn new(api: &dyn Api, creator: impl Into<String>) -> Result<Self, ElysBindingsError> {
+        Ok(Self {
+            creator: api.addr_validate(&creator.into())?,
+        })
+    }
+
+    /// `validator_address` is required to stake uelys and ignored for the other assets.
+    pub fn stake(
+        &self,
+        amount: Int128,
+        asset: impl Into<String>,
+        validator_address: Option<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_int("amount", amount)?;
+
+        Ok(ElysMsg::stake_token(
+            self.creator.to_string(),
+            amount,
+            asset.into(),
+            validator_address,
+        )
+        .into())
+    }
+
+    pub fn unstake(
+        &self,
+        amount: Int128,
+        asset: impl Into<String>,
+        validator_address: Option<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_int("amount", amount)?;
+
+        Ok(ElysMsg::unstake_token(
+            self.creator.to_string(),
+            amount,
+            asset.into(),
+            validator_address,
+        )
+        .into())
+    }
+
+    pub fn vest(
+        &self,
+        amount: Int128,
+        denom: impl Into<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_int("amount", amount)?;
+
+        Ok(ElysMsg::eden_vesting(self.creator.to_string(), amount, denom.into()).into())
+    }
+
+    pub fn cancel_vest(
+        &self,
+        amount: Int128,
+        denom: impl Into<String>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_int("amount", amount)?;
+
+        Ok(ElysMsg::eden_cancel_vesting(self.creator.to_string(), amount, denom.into()).into())
+    }
+
+    pub fn claim_vesting(&self) -> CosmosMsg<ElysMsg> {
+        ElysMsg::eden_claim_vesting(self.creator.to_string()).into()
+    }
+}
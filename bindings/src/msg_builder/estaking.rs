@@ -0,0 +1,67 @@
+use cosmwasm_std::{Addr, Api, Coin, CosmosMsg};
+
+use super::positive_coin;
+use crate::{ElysBindingsError, ElysMsg};
+
+/// Builds the staking messages of `delegator`, including the redelegations and
+/// unbonding cancellations handled by the incentive module. Validator
+/// addresses use the valoper prefix, so they are left to the chain to check.
+pub struct EstakingMsgBuilder {
+    delegator: Addr,
+}
+
+impl EstakingMsgBuilder {
+    pub fn new(api: &dyn Api, delegator: impl Into<String>) -> Result<Self, ElysBindingsError> {
+        Ok(Self {
+            delegator: api.addr_validate(&delegator.into())?,
+        })
+    }
+
+    pub fn withdraw_reward(&self, validator_address: impl Into<String>) -> CosmosMsg<ElysMsg> {
+        ElysMsg::estaking_withdraw_reward(self.delegator.to_string(), validator_address.into())
+            .into()
+    }
+
+    pub fn withdraw_elys_staking_rewards(&self) -> CosmosMsg<ElysMsg> {
+        ElysMsg::estaking_withdraw_elys_staking_rewards(self.delegator.to_string()).into()
+    }
+
+    pub fn begin_redelegate(
+        &self,
+        validator_src_address: impl Into<String>,
+        validator_dst_address: impl Into<String>,
+        amount: Coin,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_coin("amount", amount)?;
+
+        Ok(ElysMsg::begin_redelegate(
+            self.delegator.to_string(),
+            validator_src_address.into(),
+            validator_dst_address.into(),
+            amount,
+        )
+        .into())
+    }
+
+    pub fn cancel_unbonding(
+        &self,
+        validator_address: impl Into<String>,
+        amount: Coin,
+        creation_height: i64,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_coin("amount", amount)?;
+        if creation_height <= 0 {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "creation_height".to_string(),
+            });
+        }
+
+        Ok(ElysMsg::cancel_unbonding(
+            self.delegator.to_string(),
+            validator_address.into(),
+            amount,
+            creation_height,
+        )
+        .into())
+    }
+}
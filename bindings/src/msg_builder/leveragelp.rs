@@ -0,0 +1,83 @@
+use cosmwasm_std::{Addr, Api, Coin, CosmosMsg, Decimal, Int128, SignedDecimal};
+
+use super::{checked_leverage, positive_coin, positive_int};
+use crate::{query_resp::LeveragelpParams, ElysBindingsError, ElysMsg};
+
+pub struct LeveragelpMsgBuilder {
+    creator: Addr,
+    leverage_max: Option<Decimal>,
+}
+
+impl LeveragelpMsgBuilder {
+    pub fn new(api: &dyn Api, creator: impl Into<String>) -> Result<Self, ElysBindingsError> {
+        Ok(Self {
+            creator: api.addr_validate(&creator.into())?,
+            leverage_max: None,
+        })
+    }
+
+    /// Keeps the leverage bound of the module to check the positions opened.
+    pub fn with_params(mut self, params: &LeveragelpParams) -> Self {
+        self.leverage_max = Some(params.leverage_max);
+        self
+    }
+
+    pub fn open(
+        &self,
+        amm_pool_id: u64,
+        collateral: Coin,
+        leverage: SignedDecimal,
+        stop_loss_price: SignedDecimal,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let collateral = positive_coin("collateral", collateral)?;
+        let leverage_max = self
+            .leverage_max
+            .ok_or_else(|| ElysBindingsError::NotFound {
+                kind: "leveragelp params".to_string(),
+            })?;
+        let leverage = checked_leverage(leverage, leverage_max)?;
+        if stop_loss_price < SignedDecimal::zero() {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "stop_loss_price".to_string(),
+            });
+        }
+
+        Ok(ElysMsg::leveragelp_open_position(
+            self.creator.to_string(),
+            amm_pool_id,
+            collateral.denom,
+            Int128::try_from(collateral.amount)?,
+            leverage,
+            stop_loss_price,
+        )
+        .into())
+    }
+
+    pub fn close(
+        &self,
+        id: u64,
+        lp_amount: Int128,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let lp_amount = positive_int("lp_amount", lp_amount)?;
+
+        Ok(ElysMsg::leveragelp_close_position(self.creator.to_string(), id, lp_amount).into())
+    }
+
+    pub fn update_stop_loss(
+        &self,
+        position: u64,
+        price: SignedDecimal,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        if price < SignedDecimal::zero() {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "price".to_string(),
+            });
+        }
+
+        Ok(ElysMsg::leveragelp_update_stop_loss(self.creator.to_string(), position, price).into())
+    }
+
+    pub fn claim_rewards(&self, ids: Vec<u64>) -> CosmosMsg<ElysMsg> {
+        ElysMsg::leveragelp_withdraw_reward(self.creator.to_string(), ids).into()
+    }
+}
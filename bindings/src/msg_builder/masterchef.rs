@@ -0,0 +1,19 @@
+use cosmwasm_std::{Addr, Api, CosmosMsg};
+
+use crate::{ElysBindingsError, ElysMsg};
+
+pub struct MasterchefMsgBuilder {
+    sender: Addr,
+}
+
+impl MasterchefMsgBuilder {
+    pub fn new(api: &dyn Api, sender: impl Into<String>) -> Result<Self, ElysBindingsError> {
+        Ok(Self {
+            sender: api.addr_validate(&sender.into())?,
+        })
+    }
+
+    pub fn claim_rewards(&self, pool_ids: Vec<u64>) -> CosmosMsg<ElysMsg> {
+        ElysMsg::get_masterchef_claim_rewards(self.sender.to_string(), pool_ids).into()
+    }
+}
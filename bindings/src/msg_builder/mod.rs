@@ -0,0 +1,462 @@
+//! Validated constructors for the `ElysMsg` of each chain module: addresses go
+//! through `Api::addr_validate` and amounts and leverages are checked before
+//! the message is built, so bad inputs fail in the contract instead of on chain.
+
+mod amm;
+mod commitment;
+mod estaking;
+mod leveragelp;
+mod masterchef;
+mod perpetual;
+
+pub use amm::AmmMsgBuilder;
+pub use commitment::CommitmentMsgBuilder;
+pub use estaking::EstakingMsgBuilder;
+pub use leveragelp::LeveragelpMsgBuilder;
+pub use masterchef::MasterchefMsgBuilder;
+pub use perpetual::PerpetualMsgBuilder;
+
+use cosmwasm_std::{Coin, Decimal, Int128, SignedDecimal, Uint128};
+
+use crate::ElysBindingsError;
+
+fn positive_int(field: &str, amount: Int128) -> Result<Int128, ElysBindingsError> {
+    if amount <= Int128::zero() {
+        return Err(ElysBindingsError::InvalidAmount {
+            field: field.to_string(),
+        });
+    }
+    Ok(amount)
+}
+
+fn positive_uint(field: &str, amount: Uint128) -> Result<Uint128, ElysBindingsError> {
+    if amount.is_zero() {
+        return Err(ElysBindingsError::InvalidAmount {
+            field: field.to_string(),
+        });
+    }
+    Ok(amount)
+}
+
+fn positive_coin(field: &str, coin: Coin) -> Result<Coin, ElysBindingsError> {
+    if coin.denom.is_empty() {
+        return Err(ElysBindingsError::InvalidDenom {
+            field: field.to_string(),
+        });
+    }
+    positive_uint(field, coin.amount)?;
+    Ok(coin)
+}
+
+fn checked_leverage(
+    leverage: SignedDecimal,
+    leverage_max: Decimal,
+) -> Result<SignedDecimal, ElysBindingsError> {
+    let in_range = SignedDecimal::try_from(leverage_max).map_or(false, |max| {
+        leverage > SignedDecimal::one() && leverage <= max
+    });
+    if !in_range {
+        return Err(ElysBindingsError::InvalidLeverage {
+            leverage,
+            leverage_max,
+        });
+    }
+    Ok(leverage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_resp::{LeveragelpParams, PerpetualParams, PerpetualParamsRaw};
+    use crate::types::{PerpetualPosition, SwapAmountInRoute};
+    use crate::ElysMsg;
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::{coin, CosmosMsg, SignedDecimal256};
+    use std::str::FromStr;
+
+    #[test]
+    fn perpetual_open() {
+        let api = MockApi::default();
+        let params: PerpetualParams = PerpetualParamsRaw {
+            leverage_max: Some(Decimal::from_str("10").unwrap()),
+            ..Default::default()
+        }
+        .into();
+        let builder = PerpetualMsgBuilder::new(&api, "contract", "user")
+            .unwrap()
+            .with_params(&params);
+        let leverage = |leverage: &str| SignedDecimal::from_str(leverage).unwrap();
+
+        let msg = builder
+            .open(
+                coin(1000, "uusdc"),
+                "uatom",
+                PerpetualPosition::Long,
+                leverage("5"),
+                None,
+            )
+            .unwrap();
+        assert!(matches!(
+            msg,
+            CosmosMsg::Custom(ElysMsg::PerpetualOpen { position: 1, .. })
+        ));
+
+        assert_eq!(
+            builder
+                .open(
+                    coin(1000, "uusdc"),
+                    "uatom",
+                    PerpetualPosition::Long,
+                    leverage("11"),
+                    None,
+                )
+                .unwrap_err(),
+            ElysBindingsError::InvalidLeverage {
+                leverage: leverage("11"),
+                leverage_max: Decimal::from_str("10").unwrap(),
+            }
+        );
+        assert_eq!(
+            builder
+                .open(
+                    coin(0, "uusdc"),
+                    "uatom",
+                    PerpetualPosition::Short,
+                    leverage("2"),
+                    None,
+                )
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "collateral".to_string(),
+            }
+        );
+        assert_eq!(
+            builder
+                .open(
+                    coin(1000, ""),
+                    "uatom",
+                    PerpetualPosition::Short,
+                    leverage("2"),
+                    None,
+                )
+                .unwrap_err(),
+            ElysBindingsError::InvalidDenom {
+                field: "collateral".to_string(),
+            }
+        );
+        assert!(matches!(
+            builder.open(
+                coin(1000, "uusdc"),
+                "uatom",
+                PerpetualPosition::Unspecified,
+                leverage("2"),
+                None,
+            ),
+            Err(ElysBindingsError::InvalidPosition { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let api = MockApi::default();
+
+        // the mock api only accepts lower case addresses
+        assert!(matches!(
+            AmmMsgBuilder::new(&api, "User"),
+            Err(ElysBindingsError::Std(_))
+        ));
+
+        let commitment = CommitmentMsgBuilder::new(&api, "user").unwrap();
+        assert_eq!(
+            commitment
+                .stake(Int128::new(-1), "uelys", None)
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "amount".to_string(),
+            }
+        );
+
+        // the leverage of leveragelp positions is only checked once the params are known
+        let leveragelp = LeveragelpMsgBuilder::new(&api, "user").unwrap();
+        assert!(matches!(
+            leveragelp.open(
+                1,
+                coin(1000, "uusdc"),
+                SignedDecimal::percent(200),
+                SignedDecimal::zero()
+            ),
+            Err(ElysBindingsError::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn perpetual_updates() {
+        let api = MockApi::default();
+        let builder = PerpetualMsgBuilder::new(&api, "contract", "user").unwrap();
+
+        assert!(matches!(
+            builder.close(1, Int128::new(1000)).unwrap(),
+            CosmosMsg::Custom(ElysMsg::PerpetualClose { id: 1, .. })
+        ));
+        assert_eq!(
+            builder.close(1, Int128::zero()).unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "amount".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder.add_collateral(1, Uint128::new(1000)).unwrap(),
+            CosmosMsg::Custom(ElysMsg::PerpetualAddCollateral { id: 1, .. })
+        ));
+        assert_eq!(
+            builder.add_collateral(1, Uint128::zero()).unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "amount".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder
+                .update_take_profit_price(1, SignedDecimal256::from_str("30").unwrap())
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::PerpetualUpdateTakeProfitPrice { id: 1, .. })
+        ));
+        assert_eq!(
+            builder
+                .update_take_profit_price(1, SignedDecimal256::zero())
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "price".to_string(),
+            }
+        );
+
+        // a zero price removes the stop loss
+        assert!(matches!(
+            builder.update_stop_loss(1, SignedDecimal::zero()).unwrap(),
+            CosmosMsg::Custom(ElysMsg::PerpetualUpdateStopLoss { id: 1, .. })
+        ));
+        assert_eq!(
+            builder
+                .update_stop_loss(1, SignedDecimal::from_str("-1").unwrap())
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "price".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn amm_msgs() {
+        let api = MockApi::default();
+        let builder = AmmMsgBuilder::new(&api, "user").unwrap();
+        let routes = vec![SwapAmountInRoute {
+            pool_id: 1,
+            token_out_denom: "uusdc".to_string(),
+        }];
+
+        assert!(matches!(
+            builder
+                .swap_exact_amount_in(
+                    coin(1000, "uatom"),
+                    &routes,
+                    Int128::zero(),
+                    Decimal::zero(),
+                    "user"
+                )
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::AmmSwapExactAmountIn { .. })
+        ));
+        assert_eq!(
+            builder
+                .swap_exact_amount_in(
+                    coin(1000, "uatom"),
+                    &routes,
+                    Int128::new(-1),
+                    Decimal::zero(),
+                    "user"
+                )
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "token_out_min_amount".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder
+                .swap_by_denom(
+                    coin(1000, "uatom"),
+                    (coin(0, "uusdc"), coin(0, "uusdc")),
+                    "uusdc",
+                    Decimal::zero(),
+                    "user"
+                )
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::AmmSwapByDenom { .. })
+        ));
+        assert_eq!(
+            builder
+                .swap_by_denom(
+                    coin(0, "uatom"),
+                    (coin(0, "uusdc"), coin(0, "uusdc")),
+                    "uusdc",
+                    Decimal::zero(),
+                    "user"
+                )
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "amount".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder
+                .join_pool(1, vec![coin(1000, "uatom")], Uint128::new(10), true)
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::AmmJoinPool { pool_id: 1, .. })
+        ));
+        assert_eq!(
+            builder
+                .join_pool(1, vec![coin(1000, "")], Uint128::new(10), true)
+                .unwrap_err(),
+            ElysBindingsError::InvalidDenom {
+                field: "max_amounts_in".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder
+                .exit_pool(1, vec![coin(1, "uusdc")], Uint128::new(10), "uusdc")
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::AmmExitPool { pool_id: 1, .. })
+        ));
+        assert_eq!(
+            builder
+                .exit_pool(1, vec![coin(1, "")], Uint128::new(10), "uusdc")
+                .unwrap_err(),
+            ElysBindingsError::InvalidDenom {
+                field: "min_amounts_out".to_string(),
+            }
+        );
+        assert_eq!(
+            builder
+                .exit_pool(1, vec![], Uint128::new(10), "")
+                .unwrap_err(),
+            ElysBindingsError::InvalidDenom {
+                field: "token_out_denom".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn estaking_msgs() {
+        let api = MockApi::default();
+        let builder = EstakingMsgBuilder::new(&api, "user").unwrap();
+
+        assert!(matches!(
+            builder
+                .begin_redelegate("validator-a", "validator-b", coin(1000, "uelys"))
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::IncentiveBeginRedelegate { .. })
+        ));
+        assert_eq!(
+            builder
+                .begin_redelegate("validator-a", "validator-b", coin(0, "uelys"))
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "amount".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder
+                .cancel_unbonding("validator-a", coin(1000, "uelys"), 10)
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::IncentiveCancelUnbondingDelegation { .. })
+        ));
+        assert_eq!(
+            builder
+                .cancel_unbonding("validator-a", coin(1000, "uelys"), 0)
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "creation_height".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn masterchef_msgs() {
+        let api = MockApi::default();
+
+        let builder = MasterchefMsgBuilder::new(&api, "user").unwrap();
+        assert!(matches!(
+            builder.claim_rewards(vec![1, 2]),
+            CosmosMsg::Custom(ElysMsg::MasterchefClaimRewards { .. })
+        ));
+        assert!(matches!(
+            MasterchefMsgBuilder::new(&api, "User"),
+            Err(ElysBindingsError::Std(_))
+        ));
+    }
+
+    #[test]
+    fn leveragelp_msgs() {
+        let api = MockApi::default();
+        let params = LeveragelpParams {
+            leverage_max: Decimal::from_str("5").unwrap(),
+            max_open_positions: 100,
+            pool_open_threshold: Decimal::zero(),
+            safety_factor: Decimal::zero(),
+            whitelisting_enabled: false,
+            epoch_length: 1,
+        };
+        let builder = LeveragelpMsgBuilder::new(&api, "user")
+            .unwrap()
+            .with_params(&params);
+
+        assert!(matches!(
+            builder
+                .open(
+                    1,
+                    coin(1000, "uusdc"),
+                    SignedDecimal::percent(200),
+                    SignedDecimal::zero()
+                )
+                .unwrap(),
+            CosmosMsg::Custom(ElysMsg::LeveragelpOpen { amm_pool_id: 1, .. })
+        ));
+        assert!(matches!(
+            builder.open(
+                1,
+                coin(1000, "uusdc"),
+                SignedDecimal::percent(600),
+                SignedDecimal::zero()
+            ),
+            Err(ElysBindingsError::InvalidLeverage { .. })
+        ));
+
+        assert!(matches!(
+            builder.close(1, Int128::new(10)).unwrap(),
+            CosmosMsg::Custom(ElysMsg::LeveragelpClose { id: 1, .. })
+        ));
+        assert_eq!(
+            builder.close(1, Int128::zero()).unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "lp_amount".to_string(),
+            }
+        );
+
+        assert!(matches!(
+            builder.update_stop_loss(1, SignedDecimal::zero()).unwrap(),
+            CosmosMsg::Custom(ElysMsg::LeveragelpUpdateStopLoss { position: 1, .. })
+        ));
+        assert_eq!(
+            builder
+                .update_stop_loss(1, SignedDecimal::from_str("-1").unwrap())
+                .unwrap_err(),
+            ElysBindingsError::InvalidAmount {
+                field: "price".to_string(),
+            }
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use cosmwasm_std::{
+    Addr, Api, Coin, CosmosMsg, Decimal, Int128, SignedDecimal, SignedDecimal256, Uint128,
+};
+
+use super::{checked_leverage, positive_coin, positive_int, positive_uint};
+use crate::{query_resp::PerpetualParams, types::PerpetualPosition, ElysBindingsError, ElysMsg};
+
+/// Builds the perpetual messages sent by `creator` for the positions of `owner`.
+pub struct PerpetualMsgBuilder {
+    creator: Addr,
+    owner: Addr,
+    leverage_max: Option<Decimal>,
+}
+
+impl PerpetualMsgBuilder {
+    pub fn new(
+        api: &dyn Api,
+        creator: impl Into<String>,
+        owner: impl Into<String>,
+    ) -> Result<Self, ElysBindingsError> {
+        Ok(Self {
+            creator: api.addr_validate(&creator.into())?,
+            owner: api.addr_validate(&owner.into())?,
+            leverage_max: None,
+        })
+    }
+
+    /// Keeps the leverage bound of the module to check the positions opened.
+    pub fn with_params(mut self, params: &PerpetualParams) -> Self {
+        self.leverage_max = Some(params.leverage_max);
+        self
+    }
+
+    pub fn open(
+        &self,
+        collateral: Coin,
+        trading_asset: impl Into<String>,
+        position: PerpetualPosition,
+        leverage: SignedDecimal,
+        take_profit_price: Option<SignedDecimal256>,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let collateral = positive_coin("collateral", collateral)?;
+        if position == PerpetualPosition::Unspecified {
+            return Err(ElysBindingsError::InvalidPosition {
+                value: position.to_string(),
+            });
+        }
+        let leverage_max = self
+            .leverage_max
+            .ok_or_else(|| ElysBindingsError::NotFound {
+                kind: "perpetual params".to_string(),
+            })?;
+        let leverage = checked_leverage(leverage, leverage_max)?;
+        if take_profit_price.map_or(false, |price| price <= SignedDecimal256::zero()) {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "take_profit_price".to_string(),
+            });
+        }
+
+        Ok(ElysMsg::perpetual_open_position(
+            &self.creator,
+            collateral,
+            trading_asset,
+            position,
+            leverage,
+            take_profit_price,
+            &self.owner,
+        )
+        .into())
+    }
+
+    pub fn close(&self, id: u64, amount: Int128) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_int("amount", amount)?;
+
+        Ok(ElysMsg::perpetual_close_position(&self.creator, id, amount.i128(), &self.owner).into())
+    }
+
+    pub fn add_collateral(
+        &self,
+        id: u64,
+        amount: Uint128,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        let amount = positive_uint("amount", amount)?;
+
+        Ok(ElysMsg::perpetual_add_collateral(&self.creator, id, amount.u128(), &self.owner).into())
+    }
+
+    pub fn update_take_profit_price(
+        &self,
+        id: u64,
+        price: SignedDecimal256,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        if price <= SignedDecimal256::zero() {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "price".to_string(),
+            });
+        }
+
+        Ok(
+            ElysMsg::perpetual_update_take_profit_price(&self.creator, id, price, &self.owner)
+                .into(),
+        )
+    }
+
+    /// A zero `price` removes the stop loss of the position.
+    pub fn update_stop_loss(
+        &self,
+        id: u64,
+        price: SignedDecimal,
+    ) -> Result<CosmosMsg<ElysMsg>, ElysBindingsError> {
+        if price < SignedDecimal::zero() {
+            return Err(ElysBindingsError::InvalidAmount {
+                field: "price".to_string(),
+            });
+        }
+
+        Ok(ElysMsg::perpetual_update_stop_loss(&self.creator, id, price, &self.owner).into())
+    }
+}
@@ -0,0 +1,414 @@
+//! Pure perpetual position math, so that contracts and off-chain services can
+//! estimate positions without a chain round-trip. It follows the formulas of
+//! the perpetual module but is only tested against synthetic fixtures, not
+//! against outputs recorded from a chain.
+//! Amounts are atomic amounts of their denom and values are in USD.
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Int128, Int256, SignedDecimal, SignedDecimal256, StdError, StdResult};
+
+use crate::query_resp::PerpetualParams;
+use crate::types::{Mtp, PerpetualPosition};
+
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
+fn generic_err(e: impl ToString) -> StdError {
+    StdError::generic_err(e.to_string())
+}
+
+// an atomic amount is scaled by 10^18 as a decimal, which overflows 128 bits
+// from a few hundred tokens of 18 decimals
+fn atomics(amount: Int128) -> StdResult<SignedDecimal256> {
+    SignedDecimal256::from_atomics(amount, 0).map_err(generic_err)
+}
+
+fn floor(value: SignedDecimal256) -> StdResult<Int128> {
+    Int128::try_from(value.to_int_floor()).map_err(generic_err)
+}
+
+/// Oracle price of a whole token with the decimals of its denom.
+#[cw_serde]
+pub struct AssetPrice {
+    pub price: SignedDecimal,
+    pub decimals: u32,
+}
+
+impl AssetPrice {
+    pub fn new(price: SignedDecimal, decimals: u32) -> Self {
+        Self { price, decimals }
+    }
+
+    /// Number of whole tokens in an atomic amount.
+    pub fn tokens(&self, amount: Int128) -> StdResult<SignedDecimal> {
+        SignedDecimal::from_atomics(amount, self.decimals).map_err(generic_err)
+    }
+
+    pub fn value(&self, amount: Int128) -> StdResult<SignedDecimal> {
+        self.tokens(amount)?
+            .checked_mul(self.price)
+            .map_err(generic_err)
+    }
+
+    /// Atomic amount worth `value`, rounded down.
+    pub fn amount(&self, value: SignedDecimal) -> StdResult<Int128> {
+        let unit = SignedDecimal256::from_atomics(Int256::from(10i128.pow(self.decimals)), 0)
+            .map_err(generic_err)?;
+        let tokens = SignedDecimal256::from(value.checked_div(self.price).map_err(generic_err)?);
+        floor(tokens.checked_mul(unit).map_err(generic_err)?)
+    }
+}
+
+/// Prices of the assets of a position, every denom of the position other than
+/// its trading asset is valued at the base currency price.
+#[cw_serde]
+pub struct MarketPrices {
+    pub trading_asset: AssetPrice,
+    pub base_currency: AssetPrice,
+}
+
+impl MarketPrices {
+    pub fn of(&self, mtp: &Mtp, denom: &str) -> &AssetPrice {
+        if denom == mtp.trading_asset {
+            &self.trading_asset
+        } else {
+            &self.base_currency
+        }
+    }
+
+    fn value(&self, mtp: &Mtp, denom: &str, amount: Int128) -> StdResult<SignedDecimal> {
+        self.of(mtp, denom).value(amount)
+    }
+
+    /// Splits an amount between its base currency value and its trading asset
+    /// tokens, whose value depends on the trading asset price.
+    fn exposure(&self, mtp: &Mtp, denom: &str, amount: Int128) -> StdResult<Exposure> {
+        if denom == mtp.trading_asset {
+            Ok(Exposure {
+                base: SignedDecimal::zero(),
+                trading: self.trading_asset.tokens(amount)?,
+            })
+        } else {
+            Ok(Exposure {
+                base: self.base_currency.value(amount)?,
+                trading: SignedDecimal::zero(),
+            })
+        }
+    }
+}
+
+struct Exposure {
+    base: SignedDecimal,
+    trading: SignedDecimal,
+}
+
+impl Exposure {
+    fn add(self, other: Exposure) -> StdResult<Exposure> {
+        Ok(Exposure {
+            base: self.base.checked_add(other.base)?,
+            trading: self.trading.checked_add(other.trading)?,
+        })
+    }
+}
+
+/// What is returned to the owner when closing part of a position.
+#[cw_serde]
+pub struct CloseOut {
+    pub closed_custody: Int128,
+    pub repaid_liabilities: Int128,
+    pub repaid_borrow_interest: Int128, // unpaid interest settled with the close, in the collateral asset
+    pub swap_fee: SignedDecimal,
+    pub returned_amount: Int128, // in the collateral asset, zero when the close leaves bad debt
+    pub realized_pnl: SignedDecimal,
+}
+
+fn debt_value(mtp: &Mtp, prices: &MarketPrices) -> StdResult<SignedDecimal> {
+    prices
+        .value(mtp, &mtp.liabilities_asset, mtp.liabilities)?
+        .checked_add(prices.value(
+            mtp,
+            &mtp.collateral_asset,
+            mtp.borrow_interest_unpaid_collateral,
+        )?)
+        .map_err(generic_err)
+}
+
+/// Custody value over the liabilities and unpaid borrow interest value, zero
+/// when the position has no debt.
+pub fn health(mtp: &Mtp, prices: &MarketPrices) -> StdResult<SignedDecimal> {
+    let debt = debt_value(mtp, prices)?;
+    if debt.is_zero() {
+        return Ok(SignedDecimal::zero());
+    }
+
+    prices
+        .value(mtp, &mtp.custody_asset, mtp.custody)?
+        .checked_div(debt)
+        .map_err(generic_err)
+}
+
+/// Value of the custody left once the debt is repaid, minus the collateral.
+pub fn unrealized_pnl(mtp: &Mtp, prices: &MarketPrices) -> StdResult<SignedDecimal> {
+    Ok(prices
+        .value(mtp, &mtp.custody_asset, mtp.custody)?
+        .checked_sub(debt_value(mtp, prices)?)?
+        .checked_sub(prices.value(mtp, &mtp.collateral_asset, mtp.collateral)?)?)
+}
+
+/// Trading asset price at which the health of the position drops to the
+/// safety factor, zero when no price move can liquidate it.
+pub fn liquidation_price(
+    mtp: &Mtp,
+    prices: &MarketPrices,
+    params: &PerpetualParams,
+) -> StdResult<SignedDecimal> {
+    let safety_factor = SignedDecimal::try_from(params.safety_factor).map_err(generic_err)?;
+    let custody = prices.exposure(mtp, &mtp.custody_asset, mtp.custody)?;
+    let debt = prices
+        .exposure(mtp, &mtp.liabilities_asset, mtp.liabilities)?
+        .add(prices.exposure(
+            mtp,
+            &mtp.collateral_asset,
+            mtp.borrow_interest_unpaid_collateral,
+        )?)?;
+
+    // custody.base + custody.trading * price = safety_factor * (debt.base + debt.trading * price)
+    let numerator = safety_factor
+        .checked_mul(debt.base)?
+        .checked_sub(custody.base)?;
+    let denominator = custody
+        .trading
+        .checked_sub(safety_factor.checked_mul(debt.trading)?)?;
+    if denominator.is_zero() {
+        return Ok(SignedDecimal::zero());
+    }
+
+    let price = numerator.checked_div(denominator).map_err(generic_err)?;
+    Ok(price.max(SignedDecimal::zero()))
+}
+
+fn accrued(
+    amount: Int128,
+    rate: SignedDecimal,
+    from: Option<u64>,
+    now: u64,
+) -> StdResult<SignedDecimal256> {
+    let elapsed = from.map_or(0, |from| now.saturating_sub(from));
+    let period = SignedDecimal256::from_ratio(elapsed as i128, SECONDS_PER_YEAR as i128);
+
+    Ok(atomics(amount)?
+        .checked_mul(SignedDecimal256::from(rate))?
+        .checked_mul(period)?)
+}
+
+/// Borrow interest accrued on the liabilities since the last interest
+/// calculation at the yearly `borrow_rate`, in the liabilities asset. Like the
+/// perpetual module, a non-zero accrual rounds up to at least one unit.
+pub fn borrow_interest(mtp: &Mtp, borrow_rate: SignedDecimal, now: u64) -> StdResult<Int128> {
+    let interest = accrued(
+        mtp.liabilities,
+        borrow_rate,
+        mtp.last_interest_calc_time,
+        now,
+    )?;
+    if interest.is_zero() {
+        return Ok(Int128::zero());
+    }
+
+    Ok(floor(interest)?.max(Int128::one()))
+}
+
+/// Funding fee accrued on the custody since the last funding calculation, in
+/// the custody asset. The yearly `funding_rate` is capped by the params, longs
+/// pay it when positive and shorts when negative: the result is positive when
+/// the position pays and negative when it receives.
+pub fn funding_fee(
+    mtp: &Mtp,
+    funding_rate: SignedDecimal,
+    params: &PerpetualParams,
+    now: u64,
+) -> StdResult<Int128> {
+    let max_rate = SignedDecimal::try_from(params.funding_fee_max_rate).map_err(generic_err)?;
+    let rate = funding_rate.clamp(-max_rate, max_rate);
+
+    let magnitude = if rate.is_negative() { -rate } else { rate };
+    let fee = floor(accrued(
+        mtp.custody,
+        magnitude,
+        mtp.last_funding_calc_time,
+        now,
+    )?)?;
+    match PerpetualPosition::try_from_i32(mtp.position)? {
+        PerpetualPosition::Long if rate.is_negative() => Ok(-fee),
+        PerpetualPosition::Short if !rate.is_negative() => Ok(-fee),
+        PerpetualPosition::Unspecified => Err(StdError::generic_err("Position is Unspecified")),
+        _ => Ok(fee),
+    }
+}
+
+/// Closes `amount` of the custody: the matching share of the debt is repaid,
+/// the swap between the custody and liabilities assets pays the swap fee and
+/// what is left goes back to the owner.
+pub fn close_out(
+    mtp: &Mtp,
+    prices: &MarketPrices,
+    params: &PerpetualParams,
+    amount: Int128,
+) -> StdResult<CloseOut> {
+    if amount <= Int128::zero() || amount > mtp.custody {
+        return Err(StdError::generic_err(format!(
+            "amount: must be between 1 and the custody {}",
+            mtp.custody
+        )));
+    }
+
+    let ratio = SignedDecimal256::from_ratio(amount, mtp.custody);
+    let share =
+        |amount: Int128| -> StdResult<Int128> { floor(atomics(amount)?.checked_mul(ratio)?) };
+    let repaid_liabilities = share(mtp.liabilities)?;
+    let repaid_borrow_interest = share(mtp.borrow_interest_unpaid_collateral)?;
+    let closed_collateral = share(mtp.collateral)?;
+
+    let custody_value = prices.value(mtp, &mtp.custody_asset, amount)?;
+    let debt_value = prices
+        .value(mtp, &mtp.liabilities_asset, repaid_liabilities)?
+        .checked_add(prices.value(mtp, &mtp.collateral_asset, repaid_borrow_interest)?)?;
+
+    // longs sell their custody to repay, shorts buy back their liabilities
+    let swapped_value = match PerpetualPosition::try_from_i32(mtp.position)? {
+        PerpetualPosition::Long => custody_value,
+        PerpetualPosition::Short => debt_value,
+        PerpetualPosition::Unspecified => {
+            return Err(StdError::generic_err("Position is Unspecified"))
+        }
+    };
+    let swap_fee_rate = SignedDecimal::try_from(params.swap_fee).map_err(generic_err)?;
+    let swap_fee = swapped_value.checked_mul(swap_fee_rate)?;
+
+    let returned_value = custody_value
+        .checked_sub(debt_value)?
+        .checked_sub(swap_fee)?
+        .max(SignedDecimal::zero());
+    let collateral_price = prices.of(mtp, &mtp.collateral_asset);
+    let returned_amount = collateral_price.amount(returned_value)?;
+    let realized_pnl = returned_value.checked_sub(collateral_price.value(closed_collateral)?)?;
+
+    Ok(CloseOut {
+        closed_custody: amount,
+        repaid_liabilities,
+        repaid_borrow_interest,
+        swap_fee,
+        returned_amount,
+        realized_pnl,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_resp::{
+        OracleAssetInfoResponse, PerpetualMtpResponse, PerpetualParamsResponseRaw,
+    };
+    use crate::types::Price;
+    use cosmwasm_std::{from_json, Decimal};
+    use std::str::FromStr;
+
+    #[cw_serde]
+    struct Expected {
+        health: SignedDecimal,
+        unrealized_pnl: SignedDecimal,
+        liquidation_price: SignedDecimal,
+        borrow_interest: Int128,
+        funding_fee: Int128,
+        close_out: CloseOut,
+    }
+
+    // synthetic cases in the layout of the perpetual mtp and params queries and
+    // of the oracle asset info and price queries, with hand-computed values
+    #[cw_serde]
+    struct Fixtures {
+        source: String,
+        cases: Vec<Fixture>,
+    }
+
+    #[cw_serde]
+    struct Fixture {
+        name: String,
+        position: PerpetualMtpResponse,
+        params: PerpetualParamsResponseRaw,
+        trading_asset: OracleAssetInfoResponse,
+        base_currency: OracleAssetInfoResponse,
+        base_currency_price: Price,
+        now: u64,
+        borrow_rate: SignedDecimal,
+        funding_rate: SignedDecimal,
+        close_amount: Int128,
+        expected: Expected,
+    }
+
+    fn asset_price(price: Decimal, info: &OracleAssetInfoResponse) -> AssetPrice {
+        AssetPrice::new(
+            SignedDecimal::try_from(price).unwrap(),
+            info.asset_info.decimal as u32,
+        )
+    }
+
+    #[test]
+    fn fixtures() {
+        let fixtures: Fixtures = from_json(include_str!("fixtures/positions.json")).unwrap();
+        assert!(fixtures.source.starts_with("synthetic"));
+
+        for fixture in fixtures.cases {
+            let position = fixture.position.mtp.clone().unwrap();
+            let mtp = &position.mtp;
+            let prices = &MarketPrices {
+                trading_asset: asset_price(position.trading_asset_price, &fixture.trading_asset),
+                base_currency: asset_price(
+                    fixture.base_currency_price.price,
+                    &fixture.base_currency,
+                ),
+            };
+            let params: PerpetualParams = fixture.params.params.clone().unwrap().into();
+            let name = fixture.name.as_str();
+            let expected = &fixture.expected;
+
+            assert_eq!(health(mtp, prices).unwrap(), expected.health, "{name}");
+            assert_eq!(
+                unrealized_pnl(mtp, prices).unwrap(),
+                expected.unrealized_pnl,
+                "{name}"
+            );
+            assert_eq!(
+                liquidation_price(mtp, prices, &params).unwrap(),
+                expected.liquidation_price,
+                "{name}"
+            );
+            assert_eq!(
+                borrow_interest(mtp, fixture.borrow_rate, fixture.now).unwrap(),
+                expected.borrow_interest,
+                "{name}"
+            );
+            assert_eq!(
+                funding_fee(mtp, fixture.funding_rate, &params, fixture.now).unwrap(),
+                expected.funding_fee,
+                "{name}"
+            );
+            assert_eq!(
+                close_out(mtp, prices, &params, fixture.close_amount).unwrap(),
+                expected.close_out,
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn asset_price_round_trip() {
+        let price = AssetPrice::new(SignedDecimal::from_str("2500").unwrap(), 18);
+        let value = price.value(Int128::new(1_500_000_000_000_000_000)).unwrap();
+
+        assert_eq!(value, SignedDecimal::from_str("3750").unwrap());
+        assert_eq!(
+            price.amount(value).unwrap(),
+            Int128::new(1_500_000_000_000_000_000)
+        );
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
         StakingValidator,
     },
     types::{BalanceAvailable, PageRequest, PerpetualPosition, Price, SwapAmountInRoute},
+    ElysBindingsError,
 };
 
 pub struct ElysQuerier<'a> {
@@ -636,7 +637,7 @@ impl<'a> ElysQuerier<'a> {
         }
     }
 
-    pub fn get_asset_price(&self, asset: impl Into<String>) -> StdResult<Decimal> {
+    pub fn get_asset_price(&self, asset: impl Into<String>) -> Result<Decimal, ElysBindingsError> {
         let mut asset: String = asset.into();
 
         if asset == "ueden" {
@@ -650,14 +651,22 @@ impl<'a> ElysQuerier<'a> {
                     display_name,
                     ..
                 },
-        } = self.get_asset_profile("uusdc".to_string())?;
+        } = self.get_asset_profile("uusdc".to_string()).map_err(|_| {
+            ElysBindingsError::AssetProfileNotFound {
+                denom: "uusdc".to_string(),
+            }
+        })?;
 
         let QueryGetPriceResponse {
             price: Price {
                 price: usdc_usd_price,
                 ..
             },
-        } = self.get_oracle_price(display_name, "".to_string(), 0)?;
+        } = self
+            .get_oracle_price(display_name, "".to_string(), 0)
+            .map_err(|_| ElysBindingsError::PriceNotFound {
+                denom: usdc_denom.clone(),
+            })?;
 
         if asset == usdc_denom {
             return Ok(usdc_usd_price);
@@ -684,10 +693,8 @@ impl<'a> ElysQuerier<'a> {
         let asset_usdc_price = match oracle_price {
             Some(price) => price,
             None => self
-                .get_amm_price_by_denom(coin(1000000, asset), Decimal::one())
-                .map_err(|e| {
-                    StdError::generic_err(format!("get_asset_price: spot price not found:{:?}", e))
-                })?,
+                .get_amm_price_by_denom(coin(1000000, &asset), Decimal::one())
+                .map_err(|_| ElysBindingsError::PriceNotFound { denom: asset })?,
         };
 
         //ATOM/USDC * USDC/USD_rate = ATOM/USD
@@ -700,16 +707,11 @@ impl<'a> ElysQuerier<'a> {
         &self,
         denom_in: impl Into<String>,
         denom_out: impl Into<String>,
-    ) -> StdResult<Decimal> {
+    ) -> Result<Decimal, ElysBindingsError> {
         let price_in = self.get_asset_price(denom_in)?;
         let price_out = self.get_asset_price(denom_out)?;
 
-        price_in.checked_div(price_out).map_err(|e| {
-            StdError::generic_err(format!(
-                "get_asset_price_from_denom_in_to_denom_out: price calculation error : {:?}",
-                e
-            ))
-        })
+        Ok(price_in.checked_div(price_out)?)
     }
 
     pub fn masterchef_params(&self) -> StdResult<MasterchefParamsResponse> {
@@ -551,10 +551,10 @@ pub struct QueryEarnPoolResponse {
 
 #[cw_serde]
 pub struct QueryJoinPoolEstimationResponse {
-    amounts_in: Vec<Coin>,
-    share_amount_out: Coin,
-    slippage: Decimal,
-    weight_balance_ratio: SignedDecimal,
+    pub amounts_in: Vec<Coin>,
+    pub share_amount_out: Coin,
+    pub slippage: Decimal,
+    pub weight_balance_ratio: SignedDecimal,
 }
 
 #[cw_serde]
@@ -1,4 +1,7 @@
-use crate::types::PageRequest;
+use crate::types::{page_bounds, page_keys, PageItems, PageRequest};
+use cosmwasm_std::testing::MockStorage;
+use cosmwasm_std::Order;
+use cw_storage_plus::{Bound, Map};
 
 #[test]
 fn pagination_filter_test() {
@@ -30,3 +33,64 @@ fn pagination_filter_test() {
     assert_eq!(res, last);
     assert!(p_res.next_key.is_none());
 }
+
+#[test]
+fn pagination_paginate_test() {
+    const ITEMS: Map<u64, u64> = Map::new("items");
+
+    let mut storage = MockStorage::new();
+    for id in 1..=6 {
+        ITEMS.save(&mut storage, id, &(id * 10)).unwrap();
+    }
+
+    // only even ids are visible to the query
+    let range = |cursor: Option<u64>, order: Order| -> PageItems<u64, u64> {
+        let (min, max) = page_bounds(cursor.map(Bound::exclusive), order);
+        Box::new(
+            ITEMS
+                .range(&storage, min, max, order)
+                .filter(|res| res.as_ref().map_or(true, |(id, _)| id % 2 == 0)),
+        )
+    };
+
+    let mut pagination = PageRequest::new(2);
+    pagination.count_total = true;
+
+    let (res, p_res) = pagination.paginate(range).unwrap();
+    assert_eq!(res, vec![20, 40]);
+    assert_eq!(p_res.total, Some(3));
+
+    pagination.update(p_res.next_key);
+    let (res, p_res) = pagination.paginate(range).unwrap();
+    assert_eq!(res, vec![60]);
+    assert!(p_res.next_key.is_none());
+    assert_eq!(p_res.total, Some(3));
+
+    let mut pagination = PageRequest::new(1);
+    pagination.reverse = true;
+    pagination.offset = Some(1);
+
+    let (res, p_res) = pagination.paginate(range).unwrap();
+    assert_eq!(res, vec![40]);
+    assert!(p_res.total.is_none());
+
+    pagination.update(p_res.next_key);
+    pagination.offset = None;
+    let (res, p_res) = pagination.paginate(range).unwrap();
+    assert_eq!(res, vec![20]);
+    assert!(p_res.next_key.is_none());
+}
+
+#[test]
+fn pagination_page_keys_test() {
+    let keys = vec![3u64, 1, 2, 5, 4];
+
+    assert_eq!(
+        page_keys(keys.clone(), Some(2), Order::Ascending).collect::<Vec<_>>(),
+        vec![3, 4, 5]
+    );
+    assert_eq!(
+        page_keys(keys, Some(4), Order::Descending).collect::<Vec<_>>(),
+        vec![3, 2, 1]
+    );
+}
@@ -0,0 +1,525 @@
+use cosmwasm_std::{Coin, Decimal, Event, Uint128};
+use std::fmt;
+
+use super::types::{
+    AutoCompound, Batch, CompoundRun, ConditionalOrder, DiscountOverride, Grid, LiquidationGuard,
+    Mtp, PerpetualOrderV2, Role, SpotOrder, TradingGrant, TradingPermission,
+};
+
+pub const ORDER_CREATED: &str = "trade_shield_order_created";
+pub const ORDER_AMENDED: &str = "trade_shield_order_amended";
+pub const ORDER_TRIGGERED: &str = "trade_shield_order_triggered";
+pub const ORDER_EXECUTED: &str = "trade_shield_order_executed";
+pub const ORDER_CANCELLED: &str = "trade_shield_order_cancelled";
+pub const ORDER_FAILED: &str = "trade_shield_order_failed";
+pub const PARAMS_CHANGED: &str = "trade_shield_params_changed";
+pub const FEES_WITHDRAWN: &str = "trade_shield_fees_withdrawn";
+pub const REFERRER_SET: &str = "trade_shield_referrer_set";
+pub const DISCOUNT_OVERRIDE_SET: &str = "trade_shield_discount_override_set";
+pub const DISCOUNT_OVERRIDE_REMOVED: &str = "trade_shield_discount_override_removed";
+pub const PAUSE_CHANGED: &str = "trade_shield_pause_changed";
+pub const ROLE_GRANTED: &str = "trade_shield_role_granted";
+pub const ROLE_REVOKED: &str = "trade_shield_role_revoked";
+pub const LIQUIDATION_GUARD_CREATED: &str = "trade_shield_liquidation_guard_created";
+pub const LIQUIDATION_GUARD_TRIGGERED: &str = "trade_shield_liquidation_guard_triggered";
+pub const LIQUIDATION_GUARD_FAILED: &str = "trade_shield_liquidation_guard_failed";
+pub const LIQUIDATION_GUARD_CLOSED: &str = "trade_shield_liquidation_guard_closed";
+pub const GRANT_SET: &str = "trade_shield_grant_set";
+pub const GRANT_REVOKED: &str = "trade_shield_grant_revoked";
+pub const GRANT_USED: &str = "trade_shield_grant_used";
+pub const GRID_CREATED: &str = "trade_shield_grid_created";
+pub const GRID_CLOSED: &str = "trade_shield_grid_closed";
+pub const AUTO_COMPOUND_SUBSCRIBED: &str = "trade_shield_auto_compound_subscribed";
+pub const AUTO_COMPOUND_UNSUBSCRIBED: &str = "trade_shield_auto_compound_unsubscribed";
+pub const AUTO_COMPOUND_RUN: &str = "trade_shield_auto_compound_run";
+pub const AUTO_COMPOUND_FAILED: &str = "trade_shield_auto_compound_failed";
+pub const BATCH_EXECUTED: &str = "trade_shield_batch_executed";
+pub const CONDITIONAL_ORDER_CREATED: &str = "trade_shield_conditional_order_created";
+pub const CONDITIONAL_ORDER_TRIGGERED: &str = "trade_shield_conditional_order_triggered";
+pub const CONDITIONAL_ORDER_CANCELLED: &str = "trade_shield_conditional_order_cancelled";
+pub const CONDITIONAL_ORDER_FAILED: &str = "trade_shield_conditional_order_failed";
+pub const POOL_JOINED: &str = "trade_shield_pool_joined";
+pub const POOL_EXITED: &str = "trade_shield_pool_exited";
+pub const POSITION_CLOSED: &str = "trade_shield_position_closed";
+pub const COLLATERAL_ADDED: &str = "trade_shield_collateral_added";
+
+pub const ORDER_ID_KEY: &str = "order_id";
+pub const ORDER_KIND_KEY: &str = "order_kind";
+pub const ORDER_TYPE_KEY: &str = "order_type";
+pub const OWNER_KEY: &str = "owner";
+pub const PAIR_KEY: &str = "pair";
+pub const PRICE_KEY: &str = "price";
+pub const AMOUNTS_KEY: &str = "amounts";
+pub const REASON_KEY: &str = "reason";
+pub const FEE_KEY: &str = "fee";
+pub const RECIPIENT_KEY: &str = "recipient";
+pub const REFERRER_KEY: &str = "referrer";
+pub const ADDRESS_KEY: &str = "address";
+pub const DISCOUNT_KEY: &str = "discount";
+pub const EXPIRES_AT_KEY: &str = "expires_at";
+pub const PAUSED_KEY: &str = "paused";
+pub const ROLE_KEY: &str = "role";
+pub const POSITION_ID_KEY: &str = "position_id";
+pub const HEALTH_THRESHOLD_KEY: &str = "health_threshold";
+pub const REFUND_KEY: &str = "refund";
+pub const GRANTER_KEY: &str = "granter";
+pub const GRANTEE_KEY: &str = "grantee";
+pub const PERMISSIONS_KEY: &str = "permissions";
+pub const PERMISSION_KEY: &str = "permission";
+pub const SPEND_LIMIT_KEY: &str = "spend_limit";
+pub const GRID_ID_KEY: &str = "grid_id";
+pub const LEVELS_KEY: &str = "levels";
+pub const PROFIT_KEY: &str = "realised_profit";
+pub const INTERVAL_KEY: &str = "interval";
+pub const CLAIMED_KEY: &str = "claimed";
+pub const COMPOUNDED_KEY: &str = "compounded";
+pub const STEPS_KEY: &str = "steps";
+pub const OUTPUTS_KEY: &str = "outputs";
+pub const DIRECTION_KEY: &str = "direction";
+pub const POOL_ID_KEY: &str = "pool_id";
+pub const SHARES_KEY: &str = "shares";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrderKind {
+    Spot,
+    Perpetual,
+}
+
+impl fmt::Display for OrderKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderKind::Spot => write!(f, "spot"),
+            OrderKind::Perpetual => write!(f, "perpetual"),
+        }
+    }
+}
+
+/// The attributes shared by every order lifecycle event.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrderAttributes {
+    pub kind: OrderKind,
+    pub order_id: u64,
+    pub order_type: String,
+    pub owner: String,
+    pub pair: String,
+    pub price: Option<Decimal>,
+    pub amounts: Vec<Coin>,
+}
+
+impl From<&SpotOrder> for OrderAttributes {
+    fn from(order: &SpotOrder) -> Self {
+        let price = order.trigger();
+        // market orders have no price, the pair is the swapped denoms
+        let (pair, price) = if price.rate.is_zero() {
+            (
+                format!("{}/{}", order.order_amount.denom, order.order_target_denom),
+                None,
+            )
+        } else {
+            (
+                format!("{}/{}", price.base_denom, price.quote_denom),
+                Some(price.rate),
+            )
+        };
+        Self {
+            kind: OrderKind::Spot,
+            order_id: order.order_id,
+            order_type: order.order_type.to_string(),
+            owner: order.owner_address.to_string(),
+            pair,
+            price,
+            amounts: vec![order.order_amount.clone()],
+        }
+    }
+}
+
+impl From<&PerpetualOrderV2> for OrderAttributes {
+    fn from(order: &PerpetualOrderV2) -> Self {
+        let pair = match &order.trigger_price {
+            Some(price) => format!("{}/{}", price.base_denom, price.quote_denom),
+            None => format!("{}/{}", order.collateral.denom, order.trading_asset),
+        };
+        Self {
+            kind: OrderKind::Perpetual,
+            order_id: order.order_id,
+            order_type: order.order_type.to_string(),
+            owner: order.owner.clone(),
+            pair,
+            price: order.trigger_price.as_ref().map(|price| price.rate),
+            amounts: vec![order.collateral.clone()],
+        }
+    }
+}
+
+impl OrderAttributes {
+    fn into_event(self, ty: &str) -> Event {
+        let amounts: Vec<String> = self.amounts.iter().map(|coin| coin.to_string()).collect();
+        let event = Event::new(ty)
+            .add_attribute(ORDER_KIND_KEY, self.kind.to_string())
+            .add_attribute(ORDER_ID_KEY, self.order_id.to_string())
+            .add_attribute(ORDER_TYPE_KEY, self.order_type)
+            .add_attribute(OWNER_KEY, self.owner)
+            .add_attribute(PAIR_KEY, self.pair)
+            .add_attribute(AMOUNTS_KEY, amounts.join(","));
+        match self.price {
+            Some(price) => event.add_attribute(PRICE_KEY, price.to_string()),
+            None => event,
+        }
+    }
+}
+
+pub fn order_created(order: impl Into<OrderAttributes>) -> Event {
+    order.into().into_event(ORDER_CREATED)
+}
+
+pub fn order_amended(order: impl Into<OrderAttributes>) -> Event {
+    order.into().into_event(ORDER_AMENDED)
+}
+
+pub fn order_triggered(order: impl Into<OrderAttributes>) -> Event {
+    order.into().into_event(ORDER_TRIGGERED)
+}
+
+pub fn order_executed(order: impl Into<OrderAttributes>) -> Event {
+    order.into().into_event(ORDER_EXECUTED)
+}
+
+pub fn order_cancelled(order: impl Into<OrderAttributes>, reason: impl Into<String>) -> Event {
+    order
+        .into()
+        .into_event(ORDER_CANCELLED)
+        .add_attribute(REASON_KEY, reason)
+}
+
+pub fn order_failed(order: impl Into<OrderAttributes>, reason: impl Into<String>) -> Event {
+    order
+        .into()
+        .into_event(ORDER_FAILED)
+        .add_attribute(REASON_KEY, reason)
+}
+
+/// One attribute per updated parameter, keyed by the parameter name.
+pub fn params_changed(owner: impl Into<String>, changes: Vec<(&str, String)>) -> Event {
+    Event::new(PARAMS_CHANGED)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attributes(changes)
+}
+
+pub fn fees_withdrawn(recipient: impl Into<String>, amounts: &[Coin]) -> Event {
+    let amounts: Vec<String> = amounts.iter().map(|coin| coin.to_string()).collect();
+    Event::new(FEES_WITHDRAWN)
+        .add_attribute(RECIPIENT_KEY, recipient)
+        .add_attribute(AMOUNTS_KEY, amounts.join(","))
+}
+
+pub fn referrer_set(owner: impl Into<String>, referrer: impl Into<String>) -> Event {
+    Event::new(REFERRER_SET)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(REFERRER_KEY, referrer)
+}
+
+pub fn discount_override_set(address: impl Into<String>, discount: &DiscountOverride) -> Event {
+    let event = Event::new(DISCOUNT_OVERRIDE_SET)
+        .add_attribute(ADDRESS_KEY, address)
+        .add_attribute(DISCOUNT_KEY, discount.discount.to_string());
+    match discount.expires_at {
+        Some(expires_at) => event.add_attribute(EXPIRES_AT_KEY, expires_at.seconds().to_string()),
+        None => event,
+    }
+}
+
+pub fn discount_override_removed(address: impl Into<String>) -> Event {
+    Event::new(DISCOUNT_OVERRIDE_REMOVED).add_attribute(ADDRESS_KEY, address)
+}
+
+pub fn pause_changed(owner: impl Into<String>, paused: bool) -> Event {
+    Event::new(PAUSE_CHANGED)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(PAUSED_KEY, paused.to_string())
+}
+
+pub fn role_granted(owner: impl Into<String>, role: &Role, address: impl Into<String>) -> Event {
+    Event::new(ROLE_GRANTED)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(ROLE_KEY, role.to_string())
+        .add_attribute(ADDRESS_KEY, address)
+}
+
+pub fn role_revoked(owner: impl Into<String>, role: &Role, address: impl Into<String>) -> Event {
+    Event::new(ROLE_REVOKED)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(ROLE_KEY, role.to_string())
+        .add_attribute(ADDRESS_KEY, address)
+}
+
+fn liquidation_guard_event(ty: &str, guard: &LiquidationGuard) -> Event {
+    Event::new(ty)
+        .add_attribute(OWNER_KEY, &guard.owner)
+        .add_attribute(POSITION_ID_KEY, guard.position_id.to_string())
+}
+
+pub fn liquidation_guard_created(guard: &LiquidationGuard) -> Event {
+    liquidation_guard_event(LIQUIDATION_GUARD_CREATED, guard)
+        .add_attribute(HEALTH_THRESHOLD_KEY, guard.health_threshold.to_string())
+        .add_attribute(AMOUNTS_KEY, guard.escrow.to_string())
+}
+
+/// Emitted once the top-up has been added to the position.
+pub fn liquidation_guard_triggered(guard: &LiquidationGuard, top_up: &Coin) -> Event {
+    liquidation_guard_event(LIQUIDATION_GUARD_TRIGGERED, guard)
+        .add_attribute(AMOUNTS_KEY, top_up.to_string())
+}
+
+pub fn liquidation_guard_failed(
+    guard: &LiquidationGuard,
+    top_up: &Coin,
+    reason: impl Into<String>,
+) -> Event {
+    liquidation_guard_event(LIQUIDATION_GUARD_FAILED, guard)
+        .add_attribute(AMOUNTS_KEY, top_up.to_string())
+        .add_attribute(REASON_KEY, reason)
+}
+
+/// Emitted when a guard is removed, with the escrow refunded to its owner.
+pub fn liquidation_guard_closed(guard: &LiquidationGuard, reason: impl Into<String>) -> Event {
+    liquidation_guard_event(LIQUIDATION_GUARD_CLOSED, guard)
+        .add_attribute(REFUND_KEY, guard.escrow.to_string())
+        .add_attribute(REASON_KEY, reason)
+}
+
+pub fn grant_set(grant: &TradingGrant) -> Event {
+    let permissions: Vec<&str> = grant.permissions.iter().map(|p| p.as_str()).collect();
+    let spend_limit: Vec<String> = grant.spend_limit.iter().map(|c| c.to_string()).collect();
+    let event = Event::new(GRANT_SET)
+        .add_attribute(GRANTER_KEY, &grant.granter)
+        .add_attribute(GRANTEE_KEY, &grant.grantee)
+        .add_attribute(PERMISSIONS_KEY, permissions.join(","))
+        .add_attribute(SPEND_LIMIT_KEY, spend_limit.join(","));
+    match grant.expires_at {
+        Some(expires_at) => event.add_attribute(EXPIRES_AT_KEY, expires_at.seconds().to_string()),
+        None => event,
+    }
+}
+
+pub fn grant_revoked(granter: impl Into<String>, grantee: impl Into<String>) -> Event {
+    Event::new(GRANT_REVOKED)
+        .add_attribute(GRANTER_KEY, granter)
+        .add_attribute(GRANTEE_KEY, grantee)
+}
+
+pub fn grant_used(
+    granter: impl Into<String>,
+    grantee: impl Into<String>,
+    permission: &TradingPermission,
+) -> Event {
+    Event::new(GRANT_USED)
+        .add_attribute(GRANTER_KEY, granter)
+        .add_attribute(GRANTEE_KEY, grantee)
+        .add_attribute(PERMISSION_KEY, permission.as_str())
+}
+
+fn grid_event(ty: &str, grid: &Grid) -> Event {
+    Event::new(ty)
+        .add_attribute(GRID_ID_KEY, grid.grid_id.to_string())
+        .add_attribute(OWNER_KEY, grid.owner.as_str())
+        .add_attribute(
+            PAIR_KEY,
+            format!("{}/{}", grid.base_denom, grid.quote_denom),
+        )
+}
+
+pub fn grid_created(grid: &Grid) -> Event {
+    let prices: Vec<String> = grid.prices.iter().map(|price| price.to_string()).collect();
+    grid_event(GRID_CREATED, grid).add_attribute(LEVELS_KEY, prices.join(","))
+}
+
+pub fn grid_closed(grid: &Grid, refund: &[Coin]) -> Event {
+    let refund: Vec<String> = refund.iter().map(|coin| coin.to_string()).collect();
+    grid_event(GRID_CLOSED, grid)
+        .add_attribute(REFUND_KEY, refund.join(","))
+        .add_attribute(PROFIT_KEY, grid.realised_profit.to_string())
+}
+
+pub fn auto_compound_subscribed(subscription: &AutoCompound) -> Event {
+    Event::new(AUTO_COMPOUND_SUBSCRIBED)
+        .add_attribute(OWNER_KEY, subscription.owner.as_str())
+        .add_attribute(INTERVAL_KEY, subscription.interval.to_string())
+}
+
+pub fn auto_compound_unsubscribed(owner: impl Into<String>) -> Event {
+    Event::new(AUTO_COMPOUND_UNSUBSCRIBED).add_attribute(OWNER_KEY, owner)
+}
+
+fn coins_attribute(coins: &[Coin]) -> String {
+    let coins: Vec<String> = coins.iter().map(|coin| coin.to_string()).collect();
+    coins.join(",")
+}
+
+pub fn auto_compound_run(owner: impl Into<String>, run: &CompoundRun) -> Event {
+    Event::new(AUTO_COMPOUND_RUN)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(CLAIMED_KEY, coins_attribute(&run.claimed))
+        .add_attribute(COMPOUNDED_KEY, coins_attribute(&run.compounded))
+}
+
+pub fn auto_compound_failed(owner: impl Into<String>, reason: impl Into<String>) -> Event {
+    Event::new(AUTO_COMPOUND_FAILED)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(REASON_KEY, reason)
+}
+
+/// Emitted once every step of the batch has been dispatched, the outputs of
+/// the steps are separated by `;`.
+pub fn batch_executed(batch: &Batch) -> Event {
+    let outputs: Vec<String> = batch
+        .outputs
+        .iter()
+        .map(|output| coins_attribute(output))
+        .collect();
+    Event::new(BATCH_EXECUTED)
+        .add_attribute(OWNER_KEY, batch.owner.as_str())
+        .add_attribute(STEPS_KEY, batch.actions.len().to_string())
+        .add_attribute(OUTPUTS_KEY, outputs.join(";"))
+}
+
+fn conditional_order_event(ty: &str, order: &ConditionalOrder, escrow_key: &str) -> Event {
+    let price = &order.condition.price;
+    let event = Event::new(ty)
+        .add_attribute(ORDER_ID_KEY, order.order_id.to_string())
+        .add_attribute(OWNER_KEY, order.owner.as_str())
+        .add_attribute(
+            PAIR_KEY,
+            format!("{}/{}", price.base_denom, price.quote_denom),
+        );
+    // position closes don't escrow anything and attributes can't be empty
+    if order.escrow.is_empty() {
+        event
+    } else {
+        event.add_attribute(escrow_key, coins_attribute(&order.escrow))
+    }
+}
+
+pub fn conditional_order_created(order: &ConditionalOrder) -> Event {
+    conditional_order_event(CONDITIONAL_ORDER_CREATED, order, AMOUNTS_KEY)
+        .add_attribute(PRICE_KEY, order.condition.price.rate.to_string())
+        .add_attribute(DIRECTION_KEY, order.condition.direction.as_str())
+}
+
+/// Emitted with the events of the wrapped order once it has been executed.
+pub fn conditional_order_triggered(order: &ConditionalOrder) -> Event {
+    conditional_order_event(CONDITIONAL_ORDER_TRIGGERED, order, AMOUNTS_KEY)
+}
+
+pub fn conditional_order_cancelled(order: &ConditionalOrder) -> Event {
+    conditional_order_event(CONDITIONAL_ORDER_CANCELLED, order, REFUND_KEY)
+}
+
+/// Emitted when the wrapped order reverted, with the escrow refunded to its owner.
+pub fn conditional_order_failed(order: &ConditionalOrder, reason: impl Into<String>) -> Event {
+    conditional_order_event(CONDITIONAL_ORDER_FAILED, order, REFUND_KEY)
+        .add_attribute(REASON_KEY, reason)
+}
+
+fn pool_event(
+    ty: &str,
+    owner: impl Into<String>,
+    pool_id: u64,
+    shares: Uint128,
+    amounts: &[Coin],
+) -> Event {
+    let event = Event::new(ty)
+        .add_attribute(OWNER_KEY, owner)
+        .add_attribute(POOL_ID_KEY, pool_id.to_string())
+        .add_attribute(SHARES_KEY, shares.to_string());
+    if amounts.is_empty() {
+        event
+    } else {
+        event.add_attribute(AMOUNTS_KEY, coins_attribute(amounts))
+    }
+}
+
+/// Emitted once the shares are minted, with the assets deposited for them.
+pub fn pool_joined(
+    owner: impl Into<String>,
+    pool_id: u64,
+    shares: Uint128,
+    token_in: &[Coin],
+) -> Event {
+    pool_event(POOL_JOINED, owner, pool_id, shares, token_in)
+}
+
+/// Emitted once the shares are burnt, with the assets withdrawn for them.
+pub fn pool_exited(
+    owner: impl Into<String>,
+    pool_id: u64,
+    shares: Uint128,
+    token_out: &[Coin],
+) -> Event {
+    pool_event(POOL_EXITED, owner, pool_id, shares, token_out)
+}
+
+fn position_event(ty: &str, mtp: &Mtp, amount: &Coin) -> Event {
+    Event::new(ty)
+        .add_attribute(OWNER_KEY, &mtp.address)
+        .add_attribute(POSITION_ID_KEY, mtp.id.to_string())
+        .add_attribute(
+            PAIR_KEY,
+            format!("{}/{}", mtp.collateral_asset, mtp.trading_asset),
+        )
+        .add_attribute(AMOUNTS_KEY, amount.to_string())
+}
+
+/// `custody` is the closed part of the custody of the position.
+pub fn position_closed(mtp: &Mtp, custody: &Coin) -> Event {
+    position_event(POSITION_CLOSED, mtp, custody)
+}
+
+pub fn collateral_added(mtp: &Mtp, collateral: &Coin) -> Event {
+    position_event(COLLATERAL_ADDED, mtp, collateral)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trade_shield::types::{OrderPrice, SpotOrderExtras, SpotOrderType};
+    use cosmwasm_std::{coin, testing::mock_env, Addr};
+    use std::str::FromStr;
+
+    #[test]
+    fn spot_order_event_attributes() {
+        let order = SpotOrder::new(
+            3,
+            SpotOrderType::LimitSell,
+            coin(2, "btc"),
+            Addr::unchecked("user"),
+            "usdc".to_string(),
+            SpotOrderExtras {
+                order_price: Some(OrderPrice {
+                    base_denom: "btc".to_string(),
+                    quote_denom: "usdc".to_string(),
+                    rate: Decimal::from_str("30000").unwrap(),
+                }),
+                ..Default::default()
+            },
+            &mock_env().block,
+        );
+
+        let event = order_cancelled(&order, "cancelled by owner");
+
+        assert_eq!(event.ty, ORDER_CANCELLED);
+        let attr = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|attr| attr.key == key)
+                .map(|attr| attr.value.clone())
+        };
+        assert_eq!(attr(ORDER_KIND_KEY), Some("spot".to_string()));
+        assert_eq!(attr(ORDER_ID_KEY), Some("3".to_string()));
+        assert_eq!(attr(OWNER_KEY), Some("user".to_string()));
+        assert_eq!(attr(PAIR_KEY), Some("btc/usdc".to_string()));
+        assert_eq!(attr(PRICE_KEY), Some("30000".to_string()));
+        assert_eq!(attr(AMOUNTS_KEY), Some("2btc".to_string()));
+        assert_eq!(attr(REASON_KEY), Some("cancelled by owner".to_string()));
+    }
+}
@@ -1,8 +1,10 @@
 use crate::trade_shield::types::{
-    OrderPrice, PerpetualOrderType, PerpetualPosition, SpotOrderType, SwapAmountInRoute,
+    AssetMaxLeverage, BatchAction, CompoundPolicy, OrderPrice, PerpetualOrderType,
+    PerpetualPosition, PriceCondition, Role, SpotOrderType, SwapAmountInRoute, TradingPair,
+    TradingPermission,
 };
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Coin, Int128, SignedDecimal, SignedDecimal256, Uint128};
+use cosmwasm_std::{Coin, Decimal, Int128, SignedDecimal, SignedDecimal256, Timestamp, Uint128};
 
 #[cw_serde]
 pub enum ExecuteMsg {
@@ -11,6 +13,8 @@ pub enum ExecuteMsg {
         order_source_denom: String,
         order_target_denom: String,
         order_price: Option<OrderPrice>,
+        routes: Option<Vec<SwapAmountInRoute>>, // Can be null to use the best route at execution
+        trigger_price: Option<OrderPrice>, // Can be set to trigger the order on any oracle pair, "usd" can be used as quote denom
     },
     CancelSpotOrder {
         order_id: u64,
@@ -25,6 +29,7 @@ pub enum ExecuteMsg {
         leverage: Option<SignedDecimal>, // Can be null if it's not a LimitOpen or MarketOpen type
         trading_asset: Option<String>,   // Can be null if it's not a LimitOpen or MarketOpen type
         take_profit_price: Option<SignedDecimal256>, // Can be null if it's not a LimitOpen or MarketOpen type
+        stop_loss_price: Option<OrderPrice>, // Can be null, only used with LimitOpen or MarketOpen type
         order_type: PerpetualOrderType,
         trigger_price: Option<OrderPrice>, // Can be null if it's a MarketOpen or MarketClose type
         position_id: Option<u64>, // Can be null if it's not a LimitClose, MarketClose or StopLoss type
@@ -112,6 +117,14 @@ pub enum ExecuteMsg {
         reward_enabled: Option<bool>,
         leverage_enabled: Option<bool>,
         limit_process_order: Option<u128>, // set to zero set the limit to None
+        min_order_notional: Option<Decimal>,
+        max_pending_orders_per_user: Option<u64>, // set to zero set the limit to None
+        max_leverage: Option<Vec<AssetMaxLeverage>>,
+        allowed_pairs: Option<Vec<TradingPair>>,
+        fee_rate: Option<Decimal>,
+        fee_collector: Option<String>, // set to an empty string set the collector to None
+        referrer_fee_share: Option<Decimal>,
+        order_gas_limit: Option<u64>, // set to zero set the limit to None
     },
 
     EstakingWithdrawElysStakingRewards {},
@@ -129,4 +142,108 @@ pub enum ExecuteMsg {
     PerpetualAddCollateral {
         id: u64,
     },
+
+    PerpetualUpdateTakeProfitPrice {
+        id: u64,
+        price: SignedDecimal256,
+    },
+
+    PerpetualUpdateStopLoss {
+        id: u64,
+        price: SignedDecimal,
+    },
+
+    WithdrawFees {
+        denoms: Option<Vec<String>>, // every collected denom when None
+    },
+
+    SetReferrer {
+        referrer: String,
+    },
+
+    SetDiscountOverride {
+        address: String,
+        discount: Decimal,
+        expires_at: Option<Timestamp>,
+    },
+    RemoveDiscountOverride {
+        address: String,
+    },
+
+    Pause {},
+    Unpause {},
+    ForceCancelAll {
+        pair: TradingPair,
+        limit: Option<u64>, // maximum number of orders cancelled by the call
+    },
+    GrantRole {
+        role: Role,
+        address: String,
+    },
+    RevokeRole {
+        role: Role,
+        address: String,
+    },
+    // the escrowed collateral is sent with the message, in the collateral denom of the position
+    CreateLiquidationGuard {
+        position_id: u64,
+        health_threshold: SignedDecimal,
+        top_up_amount: Uint128,
+    },
+    CancelLiquidationGuard {
+        position_id: u64,
+    },
+    // replaces any previous grant of the grantee
+    Grant {
+        grantee: String,
+        permissions: Vec<TradingPermission>,
+        spend_limit: Vec<Coin>,
+        expires_at: Option<Timestamp>,
+    },
+    Revoke {
+        grantee: String,
+    },
+    // executed by a grantee, `msg` runs as if it was sent by the granter
+    OnBehalfOf {
+        granter: String,
+        msg: Box<ExecuteMsg>,
+    },
+    // quote funds are split between the levels below the market, base funds between the ones above
+    CreateGridOrder {
+        base_denom: String,
+        quote_denom: String,
+        lower_price: Decimal,
+        upper_price: Decimal,
+        levels: u64,
+    },
+    CloseGrid {
+        grid_id: u64,
+    },
+    // replaces any previous subscription of the sender, the first run is due after `interval` seconds
+    SubscribeAutoCompound {
+        policy: CompoundPolicy,
+        interval: u64,
+    },
+    UnsubscribeAutoCompound {},
+    // the owner can run its subscription at any time, keepers only once it is due
+    RunAutoCompound {
+        owner: String,
+    },
+    // the actions run in order with the funds of the sender, the whole batch reverts if one fails
+    Batch {
+        actions: Vec<BatchAction>,
+    },
+    // `msg` is a spot or perpetual order or a position close, executed on behalf of the sender
+    // with the funds sent once the condition is met
+    CreateConditionalOrder {
+        condition: PriceCondition,
+        msg: Box<ExecuteMsg>,
+    },
+    CancelConditionalOrder {
+        order_id: u64,
+    },
+    // only callable by the contract itself when processing the orders
+    ExecuteConditionalOrder {
+        order_id: u64,
+    },
 }
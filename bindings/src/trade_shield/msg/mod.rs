@@ -14,30 +14,54 @@ pub use sudo_msg::SudoMsg;
 
 pub mod query_resp {
     mod get_all_prices_resp;
+    mod get_auto_compound_resp;
+    mod get_conditional_orders_resp;
+    mod get_discount_resp;
+    mod get_fees_collected_resp;
+    mod get_grants_resp;
+    mod get_grid_resp;
+    mod get_liquidation_guards_resp;
     mod get_order_states_resp;
     mod get_perpetual_order_resp;
     mod get_perpetual_orders_resp;
     mod get_perpetual_position_resp;
     mod get_perpetual_positions_for_address_resp;
     mod get_perpetual_positions_resp;
+    mod get_referral_stats_resp;
+    mod get_roles_resp;
     mod get_sorted_order_list_resp;
     mod get_spot_order_resp;
     mod get_spot_orders_resp;
     mod get_stat_response;
+    mod get_trading_stats_resp;
+    mod get_user_pool_positions_resp;
     mod number_of_pending_order;
     mod params_resp;
 
     pub use get_all_prices_resp::GetAllPricesResponse;
+    pub use get_auto_compound_resp::GetAutoCompoundResp;
+    pub use get_conditional_orders_resp::GetConditionalOrdersResp;
+    pub use get_discount_resp::GetDiscountResp;
+    pub use get_fees_collected_resp::GetFeesCollectedResp;
+    pub use get_grants_resp::GetGrantsResp;
+    pub use get_grid_resp::GetGridResp;
+    pub use get_liquidation_guards_resp::GetLiquidationGuardsResp;
     pub use get_order_states_resp::GetSpotOrderStatesResp;
     pub use get_perpetual_order_resp::GetPerpetualOrderResp;
     pub use get_perpetual_orders_resp::GetPerpetualOrdersResp;
     pub use get_perpetual_position_resp::GetPerpetualPositionResp;
     pub use get_perpetual_positions_for_address_resp::GetPerpetualPositionsForAddressResp;
     pub use get_perpetual_positions_resp::GetPerpetualPositionsResp;
+    pub use get_referral_stats_resp::{GetRefereesResp, GetReferralStatsResp, Referee};
+    pub use get_roles_resp::{GetRolesResp, RoleGrant};
     pub use get_sorted_order_list_resp::*;
     pub use get_spot_order_resp::GetSpotOrderResp;
     pub use get_spot_orders_resp::GetSpotOrdersResp;
     pub use get_stat_response::GetStatResponse;
+    pub use get_trading_stats_resp::{
+        DailyTradingStats, GetTopTradersResp, GetTradingStatsResp, TraderVolume,
+    };
+    pub use get_user_pool_positions_resp::{GetUserPoolPositionsResp, UserPoolPosition};
     pub use number_of_pending_order::NumberOfPendingOrderResp;
     pub use params_resp::TradeShieldParamsResponse;
 }
@@ -2,7 +2,9 @@
 use super::query_resp::*;
 #[allow(unused_imports)]
 use crate::query_resp::*;
-use crate::trade_shield::types::{PerpetualAssets, PerpetualOrderType, SpotOrderType, Status};
+use crate::trade_shield::types::{
+    PerpetualAssets, PerpetualOrderType, Role, SpotOrderType, Status, TradingStatsScope,
+};
 use crate::types::{PageRequest, PerpetualPosition};
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Coin, Decimal, Int128, SignedDecimal, SignedDecimal256};
@@ -123,4 +125,46 @@ pub enum QueryMsg {
     ParameterParams {},
     #[returns(PerpetualAssets)]
     GetPerpetualAsset { address: String },
+    #[returns(GetUserPoolPositionsResp)]
+    GetUserPoolPositions { user_address: String },
+    #[returns(GetFeesCollectedResp)]
+    GetFeesCollected {},
+    #[returns(GetReferralStatsResp)]
+    GetReferralStats { address: String },
+    #[returns(GetRefereesResp)]
+    GetReferees {
+        referrer: String,
+        pagination: Option<PageRequest>,
+    },
+    #[returns(GetTradingStatsResp)]
+    GetTradingStats {
+        scope: TradingStatsScope,
+        from_day: Option<u64>, // first day included, days are block time / 86400
+        to_day: Option<u64>,   // last day included
+    },
+    #[returns(GetTopTradersResp)]
+    GetTopTraders { day: u64, limit: u64 }, // only the top 100 traders of a day are ranked
+    #[returns(GetDiscountResp)]
+    GetDiscount { address: String },
+    #[returns(GetRolesResp)]
+    GetRoles { role: Option<Role> }, // every role when None
+    #[returns(GetLiquidationGuardsResp)]
+    GetLiquidationGuards {
+        owner: String,
+        pagination: Option<PageRequest>,
+    },
+    #[returns(GetGrantsResp)]
+    GetGrants {
+        granter: String,
+        pagination: Option<PageRequest>,
+    },
+    #[returns(GetGridResp)]
+    GetGrid { grid_id: u64 },
+    #[returns(GetAutoCompoundResp)]
+    GetAutoCompound { owner: String },
+    #[returns(GetConditionalOrdersResp)]
+    GetConditionalOrders {
+        owner: String,
+        pagination: Option<PageRequest>,
+    },
 }
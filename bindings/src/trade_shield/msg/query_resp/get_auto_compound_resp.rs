@@ -0,0 +1,7 @@
+use crate::trade_shield::types::AutoCompound;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct GetAutoCompoundResp {
+    pub subscription: Option<AutoCompound>,
+}
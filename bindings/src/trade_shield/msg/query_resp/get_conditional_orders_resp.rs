@@ -0,0 +1,9 @@
+use crate::trade_shield::types::ConditionalOrder;
+use crate::types::PageResponse;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct GetConditionalOrdersResp {
+    pub orders: Vec<ConditionalOrder>,
+    pub page_response: Option<PageResponse>,
+}
@@ -0,0 +1,10 @@
+use crate::trade_shield::types::DiscountOverride;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+
+#[cw_serde]
+pub struct GetDiscountResp {
+    pub address: String,
+    pub discount: Decimal, // discount applied to the fees of the address
+    pub discount_override: Option<DiscountOverride>,
+}
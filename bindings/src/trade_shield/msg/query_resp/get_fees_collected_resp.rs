@@ -0,0 +1,7 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Coin;
+
+#[cw_serde]
+pub struct GetFeesCollectedResp {
+    pub fees: Vec<Coin>,
+}
@@ -0,0 +1,9 @@
+use crate::trade_shield::types::TradingGrant;
+use crate::types::PageResponse;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct GetGrantsResp {
+    pub grants: Vec<TradingGrant>,
+    pub page_response: Option<PageResponse>,
+}
@@ -0,0 +1,8 @@
+use crate::trade_shield::types::{Grid, SpotOrder};
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct GetGridResp {
+    pub grid: Grid,
+    pub orders: Vec<SpotOrder>, // pending orders of the levels
+}
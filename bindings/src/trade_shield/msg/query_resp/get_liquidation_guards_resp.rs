@@ -0,0 +1,9 @@
+use crate::trade_shield::types::LiquidationGuard;
+use crate::types::PageResponse;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct GetLiquidationGuardsResp {
+    pub guards: Vec<LiquidationGuard>,
+    pub page_response: Option<PageResponse>,
+}
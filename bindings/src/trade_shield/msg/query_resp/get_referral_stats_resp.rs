@@ -0,0 +1,23 @@
+use crate::types::PageResponse;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+
+#[cw_serde]
+pub struct GetReferralStatsResp {
+    pub address: String,
+    pub referrer: Option<String>,
+    pub number_of_referees: u64,
+    pub referral_volume_usd: Decimal,
+}
+
+#[cw_serde]
+pub struct GetRefereesResp {
+    pub referees: Vec<Referee>,
+    pub page_response: Option<PageResponse>,
+}
+
+#[cw_serde]
+pub struct Referee {
+    pub address: String,
+    pub volume_usd: Decimal,
+}
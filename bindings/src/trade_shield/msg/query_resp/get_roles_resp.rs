@@ -0,0 +1,14 @@
+use crate::trade_shield::types::Role;
+use cosmwasm_schema::cw_serde;
+
+#[cw_serde]
+pub struct RoleGrant {
+    pub role: Role,
+    pub address: String,
+}
+
+#[cw_serde]
+pub struct GetRolesResp {
+    pub params_admin: String, // holds every role
+    pub grants: Vec<RoleGrant>,
+}
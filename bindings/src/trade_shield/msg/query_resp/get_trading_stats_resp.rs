@@ -0,0 +1,26 @@
+use crate::trade_shield::types::TradingStats;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Decimal;
+
+#[cw_serde]
+pub struct GetTradingStatsResp {
+    pub total: TradingStats,
+    pub daily: Vec<DailyTradingStats>,
+}
+
+#[cw_serde]
+pub struct DailyTradingStats {
+    pub day: u64,
+    pub stats: TradingStats,
+}
+
+#[cw_serde]
+pub struct GetTopTradersResp {
+    pub traders: Vec<TraderVolume>,
+}
+
+#[cw_serde]
+pub struct TraderVolume {
+    pub address: String,
+    pub volume_usd: Decimal,
+}
@@ -0,0 +1,30 @@
+use crate::trade_shield::types::{CoinValue, PoolPosition};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, SignedDecimal};
+
+#[cw_serde]
+pub struct GetUserPoolPositionsResp {
+    pub positions: Vec<UserPoolPosition>,
+}
+
+#[cw_serde]
+pub struct UserPoolPosition {
+    pub position: PoolPosition,
+    // current value of the shares, computed from the pool share usd price
+    pub current_usd_value: Decimal,
+    // value the deposited tokens would have if they had been held instead
+    pub hold_usd_value: Decimal,
+    // pending rewards (including the dex fees distributed to the LPs)
+    pub rewards: Vec<CoinValue>,
+    pub rewards_usd_value: Decimal,
+    // current_usd_value - hold_usd_value
+    pub impermanent_loss: SignedDecimal,
+    // impermanent_loss / hold_usd_value
+    pub impermanent_loss_ratio: SignedDecimal,
+}
+
+impl GetUserPoolPositionsResp {
+    pub fn empty() -> Self {
+        Self { positions: vec![] }
+    }
+}
@@ -1,14 +1,9 @@
+use crate::trade_shield::types::Params;
 use cosmwasm_schema::cw_serde;
 
 #[cw_serde]
 pub struct TradeShieldParamsResponse {
     pub params_admin: String,
-    pub market_order_enabled: bool,
-    pub stake_enabled: bool,
-    pub process_order_enabled: bool,
-    pub swap_enabled: bool,
-    pub perpetual_enabled: bool,
-    pub reward_enabled: bool,
-    pub leverage_enabled: bool,
-    pub limit_process_order: Option<u128>,
+    pub params: Params,
+    pub paused: bool,
 }
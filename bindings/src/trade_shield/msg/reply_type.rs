@@ -8,4 +8,10 @@ pub enum ReplyType {
     PerpetualBrokerMarketOpen,
     PerpetualBrokerMarketClose,
     SpotOrderMarketBuy,
+    AmmJoinPool,
+    AmmExitPool,
+    LiquidationGuardTopUp,
+    AutoCompoundRun,
+    BatchStep,
+    ConditionalOrder,
 }
@@ -0,0 +1,8 @@
+use crate::trade_shield::types::AutoCompound;
+use cw_storage_plus::{Item, Map};
+
+// owner -> auto-compound subscription of its staking and liquidity mining rewards
+pub const AUTO_COMPOUNDS: Map<&str, AutoCompound> = Map::new("auto compounds");
+
+// owner of the last subscription visited by the end blocker, the next block resumes after it
+pub const AUTO_COMPOUND_CURSOR: Item<String> = Item::new("auto compound cursor");
@@ -0,0 +1,5 @@
+use crate::trade_shield::types::Batch;
+use cw_storage_plus::Item;
+
+// batch waiting for the reply of one of its steps, removed once every step is dispatched
+pub const BATCH: Item<Batch> = Item::new("batch");
@@ -0,0 +1,7 @@
+use crate::trade_shield::types::ConditionalOrder;
+use cw_storage_plus::{Item, Map};
+
+pub const CONDITIONAL_ORDER_MAX_ID: Item<u64> = Item::new("conditional order max id");
+pub const CONDITIONAL_ORDERS: Map<u64, ConditionalOrder> = Map::new("conditional orders");
+// "base\nquote\ndirection" -> ids of the pending conditional orders sorted by ascending rate
+pub const SORTED_CONDITIONAL_ORDERS: Map<&str, Vec<u64>> = Map::new("sorted conditional orders");
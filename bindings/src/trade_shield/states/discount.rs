@@ -0,0 +1,5 @@
+use crate::trade_shield::types::DiscountOverride;
+use cw_storage_plus::Map;
+
+// user address -> discount set by the admin, takes precedence over the tier discount
+pub const DISCOUNT_OVERRIDES: Map<&str, DiscountOverride> = Map::new("discount overrides");
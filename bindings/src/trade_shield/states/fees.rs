@@ -0,0 +1,4 @@
+use cosmwasm_std::Uint128;
+use cw_storage_plus::Map;
+
+pub const FEES_COLLECTED: Map<&str, Uint128> = Map::new("fees collected");
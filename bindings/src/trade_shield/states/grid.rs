@@ -0,0 +1,7 @@
+use crate::trade_shield::types::{Grid, GridLevel};
+use cw_storage_plus::{Item, Map};
+
+pub const GRID_MAX_ID: Item<u64> = Item::new("grid max id");
+pub const GRIDS: Map<u64, Grid> = Map::new("grids");
+// spot order id -> level of the grid it was placed on
+pub const GRID_ORDERS: Map<u64, GridLevel> = Map::new("grid orders");
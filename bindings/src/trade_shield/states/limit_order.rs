@@ -1,3 +1,4 @@
 use cw_storage_plus::Item;
 
+// legacy limit, only read to migrate it into `PARAMS`
 pub const LIMIT_PROCESS_ORDER: Item<Option<u128>> = Item::new("limit order processed");
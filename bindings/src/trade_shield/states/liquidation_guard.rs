@@ -0,0 +1,8 @@
+use crate::trade_shield::types::LiquidationGuard;
+use cw_storage_plus::{Item, Map};
+
+// (owner, position id) -> collateral escrowed to protect the position from liquidation
+pub const LIQUIDATION_GUARDS: Map<(&str, u64), LiquidationGuard> = Map::new("liquidation guards");
+
+// key of the last guard visited by the end blocker, the next block resumes after it
+pub const LIQUIDATION_GUARD_CURSOR: Item<(String, u64)> = Item::new("liquidation guard cursor");
@@ -1,22 +1,51 @@
+mod auto_compound;
+mod batch;
+mod conditional_order;
+mod discount;
+mod fees;
+mod grid;
 mod limit_order;
+mod liquidation_guard;
 mod number_of_order;
 mod params;
 mod perpetual_order;
 mod perpetual_order_v2;
+mod pool_position;
+mod referral;
 mod reply_info;
+mod roles;
 mod spot_order;
+mod trading_grant;
+mod trading_stats;
 
+pub use auto_compound::{AUTO_COMPOUNDS, AUTO_COMPOUND_CURSOR};
+pub use batch::BATCH;
+pub use conditional_order::{
+    CONDITIONAL_ORDERS, CONDITIONAL_ORDER_MAX_ID, SORTED_CONDITIONAL_ORDERS,
+};
+pub use discount::DISCOUNT_OVERRIDES;
+pub use fees::FEES_COLLECTED;
+pub use grid::{GRIDS, GRID_MAX_ID, GRID_ORDERS};
 pub use limit_order::LIMIT_PROCESS_ORDER;
+pub use liquidation_guard::{LIQUIDATION_GUARDS, LIQUIDATION_GUARD_CURSOR};
 pub use number_of_order::{NUMBER_OF_EXECUTED_ORDER, NUMBER_OF_PENDING_ORDER};
 pub use params::{
-    LEVERAGE_ENABLED, MARKET_ORDER_ENABLED, PARAMS_ADMIN, PERPETUAL_ENABLED,
+    LEVERAGE_ENABLED, MARKET_ORDER_ENABLED, PARAMS, PARAMS_ADMIN, PAUSED, PERPETUAL_ENABLED,
     PROCESS_ORDERS_ENABLED, REWARD_ENABLED, STAKE_ENABLED, SWAP_ENABLED,
 };
 pub use perpetual_order::{
     PENDING_PERPETUAL_ORDER, PERPETUAL_ORDER, SORTED_PENDING_PERPETUAL_ORDER, USER_PERPETUAL_ORDER,
 };
 pub use perpetual_order_v2::{PENDING_PERPETUAL_ORDER_V2, PERPETUAL_ORDER_V2};
+pub use pool_position::USER_POOL_POSITION;
+pub use referral::{REFEREES, REFERRALS, REFERRAL_VOLUME};
 pub use reply_info::{MAX_REPLY_ID, REPLY_INFO};
+pub use roles::ROLES;
 pub use spot_order::{
     PENDING_SPOT_ORDER, SORTED_PENDING_SPOT_ORDER, SPOT_ORDER, SPOT_ORDER_MAX_ID, USER_SPOT_ORDER,
 };
+pub use trading_grant::TRADING_GRANTS;
+pub use trading_stats::{
+    DAILY_TOP_TRADERS, DAILY_USER_VOLUME, GLOBAL_TRADING_STATS, PAIR_TRADING_STATS,
+    USER_TRADING_STATS,
+};
@@ -1,7 +1,16 @@
 use cw_storage_plus::Item;
 
+use crate::trade_shield::types::Params;
+
 pub const PARAMS_ADMIN: Item<String> = Item::new("params admin");
 
+pub const PARAMS: Item<Params> = Item::new("params");
+
+// emergency switch, new orders and order processing are blocked while set
+// but pending orders can still be cancelled and refunded
+pub const PAUSED: Item<bool> = Item::new("paused");
+
+// legacy toggles, only read to migrate them into `PARAMS`
 pub const MARKET_ORDER_ENABLED: Item<bool> = Item::new("market_order");
 pub const STAKE_ENABLED: Item<bool> = Item::new("stake_endpoint");
 pub const PROCESS_ORDERS_ENABLED: Item<bool> = Item::new("process_orders_enabled");
@@ -0,0 +1,4 @@
+use crate::trade_shield::types::PoolPosition;
+use cw_storage_plus::Map;
+
+pub const USER_POOL_POSITION: Map<(&str, u64), PoolPosition> = Map::new("user pool position");
@@ -0,0 +1,11 @@
+use cosmwasm_std::Decimal;
+use cw_storage_plus::Map;
+
+// user address -> referrer address, set once and never updated
+pub const REFERRALS: Map<&str, String> = Map::new("referrals");
+
+// (referrer, referee) -> usd volume executed by the referee
+pub const REFEREES: Map<(&str, &str), Decimal> = Map::new("referees");
+
+// referrer -> usd volume executed by all of its referees
+pub const REFERRAL_VOLUME: Map<&str, Decimal> = Map::new("referral volume");
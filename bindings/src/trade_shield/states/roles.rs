@@ -0,0 +1,5 @@
+use cosmwasm_std::Empty;
+use cw_storage_plus::Map;
+
+// (role, address) -> granted, the role is stored with `Role::as_str`
+pub const ROLES: Map<(&str, &str), Empty> = Map::new("roles");
@@ -0,0 +1,5 @@
+use crate::trade_shield::types::TradingGrant;
+use cw_storage_plus::Map;
+
+// (granter, grantee) -> what the grantee can do with the orders of the granter
+pub const TRADING_GRANTS: Map<(&str, &str), TradingGrant> = Map::new("trading grants");
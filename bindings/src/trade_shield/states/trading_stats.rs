@@ -0,0 +1,15 @@
+use crate::trade_shield::types::TradingStats;
+use cosmwasm_std::Decimal;
+use cw_storage_plus::Map;
+
+// the u64 in the keys is the day of the bucket: block time / SECONDS_PER_DAY
+pub const USER_TRADING_STATS: Map<(&str, u64), TradingStats> = Map::new("user trading stats");
+pub const PAIR_TRADING_STATS: Map<(&str, u64), TradingStats> = Map::new("pair trading stats");
+pub const GLOBAL_TRADING_STATS: Map<u64, TradingStats> = Map::new("global trading stats");
+
+// (day, user) -> usd volume, to rank the traders of a day
+pub const DAILY_USER_VOLUME: Map<(u64, &str), Decimal> = Map::new("daily user volume");
+
+// day -> the traders with the highest usd volume of the day, sorted by volume and
+// kept at most MAX_TOP_TRADERS long
+pub const DAILY_TOP_TRADERS: Map<u64, Vec<(String, Decimal)>> = Map::new("daily top traders");
@@ -0,0 +1,64 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal, Timestamp};
+
+/// What an auto-compound run does with the claimed Eden.
+#[cw_serde]
+pub enum EdenPolicy {
+    Stake,
+    Vest,
+    Keep,
+}
+
+/// What an auto-compound run does with the claimed USDC.
+#[cw_serde]
+pub enum UsdcPolicy {
+    Keep,
+    // the swap fails when it returns less than the oracle price minus `max_slippage`
+    Swap {
+        denom_out: String,
+        max_slippage: Decimal,
+    },
+    // the join fails when it mints less than the estimated shares minus `max_slippage`
+    JoinPool {
+        pool_id: u64,
+        max_slippage: Decimal,
+    },
+}
+
+#[cw_serde]
+pub struct CompoundPolicy {
+    pub eden: EdenPolicy,
+    pub usdc: UsdcPolicy,
+}
+
+/// Outcome of an auto-compound run, `error` is set when the run was reverted.
+#[cw_serde]
+pub struct CompoundRun {
+    pub time: Timestamp,
+    pub claimed: Vec<Coin>,
+    pub compounded: Vec<Coin>,
+    pub error: Option<String>,
+}
+
+#[cw_serde]
+pub struct AutoCompound {
+    pub owner: Addr,
+    pub policy: CompoundPolicy,
+    pub interval: u64, // seconds between two runs
+    pub next_run: Timestamp,
+    pub runs: u64,
+    pub last_run: Option<CompoundRun>,
+}
+
+impl AutoCompound {
+    pub fn is_due(&self, now: Timestamp) -> bool {
+        self.next_run <= now
+    }
+
+    /// Records the outcome of a run and schedules the next one.
+    pub fn record(&mut self, run: CompoundRun) {
+        self.next_run = run.time.plus_seconds(self.interval);
+        self.runs += 1;
+        self.last_run = Some(run);
+    }
+}
@@ -0,0 +1,85 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Int128, SignedDecimal, Uint128};
+
+/// Amount spent by a batch action.
+#[cw_serde]
+pub enum BatchAmount {
+    Fixed(Uint128),
+    // everything an earlier step returned in the denom spent by the action
+    Output { step: u64 },
+}
+
+#[cw_serde]
+pub enum BatchAction {
+    // outputs the pending rewards that are claimed
+    ClaimRewards {},
+    // outputs the swapped amount
+    Swap {
+        denom_in: String,
+        amount: BatchAmount,
+        denom_out: String,
+        min_amount_out: Uint128,
+    },
+    Stake {
+        asset: String,
+        amount: BatchAmount,
+        validator_address: Option<String>, // required to stake uelys
+    },
+    // outputs the collateral returned to the owner, in the collateral asset of the position
+    ClosePerpetualPosition {
+        id: u64,
+        amount: Int128,
+    },
+    OpenLeveragelpPosition {
+        amm_pool_id: u64,
+        collateral_asset: String,
+        collateral_amount: BatchAmount,
+        leverage: SignedDecimal,
+        stop_loss_price: SignedDecimal,
+    },
+}
+
+impl BatchAction {
+    /// Earlier step whose output is spent by the action.
+    pub fn input_step(&self) -> Option<u64> {
+        let amount = match self {
+            BatchAction::Swap { amount, .. } => amount,
+            BatchAction::Stake { amount, .. } => amount,
+            BatchAction::OpenLeveragelpPosition {
+                collateral_amount, ..
+            } => collateral_amount,
+            BatchAction::ClaimRewards {} | BatchAction::ClosePerpetualPosition { .. } => {
+                return None
+            }
+        };
+        match amount {
+            BatchAmount::Output { step } => Some(*step),
+            BatchAmount::Fixed(_) => None,
+        }
+    }
+}
+
+/// Batch being executed, `outputs` holds what every executed step returned.
+#[cw_serde]
+pub struct Batch {
+    pub owner: Addr,
+    pub actions: Vec<BatchAction>,
+    pub outputs: Vec<Vec<Coin>>,
+}
+
+impl Batch {
+    /// Resolves `amount` in `denom` for the next step.
+    pub fn amount(&self, amount: &BatchAmount, denom: &str) -> Coin {
+        match amount {
+            BatchAmount::Fixed(amount) => Coin::new(amount.u128(), denom),
+            BatchAmount::Output { step } => {
+                let amount = self
+                    .outputs
+                    .get(*step as usize)
+                    .and_then(|output| output.iter().find(|coin| coin.denom == denom))
+                    .map_or(0, |coin| coin.amount.u128());
+                Coin::new(amount, denom)
+            }
+        }
+    }
+}
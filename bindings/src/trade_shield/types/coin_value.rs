@@ -89,9 +89,7 @@ impl CoinValue {
             });
         }
 
-        let price = querier
-            .get_asset_price(balance.denom.clone())
-            .map_err(|e| StdError::generic_err(format!("failed to get_asset_price: {}", e)))?;
+        let price = querier.get_asset_price(balance.denom.clone())?;
 
         let amount_usd = price
             .clone()
@@ -0,0 +1,100 @@
+use std::fmt;
+use std::str::FromStr;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, Decimal, StdError, StdResult};
+
+use super::{OrderPrice, Status};
+use crate::trade_shield::msg::ExecuteMsg;
+
+#[cw_serde]
+pub enum PriceDirection {
+    Above, // met once the market price is greater than or equal to the rate
+    Below, // met once the market price is lower than or equal to the rate
+}
+
+impl PriceDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PriceDirection::Above => "above",
+            PriceDirection::Below => "below",
+        }
+    }
+}
+
+impl fmt::Display for PriceDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for PriceDirection {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "above" => Self::Above,
+            "below" => Self::Below,
+            _ => return Err(StdError::generic_err("unknown price direction")),
+        })
+    }
+}
+
+/// Condition on the oracle price of any pair, "usd" can be used as quote denom.
+#[cw_serde]
+pub struct PriceCondition {
+    pub price: OrderPrice,
+    pub direction: PriceDirection,
+}
+
+impl PriceCondition {
+    pub fn is_met(&self, market_price: Decimal) -> bool {
+        match self.direction {
+            PriceDirection::Above => market_price >= self.price.rate,
+            PriceDirection::Below => market_price <= self.price.rate,
+        }
+    }
+}
+
+/// Order message held by the contract and executed on behalf of its owner,
+/// with the escrowed funds, once the price condition is met.
+#[cw_serde]
+pub struct ConditionalOrder {
+    pub order_id: u64,
+    pub owner: Addr,
+    pub condition: PriceCondition,
+    pub msg: Box<ExecuteMsg>,
+    pub escrow: Vec<Coin>,
+    pub status: Status,
+}
+
+impl ConditionalOrder {
+    pub fn gen_key(&self) -> String {
+        let OrderPrice {
+            base_denom,
+            quote_denom,
+            ..
+        } = &self.condition.price;
+        format!(
+            "{}\n{}\n{}",
+            base_denom,
+            quote_denom,
+            self.condition.direction.as_str()
+        )
+    }
+
+    /// Returns the base denom, quote denom and direction encoded by `gen_key`.
+    pub fn from_key(key: &str) -> StdResult<(String, String, PriceDirection)> {
+        let parts: Vec<&str> = key.split('\n').collect();
+        match parts.as_slice() {
+            [base_denom, quote_denom, direction] => Ok((
+                base_denom.to_string(),
+                quote_denom.to_string(),
+                PriceDirection::from_str(direction)?,
+            )),
+            _ => Err(StdError::generic_err(format!(
+                "invalid conditional order key: {key}"
+            ))),
+        }
+    }
+}
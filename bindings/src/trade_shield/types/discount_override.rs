@@ -0,0 +1,18 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Timestamp};
+
+/// Fee discount granted by the admin to an address, in place of its tier discount.
+#[cw_serde]
+pub struct DiscountOverride {
+    pub discount: Decimal,
+    pub expires_at: Option<Timestamp>, // never expires when None
+}
+
+impl DiscountOverride {
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
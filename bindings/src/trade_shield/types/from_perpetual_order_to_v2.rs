@@ -12,6 +12,7 @@ impl Into<PerpetualOrderV2> for PerpetualOrder {
             trading_asset: self.trading_asset,
             leverage: self.leverage,
             take_profit_price: self.take_profit_price,
+            stop_loss_price: None,
             position_id: self.position_id,
             status: self.status,
             size: None,
@@ -0,0 +1,89 @@
+use crate::trade_shield::types::{OrderPrice, SpotOrder, SpotOrderExtras, SpotOrderType};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, BlockInfo, Coin, Decimal, Uint128};
+
+/// Grid trading strategy: limit buys below the market and limit sells above
+/// it, every filled level is replaced by the opposite order one level away.
+#[cw_serde]
+pub struct Grid {
+    pub grid_id: u64,
+    pub owner: Addr,
+    pub base_denom: String,
+    pub quote_denom: String,
+    pub prices: Vec<Decimal>, // price of every level in quote per base, ascending
+    pub order_ids: Vec<u64>,  // pending orders of the levels
+    pub idle: Vec<Coin>,      // proceeds of the outer levels, held until the grid is closed
+    pub realised_profit: Uint128, // in quote denom, from buy then sell round trips
+    pub filled_orders: u64,
+    pub active: bool,
+}
+
+/// Level of a grid a spot order was placed on.
+#[cw_serde]
+pub struct GridLevel {
+    pub grid_id: u64,
+    pub level: u64,
+    pub cost: Option<Uint128>, // quote spent on the base sold by the order, set on sells placed after a buy
+}
+
+impl Grid {
+    /// Prices of `levels` levels evenly spaced from `lower` to `upper` included.
+    pub fn level_prices(lower: Decimal, upper: Decimal, levels: u64) -> Vec<Decimal> {
+        let step = (upper - lower) / Decimal::from_atomics(levels - 1, 0).unwrap();
+        (0..levels)
+            .map(|level| lower + step * Decimal::from_atomics(level, 0).unwrap())
+            .collect()
+    }
+
+    pub fn add_idle(&mut self, amount: Coin) {
+        match self.idle.iter_mut().find(|idle| idle.denom == amount.denom) {
+            Some(idle) => idle.amount += amount.amount,
+            None => self.idle.push(amount),
+        }
+    }
+
+    /// Limit order of `amount` on `level`, a buy spends the quote denom and a
+    /// sell the base denom.
+    pub fn order(
+        &self,
+        order_id: u64,
+        level: u64,
+        order_type: SpotOrderType,
+        amount: Coin,
+        block_info: &BlockInfo,
+    ) -> SpotOrder {
+        let price = self.prices[level as usize];
+        // a buy is priced with the market price of its target denom in source denom
+        let (order_price, target_denom) = match order_type {
+            SpotOrderType::LimitBuy => (
+                OrderPrice {
+                    base_denom: self.quote_denom.clone(),
+                    quote_denom: self.base_denom.clone(),
+                    rate: price,
+                },
+                self.base_denom.clone(),
+            ),
+            _ => (
+                OrderPrice {
+                    base_denom: self.base_denom.clone(),
+                    quote_denom: self.quote_denom.clone(),
+                    rate: price,
+                },
+                self.quote_denom.clone(),
+            ),
+        };
+
+        SpotOrder::new(
+            order_id,
+            order_type,
+            amount,
+            self.owner.clone(),
+            target_denom,
+            SpotOrderExtras {
+                order_price: Some(order_price),
+                ..Default::default()
+            },
+            block_info,
+        )
+    }
+}
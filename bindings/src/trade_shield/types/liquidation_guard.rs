@@ -0,0 +1,27 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, SignedDecimal, Uint128};
+
+/// Collateral escrowed by a position owner, added to the position whenever its
+/// health drops below `health_threshold`.
+#[cw_serde]
+pub struct LiquidationGuard {
+    pub owner: String,
+    pub position_id: u64,
+    pub health_threshold: SignedDecimal,
+    pub top_up_amount: Uint128, // added per trigger, capped by the escrow left
+    pub escrow: Coin,
+}
+
+impl LiquidationGuard {
+    /// Collateral sent to the position on the next trigger.
+    pub fn next_top_up(&self) -> Coin {
+        Coin {
+            denom: self.escrow.denom.clone(),
+            amount: self.top_up_amount.min(self.escrow.amount),
+        }
+    }
+
+    pub fn is_triggered(&self, mtp_health: SignedDecimal) -> bool {
+        mtp_health < self.health_threshold
+    }
+}
@@ -8,36 +8,59 @@ mod spot_order {
         mod new;
         #[cfg(feature = "testing")]
         mod new_dummy;
+        mod trigger;
     }
 }
+mod auto_compound;
+mod batch;
 mod coin_value;
+mod conditional_order;
 mod date;
 mod denom;
+mod discount_override;
 mod fees;
 mod from_perpetual_order_to_v2;
+mod grid;
+mod liquidation_guard;
+mod params;
 mod perpetual_assets;
 mod perpetual_order;
 mod perpetual_order_plus;
 mod perpetual_order_type;
 mod perpetual_order_v2;
 mod perpetual_position_plus;
+mod pool_position;
 mod reply_info;
+mod role;
 mod status;
+mod trading_grant;
+mod trading_stats;
 
 pub use crate::types::*;
+pub use auto_compound::{AutoCompound, CompoundPolicy, CompoundRun, EdenPolicy, UsdcPolicy};
+pub use batch::{Batch, BatchAction, BatchAmount};
 pub use coin_value::CoinValue;
+pub use conditional_order::{ConditionalOrder, PriceCondition, PriceDirection};
 pub use date::Date;
 pub use denom::ElysDenom;
+pub use discount_override::DiscountOverride;
 pub use fees::Fee;
 pub use fees::FeeNeg;
+pub use grid::{Grid, GridLevel};
+pub use liquidation_guard::LiquidationGuard;
+pub use params::{AssetMaxLeverage, Params, TradingPair, PARAMS_VERSION};
 pub use perpetual_assets::{PerpetualAsset, PerpetualAssets};
 pub use perpetual_order::PerpetualOrder;
 pub use perpetual_order_plus::PerpetualOrderPlus;
 pub use perpetual_order_type::PerpetualOrderType;
 pub use perpetual_order_v2::PerpetualOrderV2;
 pub use perpetual_position_plus::PerpetualPositionPlus;
+pub use pool_position::PoolPosition;
 pub use reply_info::ReplyInfo;
-pub use spot_order::spot_order::SpotOrder;
-pub use spot_order_price::OrderPrice;
+pub use role::Role;
+pub use spot_order::spot_order::{SpotOrder, SpotOrderExtras};
+pub use spot_order_price::{OrderPrice, USD_DENOM};
 pub use spot_order_type::SpotOrderType;
 pub use status::Status;
+pub use trading_grant::{TradingGrant, TradingPermission};
+pub use trading_stats::{OrderTypeCount, TradingStats, TradingStatsScope, SECONDS_PER_DAY};
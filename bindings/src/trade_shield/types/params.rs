@@ -0,0 +1,141 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Api, Decimal, SignedDecimal, StdError, StdResult};
+
+/// Version of the `Params` layout, bumped whenever a field is added or changed.
+pub const PARAMS_VERSION: u64 = 1;
+
+#[cw_serde]
+pub struct TradingPair {
+    pub base_denom: String,
+    pub quote_denom: String,
+}
+
+#[cw_serde]
+pub struct AssetMaxLeverage {
+    pub trading_asset: String,
+    pub max_leverage: SignedDecimal,
+}
+
+#[cw_serde]
+pub struct Params {
+    pub version: u64,
+    pub market_order_enabled: bool,
+    pub stake_enabled: bool,
+    pub process_order_enabled: bool,
+    pub swap_enabled: bool,
+    pub perpetual_enabled: bool,
+    pub reward_enabled: bool,
+    pub leverage_enabled: bool,
+    pub limit_process_order: Option<u128>,
+    /// minimum USD value of the amount locked by a new order
+    pub min_order_notional: Decimal,
+    pub max_pending_orders_per_user: Option<u64>,
+    pub max_leverage: Vec<AssetMaxLeverage>,
+    /// pairs that orders can be created on, every pair is allowed when empty
+    pub allowed_pairs: Vec<TradingPair>,
+    pub fee_rate: Decimal,
+    pub fee_collector: Option<String>,
+    /// part of the fee paid to the referrer of the order owner
+    pub referrer_fee_share: Decimal,
+    /// gas limit of the submessage of each order executed while processing
+    /// orders, it is not a budget shared by the orders of a block
+    pub order_gas_limit: Option<u64>,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            version: PARAMS_VERSION,
+            market_order_enabled: true,
+            stake_enabled: true,
+            process_order_enabled: true,
+            swap_enabled: true,
+            perpetual_enabled: true,
+            reward_enabled: true,
+            leverage_enabled: true,
+            limit_process_order: None,
+            min_order_notional: Decimal::zero(),
+            max_pending_orders_per_user: None,
+            max_leverage: vec![],
+            allowed_pairs: vec![],
+            fee_rate: Decimal::zero(),
+            fee_collector: None,
+            referrer_fee_share: Decimal::zero(),
+            order_gas_limit: None,
+        }
+    }
+}
+
+impl Params {
+    pub fn validate(&self, api: &dyn Api) -> StdResult<()> {
+        if self.version != PARAMS_VERSION {
+            return Err(StdError::generic_err(format!(
+                "params: unsupported version {}",
+                self.version
+            )));
+        }
+        if self.fee_rate >= Decimal::one() {
+            return Err(StdError::generic_err(
+                "params: fee_rate should be lower than one",
+            ));
+        }
+        if self.referrer_fee_share > Decimal::one() {
+            return Err(StdError::generic_err(
+                "params: referrer_fee_share cannot be greater than one",
+            ));
+        }
+        match &self.fee_collector {
+            Some(fee_collector) => {
+                api.addr_validate(fee_collector)?;
+            }
+            None if !self.fee_rate.is_zero() => {
+                return Err(StdError::generic_err(
+                    "params: fee_collector is required when fee_rate is set",
+                ))
+            }
+            None => {}
+        }
+        if let Some(leverage) = self
+            .max_leverage
+            .iter()
+            .find(|leverage| leverage.max_leverage <= SignedDecimal::one())
+        {
+            return Err(StdError::generic_err(format!(
+                "params: max_leverage of {} should be greater than one",
+                leverage.trading_asset
+            )));
+        }
+        if let Some(pair) = self
+            .allowed_pairs
+            .iter()
+            .find(|pair| pair.base_denom == pair.quote_denom)
+        {
+            return Err(StdError::generic_err(format!(
+                "params: allowed pair {}/{} has the same denom on both sides",
+                pair.base_denom, pair.quote_denom
+            )));
+        }
+        if self.order_gas_limit == Some(0) {
+            return Err(StdError::generic_err(
+                "params: order_gas_limit cannot be zero",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Pairs are matched in both directions.
+    pub fn is_pair_allowed(&self, denom_a: &str, denom_b: &str) -> bool {
+        self.allowed_pairs.is_empty()
+            || self.allowed_pairs.iter().any(|pair| {
+                (pair.base_denom == denom_a && pair.quote_denom == denom_b)
+                    || (pair.base_denom == denom_b && pair.quote_denom == denom_a)
+            })
+    }
+
+    pub fn max_leverage_of(&self, trading_asset: &str) -> Option<SignedDecimal> {
+        self.max_leverage
+            .iter()
+            .find(|leverage| leverage.trading_asset == trading_asset)
+            .map(|leverage| leverage.max_leverage)
+    }
+}
@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
-use crate::{trade_shield::states::PENDING_PERPETUAL_ORDER, types::PerpetualPosition};
+use crate::{
+    trade_shield::states::PENDING_PERPETUAL_ORDER, types::PerpetualPosition, ElysBindingsError,
+};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
     Coin, OverflowError, SignedDecimal, SignedDecimal256, StdError, StdResult, Storage,
@@ -108,9 +110,10 @@ impl PerpetualOrder {
         let rate = match trigger_price {
             Some(price) => &price.rate,
             None => {
-                return Err(StdError::generic_err(
-                    "perpetual: binary search: price not found",
-                ))
+                return Err(ElysBindingsError::NotFound {
+                    kind: "perpetual: binary search: trigger price".to_string(),
+                }
+                .into())
             }
         };
 
@@ -120,15 +123,17 @@ impl PerpetualOrder {
                 match PENDING_PERPETUAL_ORDER.may_load(storage, list[mid])? {
                     Some(order) => order,
                     None => {
-                        return Err(StdError::generic_err(
-                            "perpetual: binary search: order not found",
-                        ))
+                        return Err(ElysBindingsError::NotFound {
+                            kind: "perpetual: binary search: order".to_string(),
+                        }
+                        .into())
                     }
                 };
             if trigger_price.is_none() {
-                return Err(StdError::generic_err(
-                    "perpetual: binary search: price not found",
-                ));
+                return Err(ElysBindingsError::NotFound {
+                    kind: "perpetual: binary search: trigger price".to_string(),
+                }
+                .into());
             }
 
             if trigger_price.unwrap().rate < *rate {
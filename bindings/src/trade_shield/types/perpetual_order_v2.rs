@@ -1,10 +1,12 @@
 use std::str::FromStr;
 
-use crate::{trade_shield::states::PENDING_PERPETUAL_ORDER_V2, types::PerpetualPosition};
+use crate::{
+    trade_shield::states::PENDING_PERPETUAL_ORDER_V2, types::PerpetualPosition, ElysBindingsError,
+};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    Coin, DecCoin, Decimal256, OverflowError, SignedDecimal, SignedDecimal256, StdError, StdResult,
-    Storage,
+    Coin, DecCoin, Decimal, Decimal256, OverflowError, SignedDecimal, SignedDecimal256, StdError,
+    StdResult, Storage,
 };
 
 use super::{fees::FeeNeg, Fee, OrderPrice, PerpetualOrderType, Status};
@@ -20,6 +22,7 @@ pub struct PerpetualOrderV2 {
     pub trading_asset: String,
     pub leverage: SignedDecimal,
     pub take_profit_price: Option<SignedDecimal256>,
+    pub stop_loss_price: Option<OrderPrice>,
     pub position_id: Option<u64>,
     pub status: Status,
     pub size: Option<DecCoin>,
@@ -38,6 +41,7 @@ impl PerpetualOrderV2 {
         leverage: &SignedDecimal,
         take_profit_price: &Option<SignedDecimal256>,
         trigger_price: &Option<OrderPrice>,
+        stop_loss_price: &Option<OrderPrice>,
         order_vec: &Vec<PerpetualOrderV2>,
         size: DecCoin,
         liquidation: SignedDecimal,
@@ -60,6 +64,7 @@ impl PerpetualOrderV2 {
             trading_asset: trading_asset.into(),
             leverage: leverage.to_owned(),
             take_profit_price: take_profit_price.to_owned(),
+            stop_loss_price: stop_loss_price.to_owned(),
             order_type: order_type.to_owned(),
             trigger_price: trigger_price.to_owned(),
             status,
@@ -106,6 +111,7 @@ impl PerpetualOrderV2 {
             position_id: Some(position_id),
             leverage: leverage.to_owned(),
             take_profit_price: take_profit_price.to_owned(),
+            stop_loss_price: None,
             size: Some(DecCoin::new(Decimal256::zero(), "")),
             liquidation: Some(SignedDecimal::zero()),
             borrow_fee: Some(Fee::default()),
@@ -125,9 +131,10 @@ impl PerpetualOrderV2 {
         let rate = match trigger_price {
             Some(price) => &price.rate,
             None => {
-                return Err(StdError::generic_err(
-                    "perpetual: binary search: price not found",
-                ))
+                return Err(ElysBindingsError::NotFound {
+                    kind: "perpetual: binary search: trigger price".to_string(),
+                }
+                .into())
             }
         };
 
@@ -137,15 +144,17 @@ impl PerpetualOrderV2 {
                 match PENDING_PERPETUAL_ORDER_V2.may_load(storage, list[mid])? {
                     Some(order) => order,
                     None => {
-                        return Err(StdError::generic_err(
-                            "perpetual: binary search: order not found",
-                        ))
+                        return Err(ElysBindingsError::NotFound {
+                            kind: "perpetual: binary search: order".to_string(),
+                        }
+                        .into())
                     }
                 };
             if trigger_price.is_none() {
-                return Err(StdError::generic_err(
-                    "perpetual: binary search: price not found",
-                ));
+                return Err(ElysBindingsError::NotFound {
+                    kind: "perpetual: binary search: trigger price".to_string(),
+                }
+                .into());
             }
 
             if trigger_price.unwrap().rate < *rate {
@@ -157,6 +166,21 @@ impl PerpetualOrderV2 {
         Ok(low)
     }
 
+    /// Size of the position in the collateral denom: the collateral times the leverage.
+    pub fn notional(&self) -> StdResult<Coin> {
+        let leverage = Decimal::try_from(self.leverage)
+            .map_err(|_| StdError::generic_err("leverage: cannot be negative"))?;
+        let amount = self
+            .collateral
+            .amount
+            .checked_mul_floor(leverage)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        Ok(Coin {
+            denom: self.collateral.denom.clone(),
+            amount,
+        })
+    }
+
     pub fn gen_key(&self) -> StdResult<String> {
         if self.order_type == PerpetualOrderType::MarketClose
             || self.order_type == PerpetualOrderType::MarketOpen
@@ -199,6 +223,34 @@ impl PerpetualOrderV2 {
             vec[3].to_string(),
         ))
     }
+
+    /// Every key the pending orders on `base_denom`/`quote_denom` can be sorted
+    /// under, in both directions of the pair.
+    pub fn keys_of_pair(base_denom: &str, quote_denom: &str) -> Vec<String> {
+        let mut keys = vec![];
+        for position in [PerpetualPosition::Long, PerpetualPosition::Short] {
+            for order_type in [
+                PerpetualOrderType::LimitOpen,
+                PerpetualOrderType::LimitClose,
+                PerpetualOrderType::StopLoss,
+            ] {
+                for (base, quote) in [(base_denom, quote_denom), (quote_denom, base_denom)] {
+                    keys.push(
+                        position.to_string()
+                            + "\n"
+                            + &order_type.to_string()
+                            + "\n"
+                            + base
+                            + "\n"
+                            + quote,
+                    );
+                }
+            }
+        }
+        keys.sort();
+        keys.dedup();
+        keys
+    }
 }
 
 fn get_new_id(orders: &[PerpetualOrderV2]) -> StdResult<u64> {
@@ -1,10 +1,12 @@
+use crate::perpetual::math::{self, AssetPrice, MarketPrices};
+use crate::query_resp::PerpetualParams;
 use crate::{types::Mtp, ElysQuerier};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Order, SignedDecimal, StdError, StdResult, Storage};
 
 use crate::trade_shield::{states::PENDING_PERPETUAL_ORDER_V2, types::PerpetualOrderV2};
 
-use super::{OrderPrice, PerpetualOrderType, PerpetualPosition};
+use super::{OrderPrice, PerpetualOrderType};
 
 #[cw_serde]
 pub struct PerpetualPositionPlus {
@@ -26,51 +28,31 @@ impl PerpetualPositionPlus {
             return Err(StdError::generic_err("parsing stop_loss_prices error"));
         }
 
-        let mut mtps_plus: Vec<PerpetualPositionPlus> = Vec::new();
+        let params = Self::get_perpetual_params(querier)?;
 
-        for i in 0..mtps.len() {
-            let mtp = mtps[i].clone();
-
-            let mtp_plus = Self::new(mtp, storage, querier)?;
-
-            mtps_plus.push(mtp_plus)
-        }
-
-        Ok(mtps_plus)
+        mtps.into_iter()
+            .map(|mtp| Self::with_params(mtp, storage, querier, &params))
+            .collect()
     }
 
     pub fn new(mtp: Mtp, storage: &dyn Storage, querier: &ElysQuerier<'_>) -> StdResult<Self> {
-        let collateral_info = querier.asset_info(mtp.collateral_asset.clone())?;
-
-        let collateral_amount = SignedDecimal::from_atomics(
-            mtp.collateral.i128(),
-            collateral_info.asset_info.decimal as u32,
-        )
-        .map_err(|e| StdError::generic_err(e.to_string()))?;
-
-        let custody_amount = SignedDecimal::from_atomics(
-            mtp.custody.i128(),
-            collateral_info.asset_info.decimal as u32,
-        )
-        .map_err(|e| StdError::generic_err(e.to_string()))?;
-
-        let _liabilities_amount = SignedDecimal::from_atomics(
-            mtp.custody.i128(),
-            collateral_info.asset_info.decimal as u32,
-        )
-        .map_err(|e| StdError::generic_err(e.to_string()))?;
-
-        let unrealized_pnl = Self::calc_unrealized_pnl(
-            &mtp,
-            // &custody_amount,
-            // &collateral_amount,
-            // &liabilities_amount,
-        )
-        .map_err(|e| StdError::generic_err(format!("unrealized_pnl: {:?}", e.to_string())))?;
-        let liquidation_price =
-            Self::calc_liquidation_price(&mtp, &collateral_amount, &custody_amount).map_err(
-                |e| StdError::generic_err(format!("liquidation_price: {:?}", e.to_string())),
-            )?;
+        let params = Self::get_perpetual_params(querier)?;
+        Self::with_params(mtp, storage, querier, &params)
+    }
+
+    fn with_params(
+        mtp: Mtp,
+        storage: &dyn Storage,
+        querier: &ElysQuerier<'_>,
+        params: &PerpetualParams,
+    ) -> StdResult<Self> {
+        let prices = Self::get_market_prices(&mtp, querier)?;
+
+        let unrealized_pnl = math::unrealized_pnl(&mtp, &prices)
+            .map_err(|e| StdError::generic_err(format!("unrealized_pnl: {:?}", e.to_string())))?;
+        let liquidation_price = math::liquidation_price(&mtp, &prices, params).map_err(|e| {
+            StdError::generic_err(format!("liquidation_price: {:?}", e.to_string()))
+        })?;
         let stop_loss_price = Self::get_stop_loss_price(&mtp, storage);
 
         Ok(Self {
@@ -81,75 +63,35 @@ impl PerpetualPositionPlus {
         })
     }
 
-    fn calc_unrealized_pnl(mtp: &Mtp) -> StdResult<SignedDecimal> {
-        let custody_amount: SignedDecimal = SignedDecimal::from_atomics(mtp.custody, 0)
-            .map_err(|e| StdError::generic_err(e.to_string()))?;
-        let collateral_amount: SignedDecimal = SignedDecimal::from_atomics(mtp.collateral, 0)
-            .map_err(|e| StdError::generic_err(e.to_string()))?;
-        let liabilities_amount: SignedDecimal = SignedDecimal::from_atomics(mtp.liabilities, 0)
-            .map_err(|e| StdError::generic_err(e.to_string()))?;
-        let take_profit_price = SignedDecimal::try_from(mtp.take_profit_price.clone())
-            .map_err(|e| StdError::generic_err(e.to_string()))?;
-        let open_price = mtp.open_price.clone();
-
-        match PerpetualPosition::try_from_i32(mtp.position)? {
-            //estimated_pnl = custody_amount - (liability_amount + collateral_amount) / take_profit_price
-            PerpetualPosition::Long => custody_amount
-                .checked_sub(
-                    liabilities_amount
-                        .checked_add(collateral_amount.to_owned())?
-                        .checked_div(take_profit_price)
-                        .map_err(|e| StdError::generic_err(e.to_string()))?,
-                )
-                .map_err(|e| StdError::generic_err(e.to_string())),
-
-            // if position is short then estimated pnl is custody_amount / open_price - (liability_amount + collateral_amount) / take_profit_price
-            PerpetualPosition::Short => {
-                liabilities_amount
-                    .checked_add(collateral_amount)?
-                    .checked_div(take_profit_price)
-                    .map_err(|e| {
-                        StdError::generic_err(format!("take_profit_price: {}", e.to_string()))
-                    })?
-                    .checked_sub((custody_amount).checked_div(open_price).map_err(|e| {
-                        StdError::generic_err(format!("open_price: {}", e.to_string()))
-                    })?)
-                    .map_err(|e| StdError::generic_err(e.to_string()))
-            }
-
-            PerpetualPosition::Unspecified => Err(StdError::generic_err("Position is Unspecified")),
-        }
+    fn get_perpetual_params(querier: &ElysQuerier<'_>) -> StdResult<PerpetualParams> {
+        querier
+            .query_perpetual_params()?
+            .params
+            .ok_or_else(|| StdError::not_found("perpetual params"))
     }
 
-    fn calc_liquidation_price(
-        mtp: &Mtp,
-        collateral_amount: &SignedDecimal,
-        custody_amount: &SignedDecimal,
-    ) -> StdResult<SignedDecimal> {
-        let open_price = mtp.open_price.clone();
-
-        match PerpetualPosition::try_from_i32(mtp.position)? {
-            // liquidation_price = open_price_value - collateral_amount / custody_amount
-            PerpetualPosition::Long => Ok(open_price.checked_sub(
-                collateral_amount
-                    .checked_div(custody_amount.to_owned())
-                    .map_err(|e| StdError::generic_err(e.to_string()))?,
-            )?),
-
-            // if position is short then liquidation price is open price + collateral amount / (custody amount / open price)
-            PerpetualPosition::Short => open_price
-                .clone()
-                .checked_add(
-                    collateral_amount
-                        .checked_div(custody_amount.checked_div(open_price).map_err(|e| {
-                            StdError::generic_err(format!("open_price: {}", e.to_string()))
-                        })?)
-                        .map_err(|e| StdError::generic_err(e.to_string()))?,
-                )
-                .map_err(|e| StdError::generic_err(e.to_string())),
-
-            PerpetualPosition::Unspecified => Err(StdError::generic_err("Position is Unspecified")),
-        }
+    fn get_market_prices(mtp: &Mtp, querier: &ElysQuerier<'_>) -> StdResult<MarketPrices> {
+        let asset_price = |denom: &str| -> StdResult<AssetPrice> {
+            let price = SignedDecimal::try_from(querier.get_asset_price(denom)?)
+                .map_err(|e| StdError::generic_err(e.to_string()))?;
+            let info = querier.asset_info(denom.to_string())?;
+            Ok(AssetPrice::new(price, info.asset_info.decimal as u32))
+        };
+
+        // the base currency is the one asset of the position that is not traded
+        let base_currency = [
+            &mtp.collateral_asset,
+            &mtp.liabilities_asset,
+            &mtp.custody_asset,
+        ]
+        .into_iter()
+        .find(|denom| **denom != mtp.trading_asset)
+        .unwrap_or(&mtp.collateral_asset);
+
+        Ok(MarketPrices {
+            trading_asset: asset_price(&mtp.trading_asset)?,
+            base_currency: asset_price(base_currency)?,
+        })
     }
 
     fn get_stop_loss_price(mtp: &Mtp, storage: &dyn Storage) -> Option<OrderPrice> {
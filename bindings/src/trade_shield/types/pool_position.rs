@@ -0,0 +1,83 @@
+use crate::trade_shield::types::{CoinValue, Date};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Decimal, StdResult, Uint128};
+
+#[cw_serde]
+pub struct PoolPosition {
+    pub owner: String,
+    pub pool_id: u64,
+    pub shares: Uint128,
+    pub token_in: Vec<Coin>,
+    pub token_in_value: Vec<CoinValue>,
+    pub deposit_usd_value: Decimal,
+    pub date: Date,
+}
+
+impl PoolPosition {
+    pub fn new(owner: impl Into<String>, pool_id: u64, date: Date) -> Self {
+        Self {
+            owner: owner.into(),
+            pool_id,
+            shares: Uint128::zero(),
+            token_in: vec![],
+            token_in_value: vec![],
+            deposit_usd_value: Decimal::zero(),
+            date,
+        }
+    }
+
+    /// Add a new deposit to the position, merging the coins by denom.
+    pub fn join(
+        &mut self,
+        shares: Uint128,
+        token_in_value: Vec<(Coin, CoinValue)>,
+    ) -> StdResult<()> {
+        self.shares = self.shares.checked_add(shares)?;
+
+        for (coin, value) in token_in_value {
+            self.deposit_usd_value = self.deposit_usd_value.checked_add(value.amount_usd)?;
+
+            match self.token_in.iter_mut().find(|c| c.denom == coin.denom) {
+                Some(c) => c.amount = c.amount.checked_add(coin.amount)?,
+                None => self.token_in.push(coin),
+            }
+
+            match self
+                .token_in_value
+                .iter_mut()
+                .find(|v| v.denom == value.denom)
+            {
+                Some(v) => {
+                    v.amount_token = v.amount_token.checked_add(value.amount_token)?;
+                    v.amount_usd = v.amount_usd.checked_add(value.amount_usd)?;
+                    v.price = v
+                        .amount_usd
+                        .checked_div(v.amount_token)
+                        .unwrap_or(value.price);
+                }
+                None => self.token_in_value.push(value),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Remove `shares` from the position, reducing the recorded deposit proportionally.
+    pub fn exit(&mut self, shares: Uint128) -> StdResult<()> {
+        let shares = shares.min(self.shares);
+        let remaining = Decimal::from_ratio(self.shares - shares, self.shares.max(Uint128::one()));
+
+        self.shares -= shares;
+        self.deposit_usd_value = self.deposit_usd_value.checked_mul(remaining)?;
+
+        for coin in self.token_in.iter_mut() {
+            coin.amount = coin.amount.mul_floor(remaining);
+        }
+        for value in self.token_in_value.iter_mut() {
+            value.amount_token = value.amount_token.checked_mul(remaining)?;
+            value.amount_usd = value.amount_usd.checked_mul(remaining)?;
+        }
+
+        Ok(())
+    }
+}
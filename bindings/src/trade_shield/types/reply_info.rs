@@ -1,10 +1,12 @@
 use crate::trade_shield::msg::ReplyType;
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Binary, Coin};
 
 #[cw_serde]
 pub struct ReplyInfo {
     pub id: u64,
     pub reply_type: ReplyType,
     pub data: Option<Binary>,
+    /// protocol fee withheld from the order amount, collected once executed
+    pub fee: Option<Coin>,
 }
@@ -0,0 +1,48 @@
+use std::{fmt, str::FromStr};
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::StdError;
+
+/// Permissions that can be granted to an address, the params admin holds all of them.
+#[cw_serde]
+pub enum Role {
+    /// everything, including granting and revoking roles
+    Admin,
+    /// pause the contract
+    Guardian,
+    /// tune the order limits of the params
+    Operator,
+    /// process orders and force cancel them
+    Keeper,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "admin",
+            Role::Guardian => "guardian",
+            Role::Operator => "operator",
+            Role::Keeper => "keeper",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = StdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => Self::Admin,
+            "guardian" => Self::Guardian,
+            "operator" => Self::Operator,
+            "keeper" => Self::Keeper,
+            _ => return Err(StdError::generic_err("unknown role")),
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use super::super::spot_order::SpotOrder;
 use crate::trade_shield::{states::PENDING_SPOT_ORDER, types::OrderPrice};
-use cosmwasm_std::{Decimal, StdError, StdResult, Storage};
+use crate::ElysBindingsError;
+use cosmwasm_std::{Decimal, StdResult, Storage};
 
 impl SpotOrder {
     pub fn binary_search(
@@ -13,19 +14,18 @@ impl SpotOrder {
 
         while low < high {
             let mid = low + (high - low) / 2;
-            let SpotOrder {
-                order_price: OrderPrice { rate: mid_rate, .. },
-                ..
-            } = match PENDING_SPOT_ORDER.may_load(storage, list[mid])? {
+            let order = match PENDING_SPOT_ORDER.may_load(storage, list[mid])? {
                 Some(order) => order,
                 None => {
-                    return Err(StdError::generic_err(
-                        "spot=: binary search: price not found",
-                    ))
+                    return Err(ElysBindingsError::NotFound {
+                        kind: "spot: binary search: order".to_string(),
+                    }
+                    .into())
                 }
             };
+            let OrderPrice { rate: mid_rate, .. } = order.trigger();
 
-            if mid_rate < *rate {
+            if mid_rate < rate {
                 low = mid + 1;
             } else {
                 high = mid;
@@ -3,29 +3,64 @@ use std::str::FromStr;
 use crate::trade_shield::types::{SpotOrder, SpotOrderType};
 use cosmwasm_std::{StdError, StdResult};
 
+const TRIGGER_KEY: &str = "trigger";
+
 impl SpotOrder {
     pub fn gen_key(&self) -> StdResult<String> {
         if self.order_type == SpotOrderType::MarketBuy {
             return Err(StdError::generic_err("gen a key on a market order"));
         }
 
-        Ok(self.order_type.to_string()
-            + "\n"
-            + &self.order_price.base_denom
-            + "\n"
-            + &self.order_price.quote_denom)
+        let trigger = self.trigger();
+
+        let key =
+            self.order_type.to_string() + "\n" + &trigger.base_denom + "\n" + &trigger.quote_denom;
+
+        if self.trigger_price.is_some() {
+            return Ok(key + "\n" + TRIGGER_KEY);
+        }
+
+        Ok(key)
     }
-    pub fn from_key(key: &str) -> StdResult<(SpotOrderType, String, String)> {
+    /// Returns the order type, the base and quote denoms and whether the orders use a trigger price.
+    pub fn from_key(key: &str) -> StdResult<(SpotOrderType, String, String, bool)> {
         let vec: Vec<&str> = key.split('\n').collect();
-        if vec.len() != 3 {
-            return Err(StdError::generic_err("Wrong Key"));
-        }
+        let is_trigger = match vec.len() {
+            3 => false,
+            4 if vec[3] == TRIGGER_KEY => true,
+            _ => return Err(StdError::generic_err("Wrong Key")),
+        };
 
         let order_type = SpotOrderType::from_str(vec[0])?;
         if order_type == SpotOrderType::MarketBuy {
             return Err(StdError::generic_err("Market Order"));
         }
 
-        Ok((order_type, vec[1].to_string(), vec[2].to_string()))
+        Ok((
+            order_type,
+            vec[1].to_string(),
+            vec[2].to_string(),
+            is_trigger,
+        ))
+    }
+
+    /// Every key the pending orders on `base_denom`/`quote_denom` can be sorted
+    /// under, in both directions of the pair.
+    pub fn keys_of_pair(base_denom: &str, quote_denom: &str) -> Vec<String> {
+        let mut keys = vec![];
+        for order_type in [
+            SpotOrderType::StopLoss,
+            SpotOrderType::LimitSell,
+            SpotOrderType::LimitBuy,
+        ] {
+            for (base, quote) in [(base_denom, quote_denom), (quote_denom, base_denom)] {
+                let key = order_type.to_string() + "\n" + base + "\n" + quote;
+                keys.push(key.clone() + "\n" + TRIGGER_KEY);
+                keys.push(key);
+            }
+        }
+        keys.sort();
+        keys.dedup();
+        keys
     }
 }
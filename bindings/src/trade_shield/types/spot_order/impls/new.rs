@@ -5,12 +5,17 @@ impl SpotOrder {
     pub fn new(
         order_id: u64,
         order_type: SpotOrderType,
-        order_price: Option<OrderPrice>,
         order_amount: Coin,
         owner_address: Addr,
         order_target_denom: String,
+        extras: SpotOrderExtras,
         block_info: &BlockInfo,
     ) -> SpotOrder {
+        let SpotOrderExtras {
+            order_price,
+            routes,
+            trigger_price,
+        } = extras;
         let order_price = match order_price {
             Some(order_price) => order_price,
             None => OrderPrice {
@@ -33,6 +38,8 @@ impl SpotOrder {
             owner_address,
             order_id,
             order_target_denom,
+            routes,
+            trigger_price,
             status,
             date: Date::from(block_info),
         }
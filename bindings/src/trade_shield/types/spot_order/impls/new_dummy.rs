@@ -14,6 +14,8 @@ impl SpotOrder {
                 rate: Decimal::from_atomics(Uint128::new(5), 0).unwrap(),
             },
             order_target_denom: "eth".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 12,
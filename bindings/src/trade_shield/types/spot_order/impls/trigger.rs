@@ -0,0 +1,11 @@
+use crate::trade_shield::types::{OrderPrice, SpotOrder};
+
+impl SpotOrder {
+    /// Price used to trigger the order, the trigger price if set or the order price.
+    pub fn trigger(&self) -> &OrderPrice {
+        match &self.trigger_price {
+            Some(trigger_price) => trigger_price,
+            None => &self.order_price,
+        }
+    }
+}
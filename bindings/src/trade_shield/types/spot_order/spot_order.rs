@@ -1,4 +1,6 @@
-use crate::trade_shield::types::{spot_order_type::SpotOrderType, Date, OrderPrice, Status};
+use crate::trade_shield::types::{
+    spot_order_type::SpotOrderType, Date, OrderPrice, Status, SwapAmountInRoute,
+};
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Coin};
 
@@ -10,6 +12,20 @@ pub struct SpotOrder {
     pub order_amount: Coin,
     pub owner_address: Addr,
     pub order_target_denom: String,
+    // if not set, the best route is estimated with the order amount at execution
+    pub routes: Option<Vec<SwapAmountInRoute>>,
+    // if set, the order is triggered on this pair instead of the order price
+    pub trigger_price: Option<OrderPrice>,
     pub status: Status,
     pub date: Date,
 }
+
+/// Optional inputs of a new spot order, `order_price` is required by every
+/// order type but market buys.
+#[cw_serde]
+#[derive(Default)]
+pub struct SpotOrderExtras {
+    pub order_price: Option<OrderPrice>,
+    pub routes: Option<Vec<SwapAmountInRoute>>,
+    pub trigger_price: Option<OrderPrice>,
+}
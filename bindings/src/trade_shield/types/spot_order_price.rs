@@ -7,3 +7,6 @@ pub struct OrderPrice {
     pub quote_denom: String,
     pub rate: Decimal,
 }
+
+// quote denom used to express a price against USD with the oracle
+pub const USD_DENOM: &str = "usd";
@@ -0,0 +1,54 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Coin, Timestamp};
+
+/// What a grantee is allowed to do with the orders of its granter.
+#[cw_serde]
+pub enum TradingPermission {
+    /// create spot and perpetual orders, paid by the grantee and owned by the granter
+    CreateOrder,
+    CancelOrder,
+    /// update the stop loss and take profit of the positions and their linked orders
+    AmendOrder,
+}
+
+impl TradingPermission {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TradingPermission::CreateOrder => "create_order",
+            TradingPermission::CancelOrder => "cancel_order",
+            TradingPermission::AmendOrder => "amend_order",
+        }
+    }
+}
+
+impl fmt::Display for TradingPermission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Authorisation given by a user to another address, a trading bot for
+/// instance, to manage its orders.
+#[cw_serde]
+pub struct TradingGrant {
+    pub granter: String,
+    pub grantee: String,
+    pub permissions: Vec<TradingPermission>,
+    pub spend_limit: Vec<Coin>, // left to spend on new orders, debited on every order created
+    pub expires_at: Option<Timestamp>, // never expires when None
+}
+
+impl TradingGrant {
+    pub fn is_active(&self, now: Timestamp) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+
+    pub fn allows(&self, permission: &TradingPermission) -> bool {
+        self.permissions.contains(permission)
+    }
+}
@@ -0,0 +1,99 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, SignedDecimal, StdResult};
+
+pub const SECONDS_PER_DAY: u64 = 86400;
+
+#[cw_serde]
+pub enum TradingStatsScope {
+    Global,
+    User {
+        address: String,
+    },
+    /// pair formatted as `base/quote`, as in the order events
+    Pair {
+        pair: String,
+    },
+}
+
+#[cw_serde]
+pub struct OrderTypeCount {
+    pub order_type: String,
+    pub count: u64,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct TradingStats {
+    pub volume_usd: Decimal,
+    pub executed_orders: u64,
+    pub failed_orders: u64,
+    /// orders leaving the book with the `Canceled` status, failed ones included
+    #[serde(default)]
+    pub cancelled_orders: u64,
+    pub orders_by_type: Vec<OrderTypeCount>,
+    /// sum of the slippage of the fills where it could be measured
+    pub slippage_sum: SignedDecimal,
+    pub slippage_samples: u64,
+}
+
+impl TradingStats {
+    pub fn record_executed(
+        &mut self,
+        order_type: &str,
+        volume_usd: Decimal,
+        slippage: Option<SignedDecimal>,
+    ) -> StdResult<()> {
+        self.volume_usd = self.volume_usd.checked_add(volume_usd)?;
+        self.executed_orders += 1;
+        self.count_order_type(order_type, 1);
+        if let Some(slippage) = slippage {
+            self.slippage_sum = self.slippage_sum.checked_add(slippage)?;
+            self.slippage_samples += 1;
+        }
+        Ok(())
+    }
+
+    pub fn record_failed(&mut self, order_type: &str) {
+        self.failed_orders += 1;
+        self.count_order_type(order_type, 1);
+    }
+
+    pub fn record_cancelled(&mut self) {
+        self.cancelled_orders += 1;
+    }
+
+    pub fn merge(&mut self, other: &TradingStats) -> StdResult<()> {
+        self.volume_usd = self.volume_usd.checked_add(other.volume_usd)?;
+        self.executed_orders += other.executed_orders;
+        self.failed_orders += other.failed_orders;
+        self.cancelled_orders += other.cancelled_orders;
+        for count in other.orders_by_type.iter() {
+            self.count_order_type(&count.order_type, count.count);
+        }
+        self.slippage_sum = self.slippage_sum.checked_add(other.slippage_sum)?;
+        self.slippage_samples += other.slippage_samples;
+        Ok(())
+    }
+
+    pub fn average_slippage(&self) -> Option<SignedDecimal> {
+        if self.slippage_samples == 0 {
+            return None;
+        }
+        let samples = SignedDecimal::from_ratio(self.slippage_samples as i128, 1i128);
+        self.slippage_sum.checked_div(samples).ok()
+    }
+
+    fn count_order_type(&mut self, order_type: &str, count: u64) {
+        match self
+            .orders_by_type
+            .iter_mut()
+            .find(|entry| entry.order_type == order_type)
+        {
+            Some(entry) => entry.count += count,
+            None => self.orders_by_type.push(OrderTypeCount {
+                order_type: order_type.to_string(),
+                count,
+            }),
+        }
+    }
+}
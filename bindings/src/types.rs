@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
 use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::serde::de::DeserializeOwned;
+use cosmwasm_schema::serde::Serialize;
 use cosmwasm_std::from_json;
 use cosmwasm_std::to_json_binary;
 use cosmwasm_std::Binary;
@@ -8,12 +10,14 @@ use cosmwasm_std::Coin;
 use cosmwasm_std::Decimal;
 use cosmwasm_std::Int128;
 use cosmwasm_std::Int256;
+use cosmwasm_std::Order;
 use cosmwasm_std::SignedDecimal;
 use cosmwasm_std::SignedDecimal256;
-use cosmwasm_std::StdError;
 use cosmwasm_std::StdResult;
 use cosmwasm_std::Uint128;
 
+use crate::ElysBindingsError;
+
 #[cw_serde]
 pub struct OracleAssetInfo {
     pub denom: String,
@@ -144,6 +148,81 @@ impl PageRequest {
     }
 }
 
+/// Items yielded by a storage range, keyed by the cursor type used for pagination.
+pub type PageItems<'a, K, T> = Box<dyn Iterator<Item = StdResult<(K, T)>> + 'a>;
+
+impl PageRequest {
+    /// Pages through a storage range without loading it into memory.
+    ///
+    /// `key` is the JSON encoded key of the last item of the previous page and
+    /// is handed to `range` as an exclusive cursor together with the iteration
+    /// order. `range` must apply any filter itself so that `total` matches the
+    /// items that can actually be returned.
+    pub fn paginate<'a, K, T, F>(&self, range: F) -> StdResult<(Vec<T>, PageResponse)>
+    where
+        K: Serialize + DeserializeOwned,
+        F: Fn(Option<K>, Order) -> PageItems<'a, K, T>,
+    {
+        let cursor = match &self.key {
+            Some(key) => Some(from_json::<K>(key)?),
+            None => None,
+        };
+        let order = if self.reverse {
+            Order::Descending
+        } else {
+            Order::Ascending
+        };
+
+        let mut items = range(cursor, order).skip(self.offset.unwrap_or(0) as usize);
+        let mut page = vec![];
+        let mut last_key = None;
+
+        for item in items.by_ref().take(self.limit as usize) {
+            let (key, value) = item?;
+            last_key = Some(key);
+            page.push(value);
+        }
+
+        let next_key = match last_key {
+            Some(key) if items.next().is_some() => Some(to_json_binary(&key)?),
+            _ => None,
+        };
+
+        let total = if self.count_total {
+            Some(range(None, Order::Ascending).count() as u64)
+        } else {
+            None
+        };
+
+        Ok((page, PageResponse::new(next_key, total)))
+    }
+}
+
+/// Splits an exclusive cursor into the `(min, max)` bounds of a range in the given order.
+pub fn page_bounds<B>(cursor: Option<B>, order: Order) -> (Option<B>, Option<B>) {
+    match order {
+        Order::Ascending => (cursor, None),
+        Order::Descending => (None, cursor),
+    }
+}
+
+/// Orders a list of keys stored in a single value and drops everything up to the cursor.
+pub fn page_keys<K: Ord>(
+    mut keys: Vec<K>,
+    cursor: Option<K>,
+    order: Order,
+) -> impl Iterator<Item = K> {
+    keys.sort();
+    if order == Order::Descending {
+        keys.reverse();
+    }
+    keys.into_iter().filter(move |key| match (&cursor, order) {
+        (None, _) => true,
+        (Some(cursor), Order::Ascending) => key > cursor,
+        (Some(cursor), Order::Descending) => key < cursor,
+    })
+}
+
 impl PageRequest {
     pub fn new(limit: u64) -> Self {
         Self {
@@ -224,12 +303,14 @@ pub enum PerpetualPosition {
 }
 
 impl PerpetualPosition {
-    pub fn try_from_i32(value: i32) -> Result<Self, StdError> {
+    pub fn try_from_i32(value: i32) -> Result<Self, ElysBindingsError> {
         match value {
             0 => Ok(Self::Unspecified),
             1 => Ok(Self::Long),
             2 => Ok(Self::Short),
-            _ => Err(StdError::generic_err("PerpetualPosition out of range")),
+            _ => Err(ElysBindingsError::InvalidPosition {
+                value: value.to_string(),
+            }),
         }
     }
 }
@@ -245,14 +326,18 @@ impl ToString for PerpetualPosition {
 }
 
 impl FromStr for PerpetualPosition {
-    type Err = StdError;
+    type Err = ElysBindingsError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s {
             "Unspecified" => PerpetualPosition::Unspecified,
             "Long" => PerpetualPosition::Long,
             "Short" => PerpetualPosition::Short,
-            _ => return Err(StdError::generic_err("unknow type")),
+            _ => {
+                return Err(ElysBindingsError::InvalidPosition {
+                    value: s.to_string(),
+                })
+            }
         })
     }
 }
@@ -8,9 +8,17 @@ pub fn perpetual_add_collateral(
     id: u64,
 ) -> Result<Response<ElysMsg>, ContractError> {
     let collateral = cw_utils::one_coin(&info)?;
-    if PERPETUAL_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.perpetual_enabled == false {
         return Err(StdError::generic_err("perpetual endpoint are disable").into());
     }
+    let mtp = match ElysQuerier::new(&deps.querier)
+        .mtp(info.sender.to_string(), id)?
+        .mtp
+    {
+        Some(mtp) => mtp.mtp,
+        None => return Err(StdError::not_found("perpetual trading position").into()),
+    };
+
     let msg = ElysMsg::perpetual_add_collateral(
         env.contract.address.as_str(),
         id,
@@ -18,7 +26,9 @@ pub fn perpetual_add_collateral(
         info.sender.as_str(),
     );
 
-    let resp = Response::new().add_message(CosmosMsg::Custom(msg));
+    let resp = Response::new()
+        .add_message(CosmosMsg::Custom(msg))
+        .add_event(events::collateral_added(&mtp, &collateral));
 
     Ok(resp)
 }
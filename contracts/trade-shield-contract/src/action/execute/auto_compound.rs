@@ -0,0 +1,211 @@
+use crate::helper::{check_not_paused, check_role, claim_rewards_msgs};
+use crate::msg::ReplyType;
+
+use super::*;
+use cosmwasm_std::{
+    to_json_binary, Coin, Decimal, Int128, OverflowError, OverflowOperation, StdError, SubMsg,
+};
+
+pub const MIN_AUTO_COMPOUND_INTERVAL: u64 = 3600;
+
+pub fn subscribe_auto_compound(
+    env: Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    policy: CompoundPolicy,
+    interval: u64,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_not_paused(deps.storage)?;
+
+    if !PARAMS.load(deps.storage)?.reward_enabled {
+        return Err(StdError::generic_err("reward endpoint are disable").into());
+    }
+
+    if interval < MIN_AUTO_COMPOUND_INTERVAL {
+        return Err(StdError::generic_err(format!(
+            "interval: must be at least {} seconds",
+            MIN_AUTO_COMPOUND_INTERVAL
+        ))
+        .into());
+    }
+
+    if let UsdcPolicy::Swap { max_slippage, .. } | UsdcPolicy::JoinPool { max_slippage, .. } =
+        &policy.usdc
+    {
+        if *max_slippage >= Decimal::one() {
+            return Err(StdError::generic_err("max_slippage: must be smaller than 1").into());
+        }
+    }
+
+    // the history of a previous subscription is kept
+    let previous = AUTO_COMPOUNDS.may_load(deps.storage, info.sender.as_str())?;
+    let subscription = AutoCompound {
+        owner: info.sender.clone(),
+        policy,
+        interval,
+        next_run: env.block.time.plus_seconds(interval),
+        runs: previous.as_ref().map_or(0, |previous| previous.runs),
+        last_run: previous.and_then(|previous| previous.last_run),
+    };
+    AUTO_COMPOUNDS.save(deps.storage, info.sender.as_str(), &subscription)?;
+
+    Ok(Response::new().add_event(events::auto_compound_subscribed(&subscription)))
+}
+
+pub fn unsubscribe_auto_compound(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+
+    if !AUTO_COMPOUNDS.has(deps.storage, info.sender.as_str()) {
+        return Err(StdError::not_found(format!("auto-compound of {}", info.sender)).into());
+    }
+    AUTO_COMPOUNDS.remove(deps.storage, info.sender.as_str());
+
+    Ok(Response::new().add_event(events::auto_compound_unsubscribed(info.sender)))
+}
+
+/// Claims the rewards of `owner` and puts them back to work according to its
+/// policy, the claimed amounts are known upfront from the pending rewards.
+pub fn run_auto_compound(
+    env: Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    owner: String,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_not_paused(deps.storage)?;
+
+    if !PARAMS.load(deps.storage)?.reward_enabled {
+        return Err(StdError::generic_err("reward endpoint are disable").into());
+    }
+
+    let mut subscription = match AUTO_COMPOUNDS.may_load(deps.storage, &owner)? {
+        Some(subscription) => subscription,
+        None => return Err(StdError::not_found(format!("auto-compound of {}", owner)).into()),
+    };
+
+    if info.sender != subscription.owner {
+        if info.sender != env.contract.address {
+            check_role(deps.storage, Role::Keeper, &info.sender)?;
+        }
+        if !subscription.is_due(env.block.time) {
+            return Err(StdError::generic_err(format!(
+                "auto-compound of {} is not due before {}",
+                owner,
+                subscription.next_run.seconds()
+            ))
+            .into());
+        }
+    }
+
+    let querier = ElysQuerier::new(&deps.querier);
+    let (msgs, claimed) = claim_rewards_msgs(&querier, &owner)?;
+    let mut resp = Response::new().add_messages(msgs);
+    let mut compounded: Vec<Coin> = vec![];
+
+    let eden = claimed.amount_of(ElysDenom::Eden.as_str());
+    if !eden.is_zero() {
+        let amount = Int128::new(eden.u128() as i128);
+        let denom = ElysDenom::Eden.as_str().to_string();
+        match subscription.policy.eden {
+            EdenPolicy::Stake => {
+                resp = resp.add_message(ElysMsg::stake_token(owner.clone(), amount, denom, None));
+                compounded.push(Coin::new(eden.u128(), ElysDenom::Eden.as_str()));
+            }
+            EdenPolicy::Vest => {
+                resp = resp.add_message(ElysMsg::eden_vesting(owner.clone(), amount, denom));
+                compounded.push(Coin::new(eden.u128(), ElysDenom::Eden.as_str()));
+            }
+            EdenPolicy::Keep => {}
+        }
+    }
+
+    let usdc_denom = querier
+        .get_asset_profile(ElysDenom::Usdc.as_str().to_string())?
+        .entry
+        .denom;
+    let usdc = Coin::new(claimed.amount_of(&usdc_denom).u128(), &usdc_denom);
+    if !usdc.amount.is_zero() {
+        match &subscription.policy.usdc {
+            UsdcPolicy::Swap {
+                denom_out,
+                max_slippage,
+            } => {
+                let price =
+                    querier.get_asset_price_from_denom_in_to_denom_out(&usdc_denom, denom_out)?;
+                let min_amount = usdc.amount * (price * (Decimal::one() - *max_slippage));
+                resp = resp.add_message(ElysMsg::swap_by_denom(
+                    &owner,
+                    usdc.clone(),
+                    Coin::new(min_amount.u128(), denom_out),
+                    Coin::new(0, denom_out),
+                    &usdc_denom,
+                    denom_out,
+                    Decimal::zero(),
+                    &owner,
+                ));
+                compounded.push(usdc);
+            }
+            UsdcPolicy::JoinPool {
+                pool_id,
+                max_slippage,
+            } => {
+                let share_amount_out = querier
+                    .join_pool_estimation(*pool_id, vec![usdc.clone()])?
+                    .share_amount_out
+                    .amount;
+                let min_share_amount_out = share_amount_out * (Decimal::one() - *max_slippage);
+
+                // the position of the owner is tracked like any other join
+                let reply_id = match MAX_REPLY_ID.load(deps.storage)?.checked_add(1) {
+                    Some(id) => id,
+                    None => {
+                        return Err(StdError::overflow(OverflowError::new(
+                            OverflowOperation::Add,
+                            "reply_info_max_id",
+                            "increment one",
+                        ))
+                        .into())
+                    }
+                };
+                MAX_REPLY_ID.save(deps.storage, &reply_id)?;
+                REPLY_INFO.save(
+                    deps.storage,
+                    reply_id,
+                    &ReplyInfo {
+                        id: reply_id,
+                        reply_type: ReplyType::AmmJoinPool,
+                        data: Some(to_json_binary(&(owner.clone(), *pool_id))?),
+                        fee: None,
+                    },
+                )?;
+
+                let msg = ElysMsg::amm_join_pool(
+                    owner.clone(),
+                    *pool_id,
+                    vec![usdc.clone()],
+                    min_share_amount_out,
+                    false,
+                );
+                resp = resp.add_submessage(SubMsg::reply_always(msg, reply_id));
+                compounded.push(usdc);
+            }
+            UsdcPolicy::Keep => {}
+        }
+    }
+
+    let run = CompoundRun {
+        time: env.block.time,
+        claimed: claimed.into_vec(),
+        compounded,
+        error: None,
+    };
+    let event = events::auto_compound_run(&owner, &run);
+    subscription.record(run);
+    AUTO_COMPOUNDS.save(deps.storage, &owner, &subscription)?;
+
+    Ok(resp.add_event(event))
+}
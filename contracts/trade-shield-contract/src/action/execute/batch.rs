@@ -0,0 +1,250 @@
+use crate::helper::{check_not_paused, claim_rewards_msgs};
+use crate::msg::ReplyType;
+use elys_bindings::query_resp::AmmSwapEstimationByDenomResponse;
+
+use super::*;
+use cosmwasm_std::{
+    to_json_binary, Coin, Decimal, Int128, OverflowError, OverflowOperation, SignedDecimal,
+    StdError, SubMsg, Uint128,
+};
+
+pub const MAX_BATCH_ACTIONS: usize = 10;
+
+pub fn batch(
+    env: Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    actions: Vec<BatchAction>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_not_paused(deps.storage)?;
+
+    if actions.is_empty() || actions.len() > MAX_BATCH_ACTIONS {
+        return Err(StdError::generic_err(format!(
+            "actions: must hold between 1 and {} actions",
+            MAX_BATCH_ACTIONS
+        ))
+        .into());
+    }
+
+    for (step, action) in actions.iter().enumerate() {
+        if let Some(input_step) = action.input_step() {
+            if input_step as usize >= step {
+                return Err(StdError::generic_err(format!(
+                    "actions: step {} can only spend the output of an earlier step",
+                    step
+                ))
+                .into());
+            }
+        }
+    }
+
+    if BATCH.exists(deps.storage) {
+        return Err(StdError::generic_err("a batch is already being executed").into());
+    }
+
+    let batch = Batch {
+        owner: info.sender,
+        actions,
+        outputs: vec![],
+    };
+
+    run_batch(deps, &env, batch, Response::new())
+}
+
+// messages of a step, the output is unknown until the reply of the last message
+enum Step {
+    Done(Vec<ElysMsg>, Vec<Coin>),
+    // denom of the output and balance of the owner in it before the message when the
+    // output is measured from the balance rather than from the reply
+    Reply(Box<ElysMsg>, String, Option<Uint128>),
+}
+
+/// Dispatches the next steps of `batch` until one of them needs the reply of
+/// its message to know its output, the batch is then resumed from the reply.
+pub fn run_batch(
+    deps: DepsMut<ElysQuery>,
+    env: &Env,
+    mut batch: Batch,
+    mut resp: Response<ElysMsg>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    let params = PARAMS.load(deps.storage)?;
+    let querier = ElysQuerier::new(&deps.querier);
+    let owner = batch.owner.to_string();
+
+    while batch.outputs.len() < batch.actions.len() {
+        let step = batch.outputs.len();
+        let action = &batch.actions[step];
+
+        let amount = |amount: &BatchAmount, denom: &str| -> Result<Coin, ContractError> {
+            let amount = batch.amount(amount, denom);
+            if amount.amount.is_zero() {
+                return Err(StdError::generic_err(format!(
+                    "step {}: nothing to spend in {}",
+                    step, denom
+                ))
+                .into());
+            }
+            Ok(amount)
+        };
+
+        let dispatch = match action {
+            BatchAction::ClaimRewards {} => {
+                if !params.reward_enabled {
+                    return Err(StdError::generic_err("reward endpoint are disable").into());
+                }
+                let (msgs, claimed) = claim_rewards_msgs(&querier, &owner)?;
+                Step::Done(msgs, claimed.into_vec())
+            }
+            BatchAction::Swap {
+                denom_in,
+                amount: amount_in,
+                denom_out,
+                min_amount_out,
+            } => {
+                if !params.swap_enabled {
+                    return Err(StdError::generic_err("swap is disable").into());
+                }
+                let token_in = amount(amount_in, denom_in)?;
+                let AmmSwapEstimationByDenomResponse { in_route, .. } = querier
+                    .amm_swap_estimation_by_denom(
+                        &token_in,
+                        denom_in,
+                        denom_out,
+                        &Decimal::zero(),
+                    )?;
+                let in_route = match in_route {
+                    Some(in_route) => in_route,
+                    None => return Err(StdError::not_found("swap route").into()),
+                };
+                Step::Reply(
+                    Box::new(ElysMsg::amm_swap_exact_amount_in(
+                        &owner,
+                        &token_in,
+                        &in_route,
+                        Int128::new(min_amount_out.u128() as i128),
+                        Decimal::zero(),
+                        &owner,
+                    )),
+                    denom_out.clone(),
+                    None,
+                )
+            }
+            BatchAction::Stake {
+                asset,
+                amount: amount_in,
+                validator_address,
+            } => {
+                if !params.reward_enabled || !params.stake_enabled {
+                    return Err(StdError::generic_err("stake endpoint is disable").into());
+                }
+                if validator_address.is_none() && asset == ElysDenom::Elys.as_str() {
+                    return Err(StdError::generic_err(
+                        "The validator Address is required only if the staked asset is uelys",
+                    )
+                    .into());
+                }
+                let stake = amount(amount_in, asset)?;
+                Step::Done(
+                    vec![ElysMsg::stake_token(
+                        owner.clone(),
+                        Int128::new(stake.amount.u128() as i128),
+                        stake.denom,
+                        validator_address.clone(),
+                    )],
+                    vec![],
+                )
+            }
+            BatchAction::ClosePerpetualPosition { id, amount } => {
+                if !params.perpetual_enabled {
+                    return Err(StdError::generic_err("perpetual endpoint are disable").into());
+                }
+                let mtp = match querier.mtp(owner.clone(), *id)?.mtp {
+                    Some(mtp) => mtp.mtp,
+                    None => return Err(StdError::not_found("perpetual trading position").into()),
+                };
+                // the close response reports the closed custody, the returned collateral is
+                // measured from the balance of the owner
+                let balance = deps
+                    .querier
+                    .query_balance(&owner, &mtp.collateral_asset)?
+                    .amount;
+                Step::Reply(
+                    Box::new(ElysMsg::perpetual_close_position(
+                        env.contract.address.as_str(),
+                        *id,
+                        amount.i128(),
+                        &owner,
+                    )),
+                    mtp.collateral_asset,
+                    Some(balance),
+                )
+            }
+            BatchAction::OpenLeveragelpPosition {
+                amm_pool_id,
+                collateral_asset,
+                collateral_amount,
+                leverage,
+                stop_loss_price,
+            } => {
+                if !params.leverage_enabled {
+                    return Err(StdError::generic_err("leverage endpoint are disable").into());
+                }
+                if *leverage <= SignedDecimal::one() {
+                    return Err(StdError::generic_err("leverage must be greater than 1").into());
+                }
+                let collateral = amount(collateral_amount, collateral_asset)?;
+                Step::Done(
+                    vec![ElysMsg::leveragelp_open_position(
+                        owner.clone(),
+                        *amm_pool_id,
+                        collateral.denom,
+                        Int128::new(collateral.amount.u128() as i128),
+                        *leverage,
+                        *stop_loss_price,
+                    )],
+                    vec![],
+                )
+            }
+        };
+
+        match dispatch {
+            Step::Done(msgs, output) => {
+                resp = resp.add_messages(msgs);
+                batch.outputs.push(output);
+            }
+            Step::Reply(msg, denom_out, balance_before) => {
+                let reply_id = match MAX_REPLY_ID.load(deps.storage)?.checked_add(1) {
+                    Some(id) => id,
+                    None => {
+                        return Err(StdError::overflow(OverflowError::new(
+                            OverflowOperation::Add,
+                            "reply_info_max_id",
+                            "increment one",
+                        ))
+                        .into())
+                    }
+                };
+                MAX_REPLY_ID.save(deps.storage, &reply_id)?;
+                REPLY_INFO.save(
+                    deps.storage,
+                    reply_id,
+                    &ReplyInfo {
+                        id: reply_id,
+                        reply_type: ReplyType::BatchStep,
+                        data: Some(to_json_binary(&(denom_out, balance_before))?),
+                        fee: None,
+                    },
+                )?;
+                BATCH.save(deps.storage, &batch)?;
+
+                // a failing step fails the transaction, reverting the steps before it
+                return Ok(resp.add_submessage(SubMsg::reply_on_success(*msg, reply_id)));
+            }
+        }
+    }
+
+    BATCH.remove(deps.storage);
+
+    Ok(resp.add_event(events::batch_executed(&batch)))
+}
@@ -1,5 +1,3 @@
-use cosmwasm_std::StdError;
-
 use crate::helper::remove_perpetual_order;
 
 use super::*;
@@ -7,11 +5,9 @@ use super::*;
 pub fn cancel_perpetual_order(
     info: MessageInfo,
     deps: DepsMut<ElysQuery>,
+    env: Env,
     order_id: u64,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if PERPETUAL_ENABLED.load(deps.storage)? == false {
-        return Err(StdError::generic_err("perpetual endpoint are disable").into());
-    }
     let order = match PERPETUAL_ORDER_V2.may_load(deps.storage, order_id)? {
         Some(order) => order,
         None => return Err(ContractError::OrderNotFound { order_id }),
@@ -30,12 +26,15 @@ pub fn cancel_perpetual_order(
         });
     }
 
-    let bank_msg = remove_perpetual_order(order_id, Status::Canceled, deps.storage, None)?;
+    let bank_msg = remove_perpetual_order(
+        order_id,
+        Status::Canceled,
+        deps.storage,
+        env.block.time,
+        None,
+    )?;
 
-    let resp = Response::new().add_event(
-        Event::new("cancel_perpetual_order")
-            .add_attribute("perpetual_order_id", order.order_id.to_string()),
-    );
+    let resp = Response::new().add_event(events::order_cancelled(&order, "cancelled by owner"));
 
     let resp = match bank_msg {
         Some(bank_msg) => resp.add_message(bank_msg),
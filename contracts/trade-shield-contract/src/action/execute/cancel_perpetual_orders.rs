@@ -7,12 +7,10 @@ use super::*;
 pub fn cancel_perpetual_orders(
     info: MessageInfo,
     deps: DepsMut<ElysQuery>,
+    env: Env,
     order_ids: Option<Vec<u64>>,
     order_type: Option<PerpetualOrderType>,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if PERPETUAL_ENABLED.load(deps.storage)? == false {
-        return Err(StdError::generic_err("perpetual endpoint are disable").into());
-    }
     let orders: Vec<PerpetualOrderV2> = if let Some(ids) = &order_ids {
         if ids.is_empty() {
             return Err(StdError::generic_err("order_ids is defined empty").into());
@@ -78,7 +76,7 @@ pub fn cancel_perpetual_orders(
     let bank_msgs: Vec<BankMsg> = order_ids
         .iter()
         .map(|id| {
-            remove_perpetual_order(*id, Status::Canceled, deps.storage, None)
+            remove_perpetual_order(*id, Status::Canceled, deps.storage, env.block.time, None)
                 .map(|bank_msg| bank_msg)
         })
         .collect::<Result<Vec<Option<BankMsg>>, StdError>>()?
@@ -86,8 +84,14 @@ pub fn cancel_perpetual_orders(
         .filter_map(|bank_msg| bank_msg.to_owned())
         .collect();
 
+    let events: Vec<Event> = orders
+        .iter()
+        .map(|order| events::order_cancelled(order, "cancelled by owner"))
+        .collect();
+
     Ok(Response::new()
         .add_messages(bank_msgs)
+        .add_events(events)
         .set_data(to_json_binary(&order_ids)?))
 }
 
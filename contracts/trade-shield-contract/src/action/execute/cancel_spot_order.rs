@@ -1,5 +1,3 @@
-use cosmwasm_std::StdError;
-
 use crate::helper::remove_spot_order;
 
 use super::*;
@@ -7,11 +5,9 @@ use super::*;
 pub fn cancel_spot_order(
     info: MessageInfo,
     deps: DepsMut<ElysQuery>,
+    env: Env,
     order_id: u64,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if SWAP_ENABLED.load(deps.storage)? == false {
-        return Err(StdError::generic_err("swap is disable").into());
-    }
     let order: SpotOrder = match SPOT_ORDER.may_load(deps.storage, order_id)? {
         Some(order) => order,
         None => return Err(ContractError::OrderNotFound { order_id }),
@@ -30,11 +26,17 @@ pub fn cancel_spot_order(
         });
     }
 
-    let refund_msg = remove_spot_order(order.order_id, Status::Canceled, deps.storage)?.unwrap();
+    let refund_msg = remove_spot_order(
+        order.order_id,
+        Status::Canceled,
+        deps.storage,
+        env.block.time,
+    )?
+    .unwrap();
 
     let resp = Response::new()
         .add_message(CosmosMsg::Bank(refund_msg))
-        .add_event(Event::new("cancel_spot_order").add_attribute("order_id", order_id.to_string()));
+        .add_event(events::order_cancelled(&order, "cancelled by owner"));
 
     Ok(resp)
 }
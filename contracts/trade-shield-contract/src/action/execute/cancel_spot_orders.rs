@@ -6,12 +6,10 @@ use crate::helper::remove_spot_order;
 pub fn cancel_spot_orders(
     info: MessageInfo,
     deps: DepsMut<ElysQuery>,
+    env: Env,
     order_ids: Option<Vec<u64>>,
     order_type: Option<SpotOrderType>,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if SWAP_ENABLED.load(deps.storage)? == false {
-        return Err(StdError::generic_err("swap is disable").into());
-    }
     let orders: Vec<SpotOrder> = if let Some(ids) = &order_ids {
         if ids.is_empty() {
             return Err(StdError::generic_err("order_ids is defined empty").into());
@@ -74,12 +72,19 @@ pub fn cancel_spot_orders(
     let bank_msgs = order_ids
         .iter()
         .map(|id| {
-            remove_spot_order(*id, Status::Canceled, deps.storage).map(|bank_msg| bank_msg.unwrap())
+            remove_spot_order(*id, Status::Canceled, deps.storage, env.block.time)
+                .map(|bank_msg| bank_msg.unwrap())
         })
         .collect::<Result<Vec<BankMsg>, StdError>>()?;
 
+    let events: Vec<Event> = orders
+        .iter()
+        .map(|order| events::order_cancelled(order, "cancelled by owner"))
+        .collect();
+
     Ok(Response::new()
         .add_messages(bank_msgs)
+        .add_events(events)
         .set_data(to_json_binary(&order_ids)?))
 }
 
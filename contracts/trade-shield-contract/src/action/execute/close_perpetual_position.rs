@@ -1,4 +1,4 @@
-use cosmwasm_std::{Int128, StdError};
+use cosmwasm_std::{Coin, Int128, StdError};
 
 use super::*;
 
@@ -9,9 +9,16 @@ pub fn close_perpetual_position(
     id: u64,
     amount: Int128,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if PERPETUAL_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.perpetual_enabled == false {
         return Err(StdError::generic_err("perpetual endpoint are disable").into());
     }
+    let querier = ElysQuerier::new(&deps.querier);
+    let mtp = match querier.mtp(info.sender.to_string(), id)?.mtp {
+        Some(mtp) => mtp.mtp,
+        None => return Err(StdError::not_found("perpetual trading position").into()),
+    };
+    let custody = Coin::new(amount.i128().unsigned_abs(), &mtp.custody_asset);
+
     let msg = ElysMsg::perpetual_close_position(
         env.contract.address.as_str(),
         id,
@@ -19,7 +26,9 @@ pub fn close_perpetual_position(
         info.sender.as_str(),
     );
 
-    let resp = Response::new().add_message(CosmosMsg::Custom(msg));
+    let resp = Response::new()
+        .add_message(CosmosMsg::Custom(msg))
+        .add_event(events::position_closed(&mtp, &custody));
 
     Ok(resp)
 }
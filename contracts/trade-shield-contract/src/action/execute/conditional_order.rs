@@ -0,0 +1,198 @@
+use crate::helper::{check_not_paused, get_trigger_market_price};
+
+use super::*;
+use crate::msg::ExecuteMsg;
+use cosmwasm_std::{Decimal, OverflowError, OverflowOperation, StdError, StdResult, Storage};
+
+pub fn create_conditional_order(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    condition: PriceCondition,
+    msg: Box<ExecuteMsg>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    check_not_paused(deps.storage)?;
+
+    match msg.as_ref() {
+        ExecuteMsg::CreateSpotOrder { .. } | ExecuteMsg::CreatePerpetualOrder { .. } => {}
+        // funds sent with a close would be stuck in the contract
+        ExecuteMsg::ClosePerpetualPosition { .. } => {
+            cw_utils::nonpayable(&info)?;
+        }
+        _ => {
+            return Err(StdError::generic_err(
+                "msg: only spot orders, perpetual orders and position closes can be conditional",
+            )
+            .into())
+        }
+    }
+
+    let OrderPrice {
+        base_denom,
+        quote_denom,
+        rate,
+    } = &condition.price;
+
+    if rate.is_zero() {
+        return Err(StdError::generic_err("condition: rate cannot be zero").into());
+    }
+
+    if base_denom == quote_denom {
+        return Err(StdError::generic_err("condition: base and quote denom are the same").into());
+    }
+
+    // rejects the pairs the oracle cannot price, the order would never trigger
+    let querier = ElysQuerier::new(&deps.querier);
+    get_trigger_market_price(&querier, base_denom, quote_denom)?;
+
+    let order_id = match CONDITIONAL_ORDER_MAX_ID
+        .may_load(deps.storage)?
+        .unwrap_or(0)
+        .checked_add(1)
+    {
+        Some(id) => id,
+        None => {
+            return Err(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                "conditional_order_max_id",
+                "increment one",
+            ))
+            .into())
+        }
+    };
+    CONDITIONAL_ORDER_MAX_ID.save(deps.storage, &order_id)?;
+
+    let order = ConditionalOrder {
+        order_id,
+        owner: info.sender,
+        condition,
+        msg,
+        escrow: info.funds,
+        status: Status::Pending,
+    };
+    CONDITIONAL_ORDERS.save(deps.storage, order_id, &order)?;
+    insert_sorted_conditional_order(deps.storage, &order)?;
+
+    Ok(Response::new().add_event(events::conditional_order_created(&order)))
+}
+
+pub fn cancel_conditional_order(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    order_id: u64,
+) -> Result<Response<ElysMsg>, ContractError> {
+    // like the other cancellations it stays available when the contract is paused
+    cw_utils::nonpayable(&info)?;
+
+    let mut order = match CONDITIONAL_ORDERS.may_load(deps.storage, order_id)? {
+        Some(order) => order,
+        None => return Err(ContractError::OrderNotFound { order_id }),
+    };
+
+    if order.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender,
+        });
+    }
+
+    if order.status != Status::Pending {
+        return Err(ContractError::CancelStatusError {
+            order_id,
+            status: order.status,
+        });
+    }
+
+    remove_sorted_conditional_order(deps.storage, &order)?;
+    order.status = Status::Canceled;
+    CONDITIONAL_ORDERS.save(deps.storage, order_id, &order)?;
+
+    let mut resp = Response::new().add_event(events::conditional_order_cancelled(&order));
+    if !order.escrow.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: order.owner.to_string(),
+            amount: order.escrow,
+        });
+    }
+
+    Ok(resp)
+}
+
+/// Marks the order executed and returns the message its wrapped order is
+/// executed with, the owner becoming the sender of the escrowed funds.
+pub fn execute_conditional_order(
+    env: &Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    order_id: u64,
+) -> Result<(MessageInfo, ExecuteMsg, Event), ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender,
+        });
+    }
+
+    let mut order = match CONDITIONAL_ORDERS.may_load(deps.storage, order_id)? {
+        Some(order) => order,
+        None => return Err(ContractError::OrderNotFound { order_id }),
+    };
+
+    if order.status != Status::Pending {
+        return Err(StdError::generic_err(format!(
+            "conditional order {} is not pending",
+            order_id
+        ))
+        .into());
+    }
+
+    order.status = Status::Executed;
+    CONDITIONAL_ORDERS.save(deps.storage, order_id, &order)?;
+
+    let event = events::conditional_order_triggered(&order);
+    let info = MessageInfo {
+        sender: order.owner,
+        funds: order.escrow,
+    };
+
+    Ok((info, *order.msg, event))
+}
+
+fn insert_sorted_conditional_order(
+    storage: &mut dyn Storage,
+    order: &ConditionalOrder,
+) -> StdResult<()> {
+    let key = order.gen_key();
+    let mut order_ids = SORTED_CONDITIONAL_ORDERS
+        .may_load(storage, &key)?
+        .unwrap_or_default();
+
+    let rates = order_ids
+        .iter()
+        .map(|id| {
+            CONDITIONAL_ORDERS
+                .load(storage, *id)
+                .map(|order| order.condition.price.rate)
+        })
+        .collect::<StdResult<Vec<Decimal>>>()?;
+    let index = rates.partition_point(|rate| *rate <= order.condition.price.rate);
+    order_ids.insert(index, order.order_id);
+
+    SORTED_CONDITIONAL_ORDERS.save(storage, &key, &order_ids)
+}
+
+fn remove_sorted_conditional_order(
+    storage: &mut dyn Storage,
+    order: &ConditionalOrder,
+) -> StdResult<()> {
+    let key = order.gen_key();
+    let mut order_ids = match SORTED_CONDITIONAL_ORDERS.may_load(storage, &key)? {
+        Some(order_ids) => order_ids,
+        None => return Ok(()),
+    };
+
+    order_ids.retain(|id| *id != order.order_id);
+    if order_ids.is_empty() {
+        SORTED_CONDITIONAL_ORDERS.remove(storage, &key);
+        Ok(())
+    } else {
+        SORTED_CONDITIONAL_ORDERS.save(storage, &key, &order_ids)
+    }
+}
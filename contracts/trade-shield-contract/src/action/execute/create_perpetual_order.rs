@@ -1,9 +1,12 @@
-use crate::{helper::get_discount, msg::ReplyType};
+use crate::{
+    helper::{check_not_paused, check_order_params, compute_fee, get_discount},
+    msg::ReplyType,
+};
 
 use super::*;
 use cosmwasm_std::{
     coin, to_json_binary, DecCoin, Decimal256, OverflowError, OverflowOperation, SignedDecimal,
-    SignedDecimal256, StdError, StdResult, SubMsg,
+    SignedDecimal256, StdError, StdResult, SubMsg, Timestamp,
 };
 use cw_utils;
 use elys_bindings::query_resp::{Entry, QueryGetEntryResponse};
@@ -18,11 +21,14 @@ pub fn create_perpetual_order(
     leverage: Option<SignedDecimal>,
     trading_asset: Option<String>,
     take_profit_price: Option<SignedDecimal256>,
+    stop_loss_price: Option<OrderPrice>,
     order_type: PerpetualOrderType,
     trigger_price: Option<OrderPrice>,
     position_id: Option<u64>,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if PERPETUAL_ENABLED.load(deps.storage)? == false {
+    check_not_paused(deps.storage)?;
+
+    if PARAMS.load(deps.storage)?.perpetual_enabled == false {
         return Err(StdError::generic_err("perpetual endpoint are disable").into());
     }
     check_order_type(
@@ -31,10 +37,11 @@ pub fn create_perpetual_order(
         &trading_asset,
         &order_type,
         &trigger_price,
+        &stop_loss_price,
         &position_id,
     )?;
 
-    if MARKET_ORDER_ENABLED.load(deps.storage)? == false
+    if PARAMS.load(deps.storage)?.market_order_enabled == false
         && (order_type == PerpetualOrderType::MarketClose
             || order_type == PerpetualOrderType::MarketOpen)
     {
@@ -50,8 +57,10 @@ pub fn create_perpetual_order(
             trading_asset.unwrap(),
             leverage.unwrap(),
             take_profit_price,
+            stop_loss_price,
             trigger_price,
             env.contract.address.as_str(),
+            env.block.time,
         )
     } else {
         create_perpetual_close_order(
@@ -71,8 +80,15 @@ fn check_order_type(
     trading_asset: &Option<String>,
     order_type: &PerpetualOrderType,
     trigger_price: &Option<OrderPrice>,
+    stop_loss_price: &Option<OrderPrice>,
     position_id: &Option<u64>,
 ) -> StdResult<()> {
+    if stop_loss_price.is_some() && order_type != &LimitOpen && order_type != &MarketOpen {
+        return Err(StdError::generic_err(
+            "stop_loss_price: can only be set on a LimitOpen or MarketOpen order",
+        ));
+    }
+
     let mut not_found: Vec<&str> = vec![];
 
     if order_type != &MarketOpen && order_type != &MarketClose && trigger_price.is_none() {
@@ -116,8 +132,10 @@ fn create_perpetual_open_order(
     trading_asset: String,
     leverage: SignedDecimal,
     take_profit_price: Option<SignedDecimal256>,
+    stop_loss_price: Option<OrderPrice>,
     trigger_price: Option<OrderPrice>,
     creator: &str,
+    block_time: Timestamp,
 ) -> Result<Response<ElysMsg>, ContractError> {
     let collateral = cw_utils::one_coin(&info)?;
 
@@ -139,13 +157,41 @@ fn create_perpetual_open_order(
         },
     } = querier.get_asset_profile("uusdc".to_string())?;
 
+    let params = PARAMS.load(deps.storage)?;
+
+    if let Some(max_leverage) = params.max_leverage_of(&trading_asset) {
+        if leverage > max_leverage {
+            return Err(ContractError::LeverageAboveMax {
+                trading_asset,
+                max_leverage,
+            });
+        }
+    }
+
+    check_order_params(
+        deps.storage,
+        &querier,
+        &params,
+        info.sender.as_str(),
+        &collateral,
+        (&usdc_denom, &trading_asset),
+        order_type != MarketOpen,
+    )?;
+
+    let discount = get_discount(
+        deps.storage,
+        deps.querier,
+        block_time,
+        info.sender.to_string(),
+    )?;
+
     let open_estimation = querier.perpetual_open_estimation(
         position.clone(),
         leverage.clone(),
         &trading_asset,
         collateral.clone(),
         take_profit_price.clone(),
-        get_discount(deps.querier, info.sender.to_string())?,
+        discount,
     )?;
 
     if let Some(price) = &trigger_price {
@@ -168,6 +214,45 @@ fn create_perpetual_open_order(
         }
     }
 
+    if let Some(price) = &stop_loss_price {
+        if price.rate.is_zero() {
+            return Err(StdError::generic_err("stop_loss_price: The rate cannot be zero").into());
+        }
+
+        if price.base_denom != usdc_denom {
+            return Err(StdError::generic_err(
+                "stop_loss_price: The base denom should be the usdc denom",
+            )
+            .into());
+        }
+
+        if price.quote_denom != trading_asset {
+            return Err(StdError::generic_err(
+                "stop_loss_price: The quote denom should be the trading asset denom",
+            )
+            .into());
+        }
+    }
+
+    if let Some(price) = &stop_loss_price {
+        // a limit open enters at its trigger, a market open at the oracle price
+        let entry = match (&order_type, &trigger_price) {
+            (LimitOpen, Some(trigger_price)) => trigger_price.rate,
+            _ => querier.get_asset_price_from_denom_in_to_denom_out(&trading_asset, &usdc_denom)?,
+        };
+        let losing_side = match position {
+            PerpetualPosition::Long => price.rate < entry,
+            _ => price.rate > entry,
+        };
+        if !losing_side {
+            return Err(ContractError::StopLossPriceSide {
+                position: position.to_string(),
+                stop_loss: price.rate,
+                entry,
+            });
+        }
+    }
+
     let amount = Decimal256::new(open_estimation.position_size.amount.into());
 
     let order = PerpetualOrderV2::new_open(
@@ -179,6 +264,7 @@ fn create_perpetual_open_order(
         &leverage,
         &take_profit_price,
         &trigger_price,
+        &stop_loss_price,
         &orders,
         DecCoin {
             denom: open_estimation.position_size.denom,
@@ -218,10 +304,7 @@ fn create_perpetual_open_order(
         SORTED_PENDING_PERPETUAL_ORDER.save(deps.storage, key.as_str(), &vec)?;
     }
 
-    let resp = Response::new().add_event(
-        Event::new("create_perpetual_open_order")
-            .add_attribute("perpetual_order_id", order_id.to_string()),
-    );
+    let resp = Response::new().add_event(events::order_created(&order));
 
     if order_type != MarketOpen {
         let number_of_pending_order = NUMBER_OF_PENDING_ORDER.load(deps.storage)? + 1;
@@ -245,6 +328,12 @@ fn create_perpetual_open_order(
     let number_of_executed_order = NUMBER_OF_EXECUTED_ORDER.load(deps.storage)? + 1;
     NUMBER_OF_EXECUTED_ORDER.save(deps.storage, &number_of_executed_order)?;
 
+    let fee = compute_fee(&PARAMS.load(deps.storage)?, &collateral, discount);
+    let collateral = match &fee {
+        Some(fee) => coin((collateral.amount - fee.amount).u128(), &collateral.denom),
+        None => collateral,
+    };
+
     let msg = ElysMsg::perpetual_open_position(
         creator,
         collateral,
@@ -274,6 +363,7 @@ fn create_perpetual_open_order(
         id: reply_id,
         reply_type: ReplyType::PerpetualBrokerMarketOpen,
         data: Some(to_json_binary(&order_id)?),
+        fee,
     };
 
     REPLY_INFO.save(deps.storage, reply_id, &reply_info)?;
@@ -361,10 +451,7 @@ fn create_perpetual_close_order(
             SORTED_PENDING_PERPETUAL_ORDER.save(deps.storage, key.as_str(), &vec)?;
         }
 
-        let resp = Response::new().add_event(
-            Event::new("create_perpetual_close_order")
-                .add_attribute("perpetual_order_id", order.order_id.to_string()),
-        );
+        let resp = Response::new().add_event(events::order_amended(&order));
 
         return Ok(resp);
     };
@@ -394,10 +481,7 @@ fn create_perpetual_close_order(
         PENDING_PERPETUAL_ORDER_V2.save(deps.storage, order_id, &order)?;
     }
 
-    let resp = Response::new().add_event(
-        Event::new("create_perpetual_close_order")
-            .add_attribute("perpetual_order_id", order_id.to_string()),
-    );
+    let resp = Response::new().add_event(events::order_created(&order));
 
     if order_type != MarketClose {
         let number_of_pending_order = NUMBER_OF_PENDING_ORDER.load(deps.storage)? + 1;
@@ -435,6 +519,7 @@ fn create_perpetual_close_order(
         id: reply_id,
         reply_type: ReplyType::PerpetualBrokerMarketClose,
         data: Some(to_json_binary(&order_id)?),
+        fee: None,
     };
 
     REPLY_INFO.save(deps.storage, reply_id, &reply_info)?;
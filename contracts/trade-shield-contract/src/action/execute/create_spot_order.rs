@@ -1,9 +1,15 @@
 use cosmwasm_std::{
-    to_json_binary, Decimal, Int128, OverflowError, StdError, StdResult, Storage, SubMsg,
+    to_json_binary, Coin, Decimal, Int128, OverflowError, StdError, StdResult, Storage, SubMsg,
 };
 use elys_bindings::query_resp::AmmSwapEstimationByDenomResponse;
 
-use crate::{helper::get_discount, msg::ReplyType};
+use crate::{
+    helper::{
+        check_not_paused, check_order_params, compute_fee, get_discount, insert_pending_spot_order,
+        next_spot_order_id,
+    },
+    msg::ReplyType,
+};
 
 use super::*;
 
@@ -14,21 +20,25 @@ pub fn create_spot_order(
     order_type: SpotOrderType,
     order_source_denom: String,
     order_target_denom: String,
-    order_price: Option<OrderPrice>,
+    extras: SpotOrderExtras,
 ) -> Result<Response<ElysMsg>, ContractError> {
     cw_utils::one_coin(&info)?;
 
     let querier = ElysQuerier::new(&deps.querier);
 
-    if SWAP_ENABLED.load(deps.storage)? == false {
+    check_not_paused(deps.storage)?;
+
+    if PARAMS.load(deps.storage)?.swap_enabled == false {
         return Err(StdError::generic_err("swap is disable").into());
     }
 
-    if MARKET_ORDER_ENABLED.load(deps.storage)? == false && order_type == SpotOrderType::MarketBuy {
+    if PARAMS.load(deps.storage)?.market_order_enabled == false
+        && order_type == SpotOrderType::MarketBuy
+    {
         return Err(StdError::generic_err("market order is disable").into());
     }
 
-    if let Some(price) = &order_price {
+    if let Some(price) = &extras.order_price {
         if price.rate.is_zero() {
             return Err(StdError::generic_err("order_price: The rate cannot be zero").into());
         }
@@ -37,40 +47,62 @@ pub fn create_spot_order(
     check_denom_error(
         &order_source_denom,
         &order_target_denom,
-        &order_price,
+        &extras.order_price,
         &order_type,
         &info.funds[0].denom,
+        extras.trigger_price.is_some(),
     )?;
 
-    let discount = get_discount(deps.querier, info.sender.to_string())?;
+    if let Some(trigger_price) = &extras.trigger_price {
+        check_trigger_price(trigger_price, &order_type)?;
+    }
+
+    if let Some(routes) = &extras.routes {
+        check_routes(routes, &order_target_denom)?;
+    }
 
-    let AmmSwapEstimationByDenomResponse { in_route, .. } = querier.amm_swap_estimation_by_denom(
+    check_order_params(
+        deps.storage,
+        &querier,
+        &PARAMS.load(deps.storage)?,
+        info.sender.as_str(),
         &info.funds[0],
-        &order_source_denom,
-        &order_target_denom,
-        &discount,
+        (&order_source_denom, &order_target_denom),
+        order_type != SpotOrderType::MarketBuy,
     )?;
-    let spot_order_max_id = SPOT_ORDER_MAX_ID.load(deps.storage)?;
-    let order_id = match spot_order_max_id.checked_add(1) {
-        Some(id) => id,
+
+    let discount = get_discount(
+        deps.storage,
+        deps.querier,
+        env.block.time,
+        info.sender.to_string(),
+    )?;
+
+    let in_route = match &extras.routes {
+        Some(routes) => routes.clone(),
         None => {
-            return Err(StdError::overflow(OverflowError::new(
-                cosmwasm_std::OverflowOperation::Add,
-                "spot_order_max_id",
-                "increment one",
-            ))
-            .into())
+            let AmmSwapEstimationByDenomResponse { in_route, .. } = querier
+                .amm_swap_estimation_by_denom(
+                    &info.funds[0],
+                    &order_source_denom,
+                    &order_target_denom,
+                    &discount,
+                )?;
+            match in_route {
+                Some(in_route) => in_route,
+                None => return Err(StdError::not_found("swap route").into()),
+            }
         }
     };
-    SPOT_ORDER_MAX_ID.save(deps.storage, &order_id)?;
+    let order_id = next_spot_order_id(deps.storage)?;
 
     let new_order: SpotOrder = SpotOrder::new(
         order_id,
         order_type.clone(),
-        order_price,
         info.funds[0].clone(),
         info.sender.clone(),
         order_target_denom,
+        extras,
         &env.block,
     );
 
@@ -79,25 +111,15 @@ pub fn create_spot_order(
         &new_order,
         deps.storage,
         discount,
-        in_route.unwrap(),
+        in_route,
     )?;
 
-    SPOT_ORDER.save(deps.storage, new_order.order_id, &new_order)?;
-
     if new_order.order_type != SpotOrderType::MarketBuy {
-        PENDING_SPOT_ORDER.save(deps.storage, new_order.order_id, &new_order)?;
-        let key = new_order.gen_key()?;
-        let mut vec = SORTED_PENDING_SPOT_ORDER
-            .may_load(deps.storage, key.as_str())?
-            .unwrap_or(vec![]);
-        let index = SpotOrder::binary_search(&new_order.order_price.rate, deps.storage, &vec)?;
-        if vec.len() <= index {
-            vec.push(new_order.order_id)
-        } else {
-            vec.insert(index, new_order.order_id);
-        }
-        SORTED_PENDING_SPOT_ORDER.save(deps.storage, key.as_str(), &vec)?;
+        insert_pending_spot_order(deps.storage, &new_order)?;
+        return Ok(resp);
     }
+
+    SPOT_ORDER.save(deps.storage, new_order.order_id, &new_order)?;
     let mut ids = USER_SPOT_ORDER
         .may_load(deps.storage, new_order.owner_address.as_str())?
         .unwrap_or(vec![]);
@@ -112,6 +134,7 @@ fn check_denom_error(
     order_price: &Option<OrderPrice>,
     order_type: &SpotOrderType,
     funds_send_denom: &str,
+    have_trigger_price: bool,
 ) -> Result<(), ContractError> {
     if order_source_denom != funds_send_denom {
         return Err(ContractError::SpotOrderWrongFund);
@@ -125,6 +148,17 @@ fn check_denom_error(
         return Ok(());
     }
 
+    // a triggered stop loss is a market sell, the limit orders keep their order price as limit
+    if have_trigger_price && order_type == &SpotOrderType::StopLoss {
+        if order_price.is_some() {
+            return Err(StdError::generic_err(
+                "order_price: cannot be set on a stop loss with a trigger_price",
+            )
+            .into());
+        }
+        return Ok(());
+    }
+
     if order_price.is_none() {
         return Err(StdError::not_found("order price").into());
     }
@@ -141,6 +175,38 @@ fn check_denom_error(
     Ok(())
 }
 
+fn check_trigger_price(
+    trigger_price: &OrderPrice,
+    order_type: &SpotOrderType,
+) -> Result<(), ContractError> {
+    if order_type == &SpotOrderType::MarketBuy {
+        return Err(StdError::generic_err("trigger_price: not supported on market order").into());
+    }
+    if trigger_price.rate.is_zero() {
+        return Err(StdError::generic_err("trigger_price: The rate cannot be zero").into());
+    }
+    if trigger_price.base_denom == trigger_price.quote_denom {
+        return Err(
+            StdError::generic_err("trigger_price: base and quote denom are the same").into(),
+        );
+    }
+    Ok(())
+}
+
+fn check_routes(
+    routes: &[SwapAmountInRoute],
+    order_target_denom: &str,
+) -> Result<(), ContractError> {
+    match routes.last() {
+        Some(route) if route.token_out_denom == order_target_denom => Ok(()),
+        Some(_) => Err(StdError::generic_err(
+            "routes: the last route should output the target denom",
+        )
+        .into()),
+        None => Err(StdError::generic_err("routes: cannot be empty").into()),
+    }
+}
+
 fn create_resp(
     sender: &str,
     new_order: &SpotOrder,
@@ -148,14 +214,9 @@ fn create_resp(
     discount: Decimal,
     in_route: Vec<SwapAmountInRoute>,
 ) -> StdResult<Response<ElysMsg>> {
-    let resp = Response::new().add_event(
-        Event::new("create_spot_order").add_attribute("order_id", new_order.order_id.to_string()),
-    );
+    let resp = Response::new().add_event(events::order_created(new_order));
     // if it is not market order, return response directly
     if new_order.order_type != SpotOrderType::MarketBuy {
-        let number_of_pending_order = NUMBER_OF_PENDING_ORDER.load(storage)? + 1;
-        NUMBER_OF_PENDING_ORDER.save(storage, &number_of_pending_order)?;
-
         return Ok(resp);
     }
 
@@ -179,9 +240,18 @@ fn create_resp(
 
     MAX_REPLY_ID.save(storage, &reply_id)?;
 
+    let fee = compute_fee(&PARAMS.load(storage)?, &new_order.order_amount, discount);
+    let swap_amount = match &fee {
+        Some(fee) => Coin {
+            denom: new_order.order_amount.denom.clone(),
+            amount: new_order.order_amount.amount - fee.amount,
+        },
+        None => new_order.order_amount.clone(),
+    };
+
     let swap_msg = ElysMsg::amm_swap_exact_amount_in(
         sender,
-        &new_order.order_amount,
+        &swap_amount,
         &in_route,
         Int128::zero(),
         discount,
@@ -192,6 +262,7 @@ fn create_resp(
         id: reply_id,
         reply_type: ReplyType::SpotOrderMarketBuy,
         data: Some(to_json_binary(&new_order.order_id)?),
+        fee,
     };
 
     REPLY_INFO.save(storage, reply_id, &reply_info)?;
@@ -0,0 +1,54 @@
+use crate::helper::check_role;
+
+use super::*;
+use cosmwasm_std::{Decimal, StdError, Timestamp};
+
+pub fn set_discount_override(
+    env: Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    address: String,
+    discount: Decimal,
+    expires_at: Option<Timestamp>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_role(deps.storage, Role::Admin, &info.sender)?;
+
+    let address = deps.api.addr_validate(&address)?;
+
+    if discount > Decimal::one() {
+        return Err(StdError::generic_err("discount: cannot be greater than one").into());
+    }
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= env.block.time {
+            return Err(StdError::generic_err("discount: expires_at is in the past").into());
+        }
+    }
+
+    let discount_override = DiscountOverride {
+        discount,
+        expires_at,
+    };
+    DISCOUNT_OVERRIDES.save(deps.storage, address.as_str(), &discount_override)?;
+
+    Ok(Response::new().add_event(events::discount_override_set(address, &discount_override)))
+}
+
+pub fn remove_discount_override(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    address: String,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_role(deps.storage, Role::Admin, &info.sender)?;
+
+    let address = deps.api.addr_validate(&address)?;
+
+    if !DISCOUNT_OVERRIDES.has(deps.storage, address.as_str()) {
+        return Err(StdError::not_found(format!("discount override of {}", address)).into());
+    }
+    DISCOUNT_OVERRIDES.remove(deps.storage, address.as_str());
+
+    Ok(Response::new().add_event(events::discount_override_removed(address)))
+}
@@ -6,7 +6,7 @@ pub fn eden_cancel_vest_request(
     deps: DepsMut<ElysQuery>,
     amount: u64,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if REWARD_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.reward_enabled == false {
         return Err(StdError::generic_err("reward endpoint are disable").into());
     }
     if amount == 0 {
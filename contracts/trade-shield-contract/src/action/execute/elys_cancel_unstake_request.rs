@@ -8,7 +8,7 @@ pub fn elys_cancel_unstake_request(
     amount: Coin,
     creation_height: i64,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if STAKE_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.stake_enabled == false {
         return Err(StdError::generic_err("stake endpoint are disable").into());
     }
     if amount.amount.is_zero() {
@@ -5,7 +5,7 @@ pub fn estaking_withdraw_elys_staking_rewards(
     info: MessageInfo,
     deps: DepsMut<ElysQuery>,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if REWARD_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.reward_enabled == false {
         return Err(StdError::generic_err("reward endpoint are disable").into());
     }
 
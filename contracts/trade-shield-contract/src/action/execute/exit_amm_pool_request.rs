@@ -1,5 +1,8 @@
 use super::*;
-use cosmwasm_std::{Coin, StdError, Uint128};
+use crate::msg::ReplyType;
+use cosmwasm_std::{
+    to_json_binary, Coin, OverflowError, OverflowOperation, StdError, SubMsg, Uint128,
+};
 
 pub fn exit_amm_pool_request(
     info: MessageInfo,
@@ -9,7 +12,7 @@ pub fn exit_amm_pool_request(
     share_amount_in: Uint128,
     token_out_denom: String,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if SWAP_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.swap_enabled == false {
         return Err(StdError::generic_err("swap is disable").into());
     }
 
@@ -29,14 +32,40 @@ pub fn exit_amm_pool_request(
         min_amounts_out_real_denom.push(real_coin.clone());
     }
 
+    let owner = info.sender.into_string();
+
     let msg: ElysMsg = ElysMsg::amm_exit_pool(
-        info.sender.into_string(),
+        owner.clone(),
         pool_id,
         min_amounts_out_real_denom,
         share_amount_in,
         token_out_denom,
     );
 
-    let resp = Response::new().add_message(msg);
+    let reply_info_max_id = MAX_REPLY_ID.load(deps.storage)?;
+
+    let reply_id = match reply_info_max_id.checked_add(1) {
+        Some(id) => id,
+        None => {
+            return Err(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                "reply_info_max_id",
+                "increment one",
+            ))
+            .into())
+        }
+    };
+    MAX_REPLY_ID.save(deps.storage, &reply_id)?;
+
+    let reply_info = ReplyInfo {
+        id: reply_id,
+        reply_type: ReplyType::AmmExitPool,
+        data: Some(to_json_binary(&(owner, pool_id, share_amount_in))?),
+        fee: None,
+    };
+
+    REPLY_INFO.save(deps.storage, reply_id, &reply_info)?;
+
+    let resp = Response::new().add_submessage(SubMsg::reply_always(msg, reply_id));
     Ok(resp)
 }
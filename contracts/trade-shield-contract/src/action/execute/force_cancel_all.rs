@@ -0,0 +1,95 @@
+use cosmwasm_std::{StdError, StdResult, Storage};
+use cw_storage_plus::Map;
+
+use crate::helper::{check_role, remove_perpetual_order, remove_spot_order};
+
+use super::*;
+
+/// Number of orders cancelled by a call when no limit is given.
+const DEFAULT_FORCE_CANCEL_LIMIT: u64 = 100;
+
+/// Cancels and refunds the pending spot and perpetual orders of a pair, up to
+/// `limit` orders per call. The call has to be repeated until no order remains.
+pub fn force_cancel_all(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    pair: TradingPair,
+    limit: Option<u64>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_role(deps.storage, Role::Keeper, &info.sender)?;
+
+    let mut limit = limit.unwrap_or(DEFAULT_FORCE_CANCEL_LIMIT) as usize;
+    if limit == 0 {
+        return Err(StdError::generic_err("limit: cannot be zero").into());
+    }
+
+    let mut remaining = 0;
+    let spot_ids = pending_orders_of_pair(
+        deps.storage,
+        SORTED_PENDING_SPOT_ORDER,
+        SpotOrder::keys_of_pair(&pair.base_denom, &pair.quote_denom),
+        &mut limit,
+        &mut remaining,
+    )?;
+    let perpetual_ids = pending_orders_of_pair(
+        deps.storage,
+        SORTED_PENDING_PERPETUAL_ORDER,
+        PerpetualOrderV2::keys_of_pair(&pair.base_denom, &pair.quote_denom),
+        &mut limit,
+        &mut remaining,
+    )?;
+
+    let mut bank_msgs: Vec<BankMsg> = vec![];
+    let mut order_events: Vec<Event> = vec![];
+
+    for id in spot_ids.iter() {
+        if let Some(bank_msg) =
+            remove_spot_order(*id, Status::Canceled, deps.storage, env.block.time)?
+        {
+            bank_msgs.push(bank_msg);
+        }
+        let order = SPOT_ORDER.load(deps.storage, *id)?;
+        order_events.push(events::order_cancelled(&order, "cancelled by admin"));
+    }
+
+    for id in perpetual_ids.iter() {
+        if let Some(bank_msg) =
+            remove_perpetual_order(*id, Status::Canceled, deps.storage, env.block.time, None)?
+        {
+            bank_msgs.push(bank_msg);
+        }
+        let order = PERPETUAL_ORDER_V2.load(deps.storage, *id)?;
+        order_events.push(events::order_cancelled(&order, "cancelled by admin"));
+    }
+
+    Ok(Response::new()
+        .add_messages(bank_msgs)
+        .add_events(order_events)
+        .add_attribute(
+            "cancelled",
+            (spot_ids.len() + perpetual_ids.len()).to_string(),
+        )
+        .add_attribute("remaining", remaining.to_string()))
+}
+
+/// Takes up to `limit` pending order ids sorted under `keys` and counts the
+/// ones left behind in `remaining`.
+fn pending_orders_of_pair(
+    storage: &dyn Storage,
+    sorted: Map<&str, Vec<u64>>,
+    keys: Vec<String>,
+    limit: &mut usize,
+    remaining: &mut usize,
+) -> StdResult<Vec<u64>> {
+    let mut ids: Vec<u64> = vec![];
+    for key in keys.iter() {
+        let order_ids = sorted.may_load(storage, key)?.unwrap_or_default();
+        let taken = order_ids.len().min(*limit);
+        ids.extend_from_slice(&order_ids[..taken]);
+        *limit -= taken;
+        *remaining += order_ids.len() - taken;
+    }
+    Ok(ids)
+}
@@ -0,0 +1,214 @@
+use crate::helper::{
+    check_not_paused, check_order_params, insert_pending_spot_order, next_spot_order_id,
+    remove_spot_order,
+};
+
+use super::*;
+use cosmwasm_std::{Coin, Decimal, OverflowError, OverflowOperation, StdError, Uint128};
+
+pub const MAX_GRID_LEVELS: u64 = 50;
+
+pub fn create_grid_order(
+    env: Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    base_denom: String,
+    quote_denom: String,
+    (lower_price, upper_price): (Decimal, Decimal),
+    levels: u64,
+) -> Result<Response<ElysMsg>, ContractError> {
+    check_not_paused(deps.storage)?;
+
+    let params = PARAMS.load(deps.storage)?;
+    if !params.swap_enabled {
+        return Err(StdError::generic_err("swap is disable").into());
+    }
+
+    if base_denom == quote_denom {
+        return Err(ContractError::SpotOrderSameDenom);
+    }
+
+    if !(2..=MAX_GRID_LEVELS).contains(&levels) {
+        return Err(StdError::generic_err(format!(
+            "levels: must be between 2 and {}",
+            MAX_GRID_LEVELS
+        ))
+        .into());
+    }
+
+    if lower_price.is_zero() || lower_price >= upper_price {
+        return Err(StdError::generic_err(
+            "lower_price: must be positive and smaller than upper_price",
+        )
+        .into());
+    }
+
+    if info.funds.is_empty() {
+        return Err(cw_utils::PaymentError::NoFunds {}.into());
+    }
+    if let Some(fund) = info
+        .funds
+        .iter()
+        .find(|fund| fund.denom != base_denom && fund.denom != quote_denom)
+    {
+        return Err(
+            StdError::generic_err(format!("funds: {} is not in the pair", fund.denom)).into(),
+        );
+    }
+
+    let querier = ElysQuerier::new(&deps.querier);
+    let market_price =
+        querier.get_asset_price_from_denom_in_to_denom_out(&base_denom, &quote_denom)?;
+
+    let grid_id = match GRID_MAX_ID
+        .may_load(deps.storage)?
+        .unwrap_or(0)
+        .checked_add(1)
+    {
+        Some(id) => id,
+        None => {
+            return Err(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                "grid_max_id",
+                "increment one",
+            ))
+            .into())
+        }
+    };
+    GRID_MAX_ID.save(deps.storage, &grid_id)?;
+
+    let mut grid = Grid {
+        grid_id,
+        owner: info.sender.clone(),
+        base_denom,
+        quote_denom,
+        prices: Grid::level_prices(lower_price, upper_price, levels),
+        order_ids: vec![],
+        idle: vec![],
+        realised_profit: Uint128::zero(),
+        filled_orders: 0,
+        active: true,
+    };
+
+    // buys are placed below the market price and sells above it
+    let buy_levels: Vec<u64> = (0..levels)
+        .filter(|level| grid.prices[*level as usize] < market_price)
+        .collect();
+    let sell_levels: Vec<u64> = (0..levels)
+        .filter(|level| grid.prices[*level as usize] > market_price)
+        .collect();
+
+    let mut resp = Response::new();
+    for fund in info.funds.iter() {
+        let (order_type, levels) = if fund.denom == grid.quote_denom {
+            (SpotOrderType::LimitBuy, &buy_levels)
+        } else {
+            (SpotOrderType::LimitSell, &sell_levels)
+        };
+
+        if levels.is_empty() {
+            return Err(StdError::generic_err(format!(
+                "funds: no level to place {} on at the market price of {}",
+                fund.denom, market_price
+            ))
+            .into());
+        }
+
+        let amount = fund.amount / Uint128::from(levels.len() as u64);
+        if amount.is_zero() {
+            return Err(StdError::generic_err(format!(
+                "funds: {} is too small for {} levels",
+                fund,
+                levels.len()
+            ))
+            .into());
+        }
+        let remainder = fund.amount - amount * Uint128::from(levels.len() as u64);
+        if !remainder.is_zero() {
+            grid.add_idle(Coin::new(remainder.u128(), &fund.denom));
+        }
+
+        for level in levels {
+            let amount = Coin::new(amount.u128(), &fund.denom);
+            check_order_params(
+                deps.storage,
+                &querier,
+                &params,
+                grid.owner.as_str(),
+                &amount,
+                (&grid.base_denom, &grid.quote_denom),
+                true,
+            )?;
+
+            let order_id = next_spot_order_id(deps.storage)?;
+            let order = grid.order(order_id, *level, order_type.clone(), amount, &env.block);
+            insert_pending_spot_order(deps.storage, &order)?;
+            GRID_ORDERS.save(
+                deps.storage,
+                order_id,
+                &GridLevel {
+                    grid_id,
+                    level: *level,
+                    cost: None,
+                },
+            )?;
+            grid.order_ids.push(order_id);
+            resp = resp.add_event(events::order_created(&order));
+        }
+    }
+
+    GRIDS.save(deps.storage, grid_id, &grid)?;
+
+    Ok(resp.add_event(events::grid_created(&grid)))
+}
+
+pub fn close_grid(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    grid_id: u64,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+
+    let grid = match GRIDS.may_load(deps.storage, grid_id)? {
+        Some(grid) => grid,
+        None => return Err(StdError::not_found(format!("grid {}", grid_id)).into()),
+    };
+
+    if grid.owner != info.sender {
+        return Err(ContractError::Unauthorized {
+            sender: info.sender,
+        });
+    }
+
+    if !grid.active {
+        return Err(StdError::generic_err(format!("grid {} is already closed", grid_id)).into());
+    }
+
+    let mut resp = Response::new();
+    let mut refund: Vec<Coin> = vec![];
+    for order_id in grid.order_ids.iter() {
+        let order = SPOT_ORDER.load(deps.storage, *order_id)?;
+        if let Some(refund_msg) =
+            remove_spot_order(*order_id, Status::Canceled, deps.storage, env.block.time)?
+        {
+            resp = resp.add_message(refund_msg);
+        }
+        refund.push(order.order_amount.clone());
+        resp = resp.add_event(events::order_cancelled(&order, "grid closed"));
+    }
+
+    // the levels are gone, only the idle funds are left in the grid
+    let mut grid = GRIDS.load(deps.storage, grid_id)?;
+    if !grid.idle.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: grid.owner.to_string(),
+            amount: grid.idle.clone(),
+        });
+        refund.append(&mut grid.idle);
+    }
+    grid.active = false;
+    GRIDS.save(deps.storage, grid_id, &grid)?;
+
+    Ok(resp.add_event(events::grid_closed(&grid, &refund)))
+}
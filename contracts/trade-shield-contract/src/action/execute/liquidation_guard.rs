@@ -0,0 +1,94 @@
+use crate::helper::check_not_paused;
+
+use super::*;
+use cosmwasm_std::{SignedDecimal, StdError, Uint128};
+
+pub fn create_liquidation_guard(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    position_id: u64,
+    health_threshold: SignedDecimal,
+    top_up_amount: Uint128,
+) -> Result<Response<ElysMsg>, ContractError> {
+    check_not_paused(deps.storage)?;
+    let escrow = cw_utils::one_coin(&info)?;
+
+    if !PARAMS.load(deps.storage)?.perpetual_enabled {
+        return Err(StdError::generic_err("perpetual endpoint are disable").into());
+    }
+
+    if health_threshold <= SignedDecimal::zero() {
+        return Err(StdError::generic_err("health_threshold: must be positive").into());
+    }
+
+    if top_up_amount.is_zero() {
+        return Err(StdError::generic_err("top_up_amount: cannot be zero").into());
+    }
+
+    let key = (info.sender.as_str(), position_id);
+    if LIQUIDATION_GUARDS.has(deps.storage, key) {
+        return Err(StdError::generic_err(format!(
+            "liquidation guard already exists for position {}",
+            position_id
+        ))
+        .into());
+    }
+
+    let querier = ElysQuerier::new(&deps.querier);
+    let mtp = match querier.mtp(info.sender.to_string(), position_id)?.mtp {
+        Some(mtp) => mtp.mtp,
+        None => return Err(StdError::not_found("perpetual trading position").into()),
+    };
+
+    if escrow.denom != mtp.collateral_asset {
+        return Err(StdError::generic_err(format!(
+            "escrow: expected collateral in {}",
+            mtp.collateral_asset
+        ))
+        .into());
+    }
+
+    let guard = LiquidationGuard {
+        owner: info.sender.to_string(),
+        position_id,
+        health_threshold,
+        top_up_amount,
+        escrow,
+    };
+    LIQUIDATION_GUARDS.save(deps.storage, key, &guard)?;
+
+    Ok(Response::new().add_event(events::liquidation_guard_created(&guard)))
+}
+
+pub fn cancel_liquidation_guard(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    position_id: u64,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+
+    let key = (info.sender.as_str(), position_id);
+    let guard = match LIQUIDATION_GUARDS.may_load(deps.storage, key)? {
+        Some(guard) => guard,
+        None => {
+            return Err(StdError::not_found(format!(
+                "liquidation guard of position {}",
+                position_id
+            ))
+            .into())
+        }
+    };
+    LIQUIDATION_GUARDS.remove(deps.storage, key);
+
+    let refund = BankMsg::Send {
+        to_address: guard.owner.clone(),
+        amount: vec![guard.escrow.clone()],
+    };
+
+    Ok(Response::new()
+        .add_message(refund)
+        .add_event(events::liquidation_guard_closed(
+            &guard,
+            "cancelled by owner",
+        )))
+}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use super::*;
+use crate::helper::reprice_perpetual_orders;
+use cosmwasm_std::{to_json_binary, Decimal, SignedDecimal, StdError};
+use elys_bindings::query_resp::{Entry, QueryGetEntryResponse};
+
+pub fn perpetual_update_stop_loss(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    id: u64,
+    price: SignedDecimal,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    if !PARAMS.load(deps.storage)?.perpetual_enabled {
+        return Err(StdError::generic_err("perpetual endpoint are disable").into());
+    }
+    if price <= SignedDecimal::zero() {
+        return Err(StdError::generic_err("price: should be greater than zero").into());
+    }
+
+    let querier = ElysQuerier::new(&deps.querier);
+    let mtp = match querier.mtp(info.sender.to_string(), id)?.mtp {
+        Some(mtp) => mtp.mtp,
+        None => return Err(StdError::not_found("perpetual trading position").into()),
+    };
+
+    let rate = Decimal::from_str(&price.to_string())?;
+
+    // like at creation, a stop loss on the winning side of the market would trigger at once
+    let QueryGetEntryResponse {
+        entry: Entry {
+            denom: usdc_denom, ..
+        },
+    } = querier.get_asset_profile("uusdc".to_string())?;
+    let market =
+        querier.get_asset_price_from_denom_in_to_denom_out(&mtp.trading_asset, usdc_denom)?;
+    let position = PerpetualPosition::try_from_i32(mtp.position)?;
+    let losing_side = match position {
+        PerpetualPosition::Long => rate < market,
+        _ => rate > market,
+    };
+    if !losing_side {
+        return Err(ContractError::StopLossPriceSide {
+            position: position.to_string(),
+            stop_loss: rate,
+            entry: market,
+        });
+    }
+    let orders = reprice_perpetual_orders(
+        deps.storage,
+        info.sender.as_str(),
+        id,
+        PerpetualOrderType::StopLoss,
+        rate,
+    )?;
+
+    let msg = ElysMsg::perpetual_update_stop_loss(
+        env.contract.address.as_str(),
+        id,
+        price,
+        info.sender.as_str(),
+    );
+
+    let order_ids: Vec<u64> = orders.iter().map(|order| order.order_id).collect();
+    let events: Vec<Event> = orders.iter().map(events::order_amended).collect();
+
+    let resp = Response::new()
+        .add_message(CosmosMsg::Custom(msg))
+        .add_events(events)
+        .set_data(to_json_binary(&order_ids)?);
+
+    Ok(resp)
+}
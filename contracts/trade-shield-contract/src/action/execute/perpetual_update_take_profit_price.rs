@@ -0,0 +1,52 @@
+use std::str::FromStr;
+
+use super::*;
+use crate::helper::reprice_perpetual_orders;
+use cosmwasm_std::{to_json_binary, Decimal, SignedDecimal256, StdError};
+
+pub fn perpetual_update_take_profit_price(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    id: u64,
+    price: SignedDecimal256,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    if !PARAMS.load(deps.storage)?.perpetual_enabled {
+        return Err(StdError::generic_err("perpetual endpoint are disable").into());
+    }
+    if price <= SignedDecimal256::zero() {
+        return Err(StdError::generic_err("price: should be greater than zero").into());
+    }
+
+    let querier = ElysQuerier::new(&deps.querier);
+    if querier.mtp(info.sender.to_string(), id)?.mtp.is_none() {
+        return Err(StdError::not_found("perpetual trading position").into());
+    }
+
+    let rate = Decimal::from_str(&price.to_string())?;
+    let orders = reprice_perpetual_orders(
+        deps.storage,
+        info.sender.as_str(),
+        id,
+        PerpetualOrderType::LimitClose,
+        rate,
+    )?;
+
+    let msg = ElysMsg::perpetual_update_take_profit_price(
+        env.contract.address.as_str(),
+        id,
+        price,
+        info.sender.as_str(),
+    );
+
+    let order_ids: Vec<u64> = orders.iter().map(|order| order.order_id).collect();
+    let events: Vec<Event> = orders.iter().map(events::order_amended).collect();
+
+    let resp = Response::new()
+        .add_message(CosmosMsg::Custom(msg))
+        .add_events(events)
+        .set_data(to_json_binary(&order_ids)?);
+
+    Ok(resp)
+}
@@ -0,0 +1,40 @@
+use crate::helper::check_role;
+
+use super::*;
+use cosmwasm_std::{Empty, StdError};
+
+pub fn grant_role(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    role: Role,
+    address: String,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_role(deps.storage, Role::Admin, &info.sender)?;
+
+    let address = deps.api.addr_validate(&address)?;
+
+    if ROLES.has(deps.storage, (role.as_str(), address.as_str())) {
+        return Err(StdError::generic_err(format!("{address} already has the {role} role")).into());
+    }
+    ROLES.save(deps.storage, (role.as_str(), address.as_str()), &Empty {})?;
+
+    Ok(Response::new().add_event(events::role_granted(info.sender, &role, address)))
+}
+
+pub fn revoke_role(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    role: Role,
+    address: String,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_role(deps.storage, Role::Admin, &info.sender)?;
+
+    if !ROLES.has(deps.storage, (role.as_str(), address.as_str())) {
+        return Err(StdError::not_found(format!("{role} role of {address}")).into());
+    }
+    ROLES.remove(deps.storage, (role.as_str(), address.as_str()));
+
+    Ok(Response::new().add_event(events::role_revoked(info.sender, &role, address)))
+}
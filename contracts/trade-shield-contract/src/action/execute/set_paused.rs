@@ -0,0 +1,24 @@
+use crate::helper::check_role;
+
+use super::*;
+use cosmwasm_std::StdError;
+
+pub fn set_paused(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    paused: bool,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    // guardians can only stop the contract, resuming it is left to the admin
+    let role = if paused { Role::Guardian } else { Role::Admin };
+    check_role(deps.storage, role, &info.sender)?;
+
+    if PAUSED.may_load(deps.storage)?.unwrap_or_default() == paused {
+        let state = if paused { "paused" } else { "unpaused" };
+        return Err(StdError::generic_err(format!("trade shield is already {state}")).into());
+    }
+
+    PAUSED.save(deps.storage, &paused)?;
+
+    Ok(Response::new().add_event(events::pause_changed(info.sender, paused)))
+}
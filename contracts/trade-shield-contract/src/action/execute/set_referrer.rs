@@ -0,0 +1,28 @@
+use super::*;
+use cosmwasm_std::{Decimal, StdError};
+
+pub fn set_referrer(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    referrer: String,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    let referrer = deps.api.addr_validate(&referrer)?;
+
+    if referrer == info.sender {
+        return Err(StdError::generic_err("referrer: cannot refer yourself").into());
+    }
+
+    if REFERRALS.has(deps.storage, info.sender.as_str()) {
+        return Err(StdError::generic_err("referrer: already set").into());
+    }
+
+    REFERRALS.save(deps.storage, info.sender.as_str(), &referrer.to_string())?;
+    REFEREES.save(
+        deps.storage,
+        (referrer.as_str(), info.sender.as_str()),
+        &Decimal::zero(),
+    )?;
+
+    Ok(Response::new().add_event(events::referrer_set(info.sender, referrer)))
+}
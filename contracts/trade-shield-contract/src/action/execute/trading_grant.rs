@@ -0,0 +1,115 @@
+use crate::helper::use_trading_grant;
+
+use super::*;
+use crate::msg::ExecuteMsg;
+use cosmwasm_std::{Coin, StdError, Timestamp};
+
+pub fn grant(
+    env: Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    grantee: String,
+    permissions: Vec<TradingPermission>,
+    spend_limit: Vec<Coin>,
+    expires_at: Option<Timestamp>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+
+    let grantee = deps.api.addr_validate(&grantee)?;
+
+    if grantee == info.sender {
+        return Err(StdError::generic_err("grantee: cannot be the granter").into());
+    }
+
+    if permissions.is_empty() {
+        return Err(StdError::generic_err("permissions: cannot be empty").into());
+    }
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= env.block.time {
+            return Err(StdError::generic_err("grant: expires_at is in the past").into());
+        }
+    }
+
+    let grant = TradingGrant {
+        granter: info.sender.to_string(),
+        grantee: grantee.to_string(),
+        permissions,
+        spend_limit,
+        expires_at,
+    };
+    TRADING_GRANTS.save(
+        deps.storage,
+        (info.sender.as_str(), grantee.as_str()),
+        &grant,
+    )?;
+
+    Ok(Response::new().add_event(events::grant_set(&grant)))
+}
+
+pub fn revoke(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    grantee: String,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+
+    let key = (info.sender.as_str(), grantee.as_str());
+    if !TRADING_GRANTS.has(deps.storage, key) {
+        return Err(StdError::not_found(format!("grant to {}", grantee)).into());
+    }
+    TRADING_GRANTS.remove(deps.storage, key);
+
+    Ok(Response::new().add_event(events::grant_revoked(info.sender, grantee)))
+}
+
+/// Checks the grant of the sender for `msg` and returns the message info it
+/// is executed with, the granter becoming the sender.
+pub fn on_behalf_of(
+    env: &Env,
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    granter: String,
+    msg: &ExecuteMsg,
+) -> Result<(MessageInfo, Event), ContractError> {
+    let permission = match msg {
+        ExecuteMsg::CreateSpotOrder { .. }
+        | ExecuteMsg::CreatePerpetualOrder { .. }
+        | ExecuteMsg::CreateConditionalOrder { .. } => TradingPermission::CreateOrder,
+        ExecuteMsg::CancelSpotOrder { .. }
+        | ExecuteMsg::CancelSpotOrders { .. }
+        | ExecuteMsg::CancelPerpetualOrder { .. }
+        | ExecuteMsg::CancelPerpetualOrders { .. }
+        | ExecuteMsg::CancelConditionalOrder { .. } => TradingPermission::CancelOrder,
+        ExecuteMsg::PerpetualUpdateStopLoss { .. }
+        | ExecuteMsg::PerpetualUpdateTakeProfitPrice { .. } => TradingPermission::AmendOrder,
+        _ => {
+            return Err(
+                StdError::generic_err("msg: cannot be executed on behalf of a granter").into(),
+            )
+        }
+    };
+
+    // only new orders are paid, funds sent with anything else would be stuck in the contract
+    if permission != TradingPermission::CreateOrder {
+        cw_utils::nonpayable(&info)?;
+    }
+
+    let granter = deps.api.addr_validate(&granter)?;
+    use_trading_grant(
+        deps.storage,
+        env.block.time,
+        granter.as_str(),
+        info.sender.as_str(),
+        &permission,
+        &info.funds,
+    )?;
+
+    let event = events::grant_used(&granter, &info.sender, &permission);
+    let info = MessageInfo {
+        sender: granter,
+        funds: info.funds,
+    };
+
+    Ok((info, event))
+}
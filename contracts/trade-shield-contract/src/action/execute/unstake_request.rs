@@ -12,10 +12,10 @@ pub fn unstake_request(
     // uelys.
     validator_address: Option<String>,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if REWARD_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.reward_enabled == false {
         return Err(StdError::generic_err("reward endpoint are disable").into());
     }
-    if STAKE_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.stake_enabled == false {
         return Err(StdError::generic_err("stake endpoint is disable").into());
     }
 
@@ -7,7 +7,7 @@ pub fn update_stop_loss_leveragelp_request(
     position: u64,
     price: SignedDecimal,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if LEVERAGE_ENABLED.load(deps.storage)? == false {
+    if PARAMS.load(deps.storage)?.leverage_enabled == false {
         return Err(StdError::generic_err("leverage endpoint are disable").into());
     }
 
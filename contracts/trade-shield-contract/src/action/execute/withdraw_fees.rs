@@ -0,0 +1,47 @@
+use crate::helper::check_role;
+
+use super::*;
+use cosmwasm_std::{Coin, StdError, StdResult, Uint128};
+
+pub fn withdraw_fees(
+    info: MessageInfo,
+    deps: DepsMut<ElysQuery>,
+    denoms: Option<Vec<String>>,
+) -> Result<Response<ElysMsg>, ContractError> {
+    cw_utils::nonpayable(&info)?;
+    check_role(deps.storage, Role::Admin, &info.sender)?;
+
+    let fee_collector = match PARAMS.load(deps.storage)?.fee_collector {
+        Some(fee_collector) => fee_collector,
+        None => return Err(StdError::not_found("fee collector").into()),
+    };
+
+    let denoms = match denoms {
+        Some(denoms) => denoms,
+        None => FEES_COLLECTED
+            .keys(deps.storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<String>>>()?,
+    };
+
+    let mut amounts: Vec<Coin> = vec![];
+    for denom in denoms {
+        let amount = FEES_COLLECTED
+            .may_load(deps.storage, &denom)?
+            .unwrap_or_default();
+        FEES_COLLECTED.remove(deps.storage, &denom);
+        if amount != Uint128::zero() {
+            amounts.push(Coin { denom, amount });
+        }
+    }
+
+    if amounts.is_empty() {
+        return Err(StdError::generic_err("no fees to withdraw").into());
+    }
+
+    Ok(Response::new()
+        .add_message(BankMsg::Send {
+            to_address: fee_collector.clone(),
+            amount: amounts.clone(),
+        })
+        .add_event(events::fees_withdrawn(fee_collector, &amounts)))
+}
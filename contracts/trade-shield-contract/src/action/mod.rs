@@ -1,4 +1,4 @@
-use crate::{states::*, types::*, ContractError};
+use crate::{events, states::*, types::*, ContractError};
 use cosmwasm_std::Event;
 use cosmwasm_std::{BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Order, Response};
 use elys_bindings::*;
@@ -6,15 +6,26 @@ use elys_bindings::*;
 pub mod query {
     mod asset_info;
     mod get_all_price;
+    mod get_auto_compound;
+    mod get_conditional_orders;
+    mod get_discount;
+    mod get_fees_collected;
+    mod get_grants;
+    mod get_grid;
+    mod get_liquidation_guards;
     mod get_perpetual_asset;
     mod get_perpetual_order;
     mod get_perpetual_orders;
     mod get_perpetual_position;
     mod get_perpetual_positions;
+    mod get_referral_stats;
+    mod get_roles;
     mod get_spot_order;
     mod get_spot_order_states;
     mod get_spot_orders;
     mod get_stat;
+    mod get_trading_stats;
+    mod get_user_pool_positions;
     mod perpetual_get_position_for_address;
     mod perpetual_open_estimation;
     mod swap_estimation_by_denom;
@@ -27,15 +38,26 @@ pub mod query {
 
     pub use asset_info::asset_info;
     pub use get_all_price::get_all_prices;
+    pub use get_auto_compound::get_auto_compound;
+    pub use get_conditional_orders::get_conditional_orders;
+    pub use get_discount::get_discount;
+    pub use get_fees_collected::get_fees_collected;
+    pub use get_grants::get_grants;
+    pub use get_grid::get_grid;
+    pub use get_liquidation_guards::get_liquidation_guards;
     pub use get_perpetual_asset::get_perpetuals_assets;
     pub use get_perpetual_order::get_perpetual_order;
     pub use get_perpetual_orders::get_perpetual_orders;
     pub use get_perpetual_position::get_perpetual_position;
     pub use get_perpetual_positions::get_perpetual_positions;
+    pub use get_referral_stats::{get_referees, get_referral_stats};
+    pub use get_roles::get_roles;
     pub use get_spot_order::get_spot_order;
     pub use get_spot_order_states::get_spot_order_states;
     pub use get_spot_orders::get_spot_orders;
     pub use get_stat::get_stat;
+    pub use get_trading_stats::{get_top_traders, get_trading_stats};
+    pub use get_user_pool_positions::get_user_pool_positions;
     pub use perpetual_get_position_for_address::perpetual_get_position_for_address;
     pub use perpetual_open_estimation::perpetual_open_estimation;
     pub use swap_estimation_by_denom::swap_estimation_by_denom;
@@ -43,13 +65,27 @@ pub mod query {
 
 pub mod execute {
     mod add_collateral_perpetual;
+    mod auto_compound;
+    mod batch;
     mod cancel_perpetual_order;
     mod cancel_perpetual_orders;
     mod cancel_spot_order;
     mod cancel_spot_orders;
     mod close_perpetual_position;
+    mod conditional_order;
     mod create_perpetual_order;
     mod create_spot_order;
+    mod discount_override;
+    mod force_cancel_all;
+    mod grid;
+    mod liquidation_guard;
+    mod perpetual_update_stop_loss;
+    mod perpetual_update_take_profit_price;
+    mod roles;
+    mod set_paused;
+    mod set_referrer;
+    mod trading_grant;
+    mod withdraw_fees;
 
     mod stake_request;
     mod unstake_request;
@@ -74,13 +110,31 @@ pub mod execute {
     use super::*;
 
     pub use add_collateral_perpetual::perpetual_add_collateral;
+    pub use auto_compound::{
+        run_auto_compound, subscribe_auto_compound, unsubscribe_auto_compound,
+    };
+    pub use batch::{batch, run_batch};
     pub use cancel_perpetual_order::cancel_perpetual_order;
     pub use cancel_perpetual_orders::cancel_perpetual_orders;
     pub use cancel_spot_order::cancel_spot_order;
     pub use cancel_spot_orders::cancel_spot_orders;
     pub use close_perpetual_position::close_perpetual_position;
+    pub use conditional_order::{
+        cancel_conditional_order, create_conditional_order, execute_conditional_order,
+    };
     pub use create_perpetual_order::create_perpetual_order;
     pub use create_spot_order::create_spot_order;
+    pub use discount_override::{remove_discount_override, set_discount_override};
+    pub use force_cancel_all::force_cancel_all;
+    pub use grid::{close_grid, create_grid_order};
+    pub use liquidation_guard::{cancel_liquidation_guard, create_liquidation_guard};
+    pub use perpetual_update_stop_loss::perpetual_update_stop_loss;
+    pub use perpetual_update_take_profit_price::perpetual_update_take_profit_price;
+    pub use roles::{grant_role, revoke_role};
+    pub use set_paused::set_paused;
+    pub use set_referrer::set_referrer;
+    pub use trading_grant::{grant, on_behalf_of, revoke};
+    pub use withdraw_fees::withdraw_fees;
 
     pub use claim_rewards_request::claim_rewards_request;
     pub use close_leveragelp_position_request::close_leveragelp_position_request;
@@ -105,16 +159,28 @@ pub mod reply {
     use super::*;
     use elys_bindings::msg_resp::*;
 
+    mod amm_exit_pool;
+    mod amm_join_pool;
+    mod auto_compound_run;
+    mod batch_step;
     mod close_perpetual_position;
+    mod conditional_order;
     mod create_perpetual_order_market_close;
     mod create_perpetual_order_market_open;
+    mod liquidation_guard_top_up;
     mod open_perpetual_position;
     mod spot_order;
     mod spot_order_market;
 
+    pub use amm_exit_pool::reply_to_amm_exit_pool;
+    pub use amm_join_pool::reply_to_amm_join_pool;
+    pub use auto_compound_run::reply_to_auto_compound_run;
+    pub use batch_step::reply_to_batch_step;
     pub use close_perpetual_position::reply_to_close_perpetual_order;
+    pub use conditional_order::reply_to_conditional_order;
     pub use create_perpetual_order_market_close::reply_to_create_perpetual_market_close;
     pub use create_perpetual_order_market_open::reply_to_create_perpetual_market_open;
+    pub use liquidation_guard_top_up::reply_to_liquidation_guard_top_up;
     pub use open_perpetual_position::reply_to_open_perpetual_position;
     pub use spot_order::reply_to_spot_order;
     pub use spot_order_market::reply_to_spot_order_market;
@@ -123,6 +189,12 @@ pub mod reply {
 pub mod sudo {
     use super::*;
 
+    mod process_auto_compounds;
+    mod process_conditional_orders;
+    pub(crate) mod process_liquidation_guards;
     mod process_orders;
+    pub use process_auto_compounds::process_auto_compounds;
+    pub use process_conditional_orders::process_conditional_orders;
+    pub use process_liquidation_guards::process_liquidation_guards;
     pub use process_orders::process_orders;
 }
@@ -0,0 +1,10 @@
+use super::*;
+
+pub fn get_auto_compound(
+    deps: Deps<ElysQuery>,
+    owner: String,
+) -> Result<GetAutoCompoundResp, ContractError> {
+    Ok(GetAutoCompoundResp {
+        subscription: AUTO_COMPOUNDS.may_load(deps.storage, &owner)?,
+    })
+}
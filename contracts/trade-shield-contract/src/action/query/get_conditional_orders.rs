@@ -0,0 +1,39 @@
+use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
+
+pub fn get_conditional_orders(
+    deps: Deps<ElysQuery>,
+    owner: String,
+    pagination: Option<PageRequest>,
+) -> Result<GetConditionalOrdersResp, ContractError> {
+    let range = |cursor: Option<u64>, order: Order| -> PageItems<u64, ConditionalOrder> {
+        let (min, max) = page_bounds(cursor.map(Bound::exclusive), order);
+        Box::new(
+            CONDITIONAL_ORDERS
+                .range(deps.storage, min, max, order)
+                .filter(|res| match res {
+                    Ok((_, conditional_order)) => conditional_order.owner == owner,
+                    Err(_) => true,
+                }),
+        )
+    };
+
+    let (orders, page_response) = match pagination {
+        Some(pagination) => {
+            let (orders, page_response) = pagination.paginate(range)?;
+            (orders, Some(page_response))
+        }
+        None => (
+            range(None, Order::Ascending)
+                .map(|res| res.map(|(_, order)| order))
+                .collect::<StdResult<Vec<ConditionalOrder>>>()?,
+            None,
+        ),
+    };
+
+    Ok(GetConditionalOrdersResp {
+        orders,
+        page_response,
+    })
+}
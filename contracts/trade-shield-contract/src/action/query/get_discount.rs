@@ -0,0 +1,20 @@
+use crate::helper::get_discount as user_discount;
+
+use super::*;
+
+pub fn get_discount(
+    deps: Deps<ElysQuery>,
+    env: Env,
+    address: String,
+) -> Result<GetDiscountResp, ContractError> {
+    let discount_override = DISCOUNT_OVERRIDES
+        .may_load(deps.storage, &address)?
+        .filter(|discount_override| discount_override.is_active(env.block.time));
+    let discount = user_discount(deps.storage, deps.querier, env.block.time, address.clone())?;
+
+    Ok(GetDiscountResp {
+        address,
+        discount,
+        discount_override,
+    })
+}
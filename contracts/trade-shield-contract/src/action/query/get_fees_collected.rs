@@ -0,0 +1,13 @@
+use super::*;
+use cosmwasm_std::{Coin, StdResult, Uint128};
+
+pub fn get_fees_collected(deps: Deps<ElysQuery>) -> Result<GetFeesCollectedResp, ContractError> {
+    let fees = FEES_COLLECTED
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Uint128)>>>()?
+        .into_iter()
+        .map(|(denom, amount)| Coin { denom, amount })
+        .collect();
+
+    Ok(GetFeesCollectedResp { fees })
+}
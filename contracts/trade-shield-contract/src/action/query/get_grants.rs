@@ -0,0 +1,39 @@
+use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
+
+pub fn get_grants(
+    deps: Deps<ElysQuery>,
+    granter: String,
+    pagination: Option<PageRequest>,
+) -> Result<GetGrantsResp, ContractError> {
+    let range = |cursor: Option<String>, order: Order| -> PageItems<String, TradingGrant> {
+        let (min, max) = page_bounds(
+            cursor.map(|grantee| Bound::ExclusiveRaw(grantee.into_bytes())),
+            order,
+        );
+        Box::new(
+            TRADING_GRANTS
+                .prefix(&granter)
+                .range(deps.storage, min, max, order),
+        )
+    };
+
+    let (grants, page_response) = match pagination {
+        Some(pagination) => {
+            let (grants, page_response) = pagination.paginate(range)?;
+            (grants, Some(page_response))
+        }
+        None => (
+            range(None, Order::Ascending)
+                .map(|res| res.map(|(_, grant)| grant))
+                .collect::<StdResult<Vec<TradingGrant>>>()?,
+            None,
+        ),
+    };
+
+    Ok(GetGrantsResp {
+        grants,
+        page_response,
+    })
+}
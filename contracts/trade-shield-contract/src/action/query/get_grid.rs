@@ -0,0 +1,17 @@
+use super::*;
+use cosmwasm_std::{StdError, StdResult};
+
+pub fn get_grid(deps: Deps<ElysQuery>, grid_id: u64) -> Result<GetGridResp, ContractError> {
+    let grid = match GRIDS.may_load(deps.storage, grid_id)? {
+        Some(grid) => grid,
+        None => return Err(StdError::not_found(format!("grid {}", grid_id)).into()),
+    };
+
+    let orders = grid
+        .order_ids
+        .iter()
+        .map(|order_id| SPOT_ORDER.load(deps.storage, *order_id))
+        .collect::<StdResult<Vec<SpotOrder>>>()?;
+
+    Ok(GetGridResp { grid, orders })
+}
@@ -0,0 +1,36 @@
+use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
+
+pub fn get_liquidation_guards(
+    deps: Deps<ElysQuery>,
+    owner: String,
+    pagination: Option<PageRequest>,
+) -> Result<GetLiquidationGuardsResp, ContractError> {
+    let range = |cursor: Option<u64>, order: Order| -> PageItems<u64, LiquidationGuard> {
+        let (min, max) = page_bounds(cursor.map(Bound::exclusive), order);
+        Box::new(
+            LIQUIDATION_GUARDS
+                .prefix(&owner)
+                .range(deps.storage, min, max, order),
+        )
+    };
+
+    let (guards, page_response) = match pagination {
+        Some(pagination) => {
+            let (guards, page_response) = pagination.paginate(range)?;
+            (guards, Some(page_response))
+        }
+        None => (
+            range(None, Order::Ascending)
+                .map(|res| res.map(|(_, guard)| guard))
+                .collect::<StdResult<Vec<LiquidationGuard>>>()?,
+            None,
+        ),
+    };
+
+    Ok(GetLiquidationGuardsResp {
+        guards,
+        page_response,
+    })
+}
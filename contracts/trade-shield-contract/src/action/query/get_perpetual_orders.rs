@@ -1,6 +1,6 @@
-use cosmwasm_std::StdError;
-
 use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
 
 pub fn get_perpetual_orders(
     deps: Deps<ElysQuery>,
@@ -9,62 +9,57 @@ pub fn get_perpetual_orders(
     order_type: Option<PerpetualOrderType>,
     order_status: Option<Status>,
 ) -> Result<GetPerpetualOrdersResp, ContractError> {
-    let orders: Vec<PerpetualOrderV2> = match order_owner {
-        Some(addr) => match USER_PERPETUAL_ORDER.may_load(deps.storage, &addr)? {
-            Some(v) => v
-                .iter()
-                .filter_map(|id| PERPETUAL_ORDER_V2.load(deps.storage, *id).ok())
-                .collect(), // Collect the filtered orders into a vector
-            None => vec![], // Provide an empty vector for the None case
-        },
-        None => PERPETUAL_ORDER_V2
-            .prefix_range(deps.storage, None, None, Order::Ascending)
-            .filter_map(|res| res.ok().map(|r| r.1))
-            .collect(),
-    };
-    if orders.is_empty() {
-        return Ok(GetPerpetualOrdersResp {
-            page_response: if let Some(page) = pagination {
-                Some(PageResponse::empty(page.count_total))
-            } else {
-                None
-            },
-            orders: vec![],
-        });
+    let owner_ids = match &order_owner {
+        Some(addr) => Some(
+            USER_PERPETUAL_ORDER
+                .may_load(deps.storage, addr)?
+                .unwrap_or_default(),
+        ),
+        None => None,
     };
 
-    let orders: Vec<PerpetualOrderPlus> = orders
-        .iter()
-        .filter(|order| {
-            order_type
+    let matches = |order: &PerpetualOrderV2| {
+        order_type
+            .as_ref()
+            .map_or(true, |order_type| order_type == &order.order_type)
+            && order_status
                 .as_ref()
-                .map_or(true, |order_type| order_type == &order.order_type)
-                && order_status
-                    .as_ref()
-                    .map_or(true, |status| &order.status == status)
-        })
-        .map(|order| PerpetualOrderPlus::new(order.to_owned()))
-        .collect::<Result<Vec<PerpetualOrderPlus>, StdError>>()?;
+                .map_or(true, |status| &order.status == status)
+    };
+
+    let range = |cursor: Option<u64>, order: Order| -> PageItems<u64, PerpetualOrderV2> {
+        let orders: PageItems<u64, PerpetualOrderV2> = match &owner_ids {
+            Some(ids) => Box::new(page_keys(ids.clone(), cursor, order).filter_map(|id| {
+                PERPETUAL_ORDER_V2
+                    .load(deps.storage, id)
+                    .ok()
+                    .map(|o| Ok((id, o)))
+            })),
+            None => {
+                let (min, max) = page_bounds(cursor.map(Bound::exclusive), order);
+                Box::new(PERPETUAL_ORDER_V2.range(deps.storage, min, max, order))
+            }
+        };
+        Box::new(orders.filter(move |res| res.as_ref().map_or(true, |(_, o)| matches(o))))
+    };
 
     let (orders, page_response) = match pagination {
         Some(pagination) => {
-            let (orders, page_resp) = pagination.filter(orders)?;
-            (orders, Some(page_resp))
+            let (orders, page_response) = pagination.paginate(range)?;
+            (orders, Some(page_response))
         }
-        None => (orders, None),
+        None => (
+            range(None, Order::Ascending)
+                .map(|res| res.map(|(_, order)| order))
+                .collect::<StdResult<Vec<PerpetualOrderV2>>>()?,
+            None,
+        ),
     };
 
-    let page_response = if let Some(page_response) = page_response {
-        match page_response.total {
-            Some(_) => Some(PageResponse {
-                next_key: page_response.next_key,
-                total: Some(orders.len() as u64),
-            }),
-            None => Some(page_response),
-        }
-    } else {
-        None
-    };
+    let orders = orders
+        .into_iter()
+        .map(PerpetualOrderPlus::new)
+        .collect::<StdResult<Vec<PerpetualOrderPlus>>>()?;
 
     Ok(GetPerpetualOrdersResp {
         page_response,
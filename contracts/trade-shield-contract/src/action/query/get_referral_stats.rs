@@ -0,0 +1,71 @@
+use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
+
+pub fn get_referral_stats(
+    deps: Deps<ElysQuery>,
+    address: String,
+) -> Result<GetReferralStatsResp, ContractError> {
+    let referrer = REFERRALS.may_load(deps.storage, &address)?;
+    let number_of_referees = REFEREES
+        .prefix(&address)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .count() as u64;
+    let referral_volume_usd = REFERRAL_VOLUME
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+
+    Ok(GetReferralStatsResp {
+        address,
+        referrer,
+        number_of_referees,
+        referral_volume_usd,
+    })
+}
+
+pub fn get_referees(
+    deps: Deps<ElysQuery>,
+    referrer: String,
+    pagination: Option<PageRequest>,
+) -> Result<GetRefereesResp, ContractError> {
+    let range = |cursor: Option<String>, order: Order| -> PageItems<String, Referee> {
+        let (min, max) = page_bounds(
+            cursor.map(|address| Bound::ExclusiveRaw(address.into_bytes())),
+            order,
+        );
+        Box::new(
+            REFEREES
+                .prefix(&referrer)
+                .range(deps.storage, min, max, order)
+                .map(|res| {
+                    res.map(|(address, volume_usd)| {
+                        (
+                            address.clone(),
+                            Referee {
+                                address,
+                                volume_usd,
+                            },
+                        )
+                    })
+                }),
+        )
+    };
+
+    let (referees, page_response) = match pagination {
+        Some(pagination) => {
+            let (referees, page_response) = pagination.paginate(range)?;
+            (referees, Some(page_response))
+        }
+        None => (
+            range(None, Order::Ascending)
+                .map(|res| res.map(|(_, referee)| referee))
+                .collect::<StdResult<Vec<Referee>>>()?,
+            None,
+        ),
+    };
+
+    Ok(GetRefereesResp {
+        referees,
+        page_response,
+    })
+}
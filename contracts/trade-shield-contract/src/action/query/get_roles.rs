@@ -0,0 +1,35 @@
+use std::str::FromStr;
+
+use super::*;
+use cosmwasm_std::{Empty, StdResult};
+
+pub fn get_roles(deps: Deps<ElysQuery>, role: Option<Role>) -> Result<GetRolesResp, ContractError> {
+    let params_admin = PARAMS_ADMIN.load(deps.storage)?;
+
+    let entries = match &role {
+        Some(role) => ROLES
+            .prefix(role.as_str())
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|entry| entry.map(|(address, _)| (role.as_str().to_string(), address)))
+            .collect::<StdResult<Vec<(String, String)>>>()?,
+        None => ROLES
+            .range(deps.storage, None, None, Order::Ascending)
+            .map(|entry| entry.map(|(key, _): ((String, String), Empty)| key))
+            .collect::<StdResult<Vec<(String, String)>>>()?,
+    };
+
+    let grants = entries
+        .into_iter()
+        .map(|(role, address)| {
+            Ok(RoleGrant {
+                role: Role::from_str(&role)?,
+                address,
+            })
+        })
+        .collect::<StdResult<Vec<RoleGrant>>>()?;
+
+    Ok(GetRolesResp {
+        params_admin,
+        grants,
+    })
+}
@@ -1,4 +1,6 @@
 use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
 
 pub fn get_spot_orders(
     deps: Deps<ElysQuery>,
@@ -7,62 +9,49 @@ pub fn get_spot_orders(
     order_type: Option<SpotOrderType>,
     order_status: Option<Status>,
 ) -> Result<GetSpotOrdersResp, ContractError> {
-    let orders: Vec<SpotOrder> = match order_owner {
-        Some(addr) => match USER_SPOT_ORDER.may_load(deps.storage, &addr)? {
-            Some(v) => v
-                .iter()
-                .filter_map(|id| SPOT_ORDER.load(deps.storage, *id).ok())
-                .collect(),
-            None => vec![],
-        },
-        None => SPOT_ORDER
-            .prefix_range(deps.storage, None, None, Order::Ascending)
-            .filter_map(|res| res.ok().map(|r| r.1))
-            .collect(),
+    let owner_ids = match &order_owner {
+        Some(addr) => Some(
+            USER_SPOT_ORDER
+                .may_load(deps.storage, addr)?
+                .unwrap_or_default(),
+        ),
+        None => None,
     };
 
-    if orders.is_empty() {
-        return Ok(GetSpotOrdersResp {
-            page_response: if let Some(page) = pagination {
-                Some(PageResponse::empty(page.count_total))
-            } else {
-                None
-            },
-            orders,
-        });
+    let matches = |order: &SpotOrder| {
+        order_type
+            .as_ref()
+            .map_or(true, |order_type| order_type == &order.order_type)
+            && order_status
+                .as_ref()
+                .map_or(true, |status| &order.status == status)
     };
 
-    let orders: Vec<SpotOrder> = orders
-        .iter()
-        .filter(|order| {
-            order_type
-                .as_ref()
-                .map_or(true, |order_type| order_type == &order.order_type)
-                && order_status
-                    .as_ref()
-                    .map_or(true, |status| &order.status == status)
-        })
-        .cloned()
-        .collect();
+    let range = |cursor: Option<u64>, order: Order| -> PageItems<u64, SpotOrder> {
+        let orders: PageItems<u64, SpotOrder> = match &owner_ids {
+            Some(ids) => Box::new(
+                page_keys(ids.clone(), cursor, order)
+                    .filter_map(|id| SPOT_ORDER.load(deps.storage, id).ok().map(|o| Ok((id, o)))),
+            ),
+            None => {
+                let (min, max) = page_bounds(cursor.map(Bound::exclusive), order);
+                Box::new(SPOT_ORDER.range(deps.storage, min, max, order))
+            }
+        };
+        Box::new(orders.filter(move |res| res.as_ref().map_or(true, |(_, o)| matches(o))))
+    };
 
     let (orders, page_response) = match pagination {
         Some(pagination) => {
-            let (orders, page_resp) = pagination.filter(orders)?;
-            (orders, Some(page_resp))
-        }
-        None => (orders, None),
-    };
-
-    let page_response = if let Some(page_response) = page_response {
-        match page_response.total {
-            Some(_) => Some(PageResponse {
-                next_key: page_response.next_key,
-                total: Some(orders.len() as u64),
-            }),
-            None => Some(page_response),
+            let (orders, page_response) = pagination.paginate(range)?;
+            (orders, Some(page_response))
         }
-    } else {
-        None
+        None => (
+            range(None, Order::Ascending)
+                .map(|res| res.map(|(_, order)| order))
+                .collect::<StdResult<Vec<SpotOrder>>>()?,
+            None,
+        ),
     };
 
     Ok(GetSpotOrdersResp {
@@ -0,0 +1,59 @@
+use super::*;
+use cosmwasm_std::StdResult;
+use cw_storage_plus::Bound;
+
+pub fn get_trading_stats(
+    deps: Deps<ElysQuery>,
+    scope: TradingStatsScope,
+    from_day: Option<u64>,
+    to_day: Option<u64>,
+) -> Result<GetTradingStatsResp, ContractError> {
+    let min = from_day.map(Bound::inclusive);
+    let max = to_day.map(Bound::inclusive);
+
+    let buckets: Vec<(u64, TradingStats)> = match &scope {
+        TradingStatsScope::Global => GLOBAL_TRADING_STATS
+            .range(deps.storage, min, max, Order::Ascending)
+            .collect::<StdResult<_>>()?,
+        TradingStatsScope::User { address } => USER_TRADING_STATS
+            .prefix(address)
+            .range(deps.storage, min, max, Order::Ascending)
+            .collect::<StdResult<_>>()?,
+        TradingStatsScope::Pair { pair } => PAIR_TRADING_STATS
+            .prefix(pair)
+            .range(deps.storage, min, max, Order::Ascending)
+            .collect::<StdResult<_>>()?,
+    };
+
+    let mut total = TradingStats::default();
+    for (_, stats) in buckets.iter() {
+        total.merge(stats)?;
+    }
+
+    Ok(GetTradingStatsResp {
+        total,
+        daily: buckets
+            .into_iter()
+            .map(|(day, stats)| DailyTradingStats { day, stats })
+            .collect(),
+    })
+}
+
+pub fn get_top_traders(
+    deps: Deps<ElysQuery>,
+    day: u64,
+    limit: u64,
+) -> Result<GetTopTradersResp, ContractError> {
+    let traders: Vec<TraderVolume> = DAILY_TOP_TRADERS
+        .may_load(deps.storage, day)?
+        .unwrap_or_default()
+        .into_iter()
+        .take(limit as usize)
+        .map(|(address, volume_usd)| TraderVolume {
+            address,
+            volume_usd,
+        })
+        .collect();
+
+    Ok(GetTopTradersResp { traders })
+}
@@ -0,0 +1,96 @@
+use super::*;
+use cosmwasm_std::{Decimal, SignedDecimal, StdError, StdResult};
+
+pub fn get_user_pool_positions(
+    deps: Deps<ElysQuery>,
+    user_address: String,
+) -> Result<GetUserPoolPositionsResp, ContractError> {
+    let positions: Vec<PoolPosition> = USER_POOL_POSITION
+        .prefix(&user_address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter_map(|res| res.ok().map(|r| r.1))
+        .collect();
+
+    if positions.is_empty() {
+        return Ok(GetUserPoolPositionsResp::empty());
+    }
+
+    let querier = ElysQuerier::new(&deps.querier);
+
+    let pool_ids: Vec<u64> = positions.iter().map(|p| p.pool_id).collect();
+    let pools = querier
+        .get_all_pools(Some(pool_ids), PoolFilterType::FilterAll as i32, None)?
+        .pools
+        .unwrap_or_default();
+
+    let pending_rewards = querier.get_masterchef_pending_rewards(user_address)?;
+
+    let mut resp_positions: Vec<UserPoolPosition> = vec![];
+
+    for position in positions {
+        let share_usd_price = pools
+            .iter()
+            .find(|pool| pool.pool_id as u64 == position.pool_id)
+            .and_then(|pool| pool.share_usd_price)
+            .unwrap_or_default();
+
+        // pool shares are expressed with 18 decimals
+        let current_usd_value = Decimal::from_atomics(position.shares, 18)
+            .map_err(|e| StdError::generic_err(format!("failed to convert shares: {}", e)))?
+            .checked_mul(share_usd_price)
+            .map_err(StdError::from)?;
+
+        let hold_usd_value = position
+            .token_in
+            .iter()
+            .map(|coin| CoinValue::from_coin(coin, &querier).map(|value| value.amount_usd))
+            .collect::<StdResult<Vec<Decimal>>>()?
+            .into_iter()
+            .try_fold(Decimal::zero(), |acc, value| acc.checked_add(value))
+            .map_err(StdError::from)?;
+
+        let rewards: Vec<CoinValue> = pending_rewards
+            .rewards
+            .iter()
+            .filter(|reward| reward.pool_id == position.pool_id)
+            .flat_map(|reward| reward.reward.iter())
+            .map(|coin| CoinValue::from_coin(coin, &querier))
+            .collect::<StdResult<Vec<CoinValue>>>()?;
+
+        let rewards_usd_value = rewards
+            .iter()
+            .try_fold(Decimal::zero(), |acc, value| {
+                acc.checked_add(value.amount_usd)
+            })
+            .map_err(StdError::from)?;
+
+        let current = SignedDecimal::try_from(current_usd_value)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+        let hold = SignedDecimal::try_from(hold_usd_value)
+            .map_err(|e| StdError::generic_err(e.to_string()))?;
+
+        let impermanent_loss = current.checked_sub(hold).map_err(StdError::from)?;
+
+        let impermanent_loss_ratio = if hold.is_zero() {
+            SignedDecimal::zero()
+        } else {
+            impermanent_loss
+                .checked_div(hold)
+                .map_err(|e| StdError::generic_err(e.to_string()))?
+        };
+
+        resp_positions.push(UserPoolPosition {
+            position,
+            current_usd_value,
+            hold_usd_value,
+            rewards,
+            rewards_usd_value,
+            impermanent_loss,
+            impermanent_loss_ratio,
+        });
+    }
+
+    Ok(GetUserPoolPositionsResp {
+        positions: resp_positions,
+    })
+}
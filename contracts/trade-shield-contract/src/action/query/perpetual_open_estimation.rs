@@ -12,11 +12,14 @@ pub fn perpetual_open_estimation(
     collateral: Coin,
     take_profit_price: Option<SignedDecimal256>,
     user_address: Option<String>, // Parameter unused until account history work
+    env: Env,
 ) -> StdResult<PerpetualOpenEstimationResponse> {
     let querier = ElysQuerier::new(&deps.querier);
 
     let discount = match user_address {
-        Some(user_address) => get_discount(deps.querier, user_address)?,
+        Some(user_address) => {
+            get_discount(deps.storage, deps.querier, env.block.time, user_address)?
+        }
         None => Decimal::zero(),
     };
 
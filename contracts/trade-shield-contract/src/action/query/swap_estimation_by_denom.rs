@@ -1,4 +1,4 @@
-use cosmwasm_std::{Coin, Decimal, Deps, StdResult};
+use cosmwasm_std::{Coin, Decimal, Deps, Env, StdResult};
 use elys_bindings::{query_resp::AmmSwapEstimationByDenomResponse, ElysQuerier, ElysQuery};
 
 use crate::helper::get_discount;
@@ -9,11 +9,14 @@ pub fn swap_estimation_by_denom(
     denom_in: String,
     denom_out: String,
     user_address: Option<String>, // Parameter unused until account history work
+    env: Env,
 ) -> StdResult<AmmSwapEstimationByDenomResponse> {
     let querier = ElysQuerier::new(&deps.querier);
 
     let discount = match user_address {
-        Some(user_address) => get_discount(deps.querier, user_address)?,
+        Some(user_address) => {
+            get_discount(deps.storage, deps.querier, env.block.time, user_address)?
+        }
         None => Decimal::zero(),
     };
 
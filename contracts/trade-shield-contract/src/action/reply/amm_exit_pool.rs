@@ -0,0 +1,48 @@
+use super::*;
+use cosmwasm_std::{from_json, Binary, StdError, SubMsgResult, Uint128};
+
+pub fn reply_to_amm_exit_pool(
+    deps: DepsMut<ElysQuery>,
+    data: Option<Binary>,
+    module_resp: SubMsgResult,
+) -> Result<Response<ElysMsg>, ContractError> {
+    let response = module_resp
+        .into_result()
+        .map_err(|err| StdError::generic_err(format!("{}: {}: {}", file!(), line!(), err)))?;
+
+    let (owner, pool_id, share_amount_in): (String, u64, Uint128) = match data {
+        Some(data) => from_json(data)?,
+        None => {
+            return Err(
+                StdError::generic_err(format!("{}: {}: no meta_data", file!(), line!())).into(),
+            )
+        }
+    };
+
+    let token_out = match response.data {
+        Some(data) => from_json::<MsgExitPoolResponse>(data)?.token_out,
+        None => vec![],
+    };
+    let resp: Response<ElysMsg> = Response::new().add_event(events::pool_exited(
+        &owner,
+        pool_id,
+        share_amount_in,
+        &token_out,
+    ));
+
+    // positions opened before the tracking was introduced are not recorded
+    let mut position = match USER_POOL_POSITION.may_load(deps.storage, (&owner, pool_id))? {
+        Some(position) => position,
+        None => return Ok(resp),
+    };
+
+    position.exit(share_amount_in)?;
+
+    if position.shares.is_zero() {
+        USER_POOL_POSITION.remove(deps.storage, (&owner, pool_id));
+    } else {
+        USER_POOL_POSITION.save(deps.storage, (&owner, pool_id), &position)?;
+    }
+
+    Ok(resp)
+}
@@ -0,0 +1,51 @@
+use super::*;
+use cosmwasm_std::{from_json, Binary, Coin, StdError, StdResult, SubMsgResult, Uint128};
+
+pub fn reply_to_amm_join_pool(
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    data: Option<Binary>,
+    module_resp: SubMsgResult,
+) -> Result<Response<ElysMsg>, ContractError> {
+    let response = module_resp
+        .into_result()
+        .map_err(|err| StdError::generic_err(format!("{}: {}: {}", file!(), line!(), err)))?;
+
+    let (owner, pool_id): (String, u64) = match data {
+        Some(data) => from_json(data)?,
+        None => {
+            return Err(
+                StdError::generic_err(format!("{}: {}: no meta_data", file!(), line!())).into(),
+            )
+        }
+    };
+
+    let res: MsgJoinPoolResponse = match response.data {
+        Some(data) => from_json(data)?,
+        None => {
+            return Err(StdError::generic_err(format!("{}: {}: no data", file!(), line!())).into())
+        }
+    };
+
+    let shares = Uint128::new(res.share_amount_out.i128().unsigned_abs());
+
+    let querier = ElysQuerier::new(&deps.querier);
+    let token_in_value = res
+        .token_in
+        .iter()
+        .map(|coin| Ok((coin.clone(), CoinValue::from_coin(coin, &querier)?)))
+        .collect::<StdResult<Vec<(Coin, CoinValue)>>>()?;
+
+    let mut position = USER_POOL_POSITION
+        .may_load(deps.storage, (&owner, pool_id))?
+        .unwrap_or(PoolPosition::new(&owner, pool_id, Date::from(&env.block)));
+
+    position.join(shares, token_in_value)?;
+
+    USER_POOL_POSITION.save(deps.storage, (&owner, pool_id), &position)?;
+
+    let resp: Response<ElysMsg> =
+        Response::new().add_event(events::pool_joined(owner, pool_id, shares, &res.token_in));
+
+    Ok(resp)
+}
@@ -0,0 +1,33 @@
+use cosmwasm_std::{from_json, Binary, SubMsgResult};
+
+use super::*;
+
+pub fn reply_to_auto_compound_run(
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    data: Option<Binary>,
+    module_resp: SubMsgResult,
+) -> Result<Response<ElysMsg>, ContractError> {
+    // a successful run already recorded itself
+    let reason = match module_resp.into_result() {
+        Ok(_) => return Ok(Response::new()),
+        Err(reason) => reason,
+    };
+
+    let owner: String = from_json(data.unwrap())?;
+    let mut subscription = match AUTO_COMPOUNDS.may_load(deps.storage, &owner)? {
+        Some(subscription) => subscription,
+        None => return Ok(Response::new()),
+    };
+
+    // the next run is still pushed back, a failing run is not retried every block
+    subscription.record(CompoundRun {
+        time: env.block.time,
+        claimed: vec![],
+        compounded: vec![],
+        error: Some(reason.clone()),
+    });
+    AUTO_COMPOUNDS.save(deps.storage, &owner, &subscription)?;
+
+    Ok(Response::new().add_event(events::auto_compound_failed(owner, reason)))
+}
@@ -0,0 +1,41 @@
+use crate::action::execute::run_batch;
+use crate::helper::get_response_from_reply;
+use cosmwasm_std::{from_json, Binary, Coin, StdError, SubMsgResult, Uint128};
+
+use super::*;
+
+/// Records the output of the step waiting for its reply and resumes the batch.
+pub fn reply_to_batch_step(
+    deps: DepsMut<ElysQuery>,
+    env: Env,
+    data: Option<Binary>,
+    module_resp: SubMsgResult,
+) -> Result<Response<ElysMsg>, ContractError> {
+    let (denom_out, balance_before): (String, Option<Uint128>) = from_json(data.unwrap())?;
+    let mut batch = BATCH.load(deps.storage)?;
+    let step = batch.outputs.len();
+
+    let amount = match &batch.actions[step] {
+        BatchAction::Swap { .. } => {
+            let resp: AmmSwapExactAmountInResp = get_response_from_reply(module_resp)
+                .map_err(|err| StdError::generic_err(format!("step {}: {}", step, err)))?;
+            u128::try_from(resp.token_out_amount.i64())
+                .map_err(|_| StdError::generic_err(format!("step {}: negative amount out", step)))?
+        }
+        BatchAction::ClosePerpetualPosition { .. } => {
+            let _: PerpetualCloseResponse = get_response_from_reply(module_resp)
+                .map_err(|err| StdError::generic_err(format!("step {}: {}", step, err)))?;
+            let balance = deps
+                .querier
+                .query_balance(batch.owner.as_str(), &denom_out)?
+                .amount;
+            balance
+                .saturating_sub(balance_before.unwrap_or_default())
+                .u128()
+        }
+        _ => return Err(StdError::generic_err(format!("step {}: no reply expected", step)).into()),
+    };
+    batch.outputs.push(vec![Coin::new(amount, denom_out)]);
+
+    run_batch(deps, &env, batch, Response::new())
+}
@@ -1,11 +1,14 @@
-use cosmwasm_std::{from_json, Binary, SubMsgResult};
+use cosmwasm_std::{from_json, Binary, Env, SubMsgResult};
 
-use crate::helper::get_response_from_reply;
+use crate::helper::{
+    get_response_from_reply, record_executed_perpetual_order, record_failed_order,
+};
 
 use super::*;
 
 pub fn reply_to_close_perpetual_order(
     deps: DepsMut<ElysQuery>,
+    env: Env,
     data: Option<Binary>,
     module_resp: SubMsgResult,
 ) -> Result<Response<ElysMsg>, ContractError> {
@@ -25,11 +28,12 @@ pub fn reply_to_close_perpetual_order(
     }
     let res: PerpetualCloseResponse = match get_response_from_reply(module_resp) {
         Ok(expr) => expr,
-        Err(err) => {
+        Err(reason) => {
             order.status = Status::Canceled;
             PERPETUAL_ORDER_V2.save(deps.storage, order_id, &order)?;
             PENDING_PERPETUAL_ORDER_V2.remove(deps.storage, order.order_id);
-            return Ok(err);
+            record_failed_order(deps.storage, env.block.time, (&order).into())?;
+            return Ok(Response::new().add_event(events::order_failed(&order, reason)));
         }
     };
 
@@ -37,12 +41,13 @@ pub fn reply_to_close_perpetual_order(
 
     PENDING_PERPETUAL_ORDER_V2.remove(deps.storage, order.order_id);
     PERPETUAL_ORDER_V2.save(deps.storage, order_id, &order)?;
+    let querier = ElysQuerier::new(&deps.querier);
+    record_executed_perpetual_order(deps.storage, &querier, env.block.time, &order)?;
 
     let resp: Response<ElysMsg> = Response::new().add_event(
-        Event::new("reply_to_close_perpetual_order")
-            .add_attribute("perpetual_order_id", order_id.to_string())
-            .add_attribute("perpetual_trading_position_closed_id", res.id.to_string())
-            .add_attribute("perpetual_amount_closed", res.amount.i128().to_string()),
+        events::order_executed(&order)
+            .add_attribute("position_id", res.id.to_string())
+            .add_attribute("closed_amount", res.amount.i128().to_string()),
     );
 
     Ok(resp)
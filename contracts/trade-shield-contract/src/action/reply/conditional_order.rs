@@ -0,0 +1,32 @@
+use cosmwasm_std::{from_json, Binary, SubMsgResult};
+
+use super::*;
+
+pub fn reply_to_conditional_order(
+    deps: DepsMut<ElysQuery>,
+    data: Option<Binary>,
+    module_resp: SubMsgResult,
+) -> Result<Response<ElysMsg>, ContractError> {
+    // an executed order already emitted its events with the wrapped order
+    let reason = match module_resp.into_result() {
+        Ok(_) => return Ok(Response::new()),
+        Err(reason) => reason,
+    };
+
+    let order_id: u64 = from_json(data.unwrap())?;
+    let mut order = CONDITIONAL_ORDERS.load(deps.storage, order_id)?;
+
+    // the execution was reverted, the order is cancelled rather than retried every block
+    order.status = Status::Canceled;
+    CONDITIONAL_ORDERS.save(deps.storage, order_id, &order)?;
+
+    let mut resp = Response::new().add_event(events::conditional_order_failed(&order, reason));
+    if !order.escrow.is_empty() {
+        resp = resp.add_message(BankMsg::Send {
+            to_address: order.owner.to_string(),
+            amount: order.escrow,
+        });
+    }
+
+    Ok(resp)
+}
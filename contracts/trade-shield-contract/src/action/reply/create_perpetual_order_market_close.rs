@@ -1,8 +1,10 @@
 use super::*;
-use cosmwasm_std::{from_json, Binary, StdError, SubMsgResult};
+use crate::helper::record_executed_perpetual_order;
+use cosmwasm_std::{from_json, Binary, Env, StdError, SubMsgResult};
 
 pub fn reply_to_create_perpetual_market_close(
     deps: DepsMut<ElysQuery>,
+    env: Env,
     data: Option<Binary>,
     module_resp: SubMsgResult,
 ) -> Result<Response<ElysMsg>, ContractError> {
@@ -33,19 +35,14 @@ pub fn reply_to_create_perpetual_market_close(
     order.status = Status::Executed;
 
     let resp = Response::new().add_event(
-        Event::new("reply_to_create_perpetual_market_close")
-            .add_attribute("perpetual_order_id", order_id.to_string())
-            .add_attribute(
-                "perpetual_trading_position_closed_id",
-                perpetual_resp.id.to_string(),
-            )
-            .add_attribute(
-                "perpetual_amount_closed",
-                perpetual_resp.amount.i128().to_string(),
-            ),
+        events::order_executed(&order)
+            .add_attribute("position_id", perpetual_resp.id.to_string())
+            .add_attribute("closed_amount", perpetual_resp.amount.i128().to_string()),
     );
 
     PERPETUAL_ORDER_V2.save(deps.storage, order_id, &order)?;
+    let querier = ElysQuerier::new(&deps.querier);
+    record_executed_perpetual_order(deps.storage, &querier, env.block.time, &order)?;
 
     Ok(resp)
 }
@@ -1,9 +1,12 @@
 use super::*;
-use cosmwasm_std::{from_json, Binary, StdError, SubMsgResult};
+use crate::helper::{collect_fee, create_stop_loss_order, record_executed_perpetual_order};
+use cosmwasm_std::{from_json, Binary, Coin, Env, StdError, SubMsgResult};
 
 pub fn reply_to_create_perpetual_market_open(
     deps: DepsMut<ElysQuery>,
+    env: Env,
     data: Option<Binary>,
+    fee: Option<Coin>,
     module_resp: SubMsgResult,
 ) -> Result<Response<ElysMsg>, ContractError> {
     let resp_data = match module_resp.into_result() {
@@ -34,15 +37,25 @@ pub fn reply_to_create_perpetual_market_open(
     order.position_id = Some(perpetual_resp.id);
 
     PERPETUAL_ORDER_V2.save(deps.storage, order_id, &order)?;
+    let querier = ElysQuerier::new(&deps.querier);
+    record_executed_perpetual_order(deps.storage, &querier, env.block.time, &order)?;
+
+    let mut executed =
+        events::order_executed(&order).add_attribute("position_id", perpetual_resp.id.to_string());
+    let mut resp = Response::new();
+    if let Some(fee) = fee {
+        if let Some(bank_msg) = collect_fee(deps.storage, &order.owner, &fee)? {
+            resp = resp.add_message(bank_msg);
+        }
+        executed = executed.add_attribute(events::FEE_KEY, fee.to_string());
+    }
+    resp = resp.add_event(executed);
 
-    let resp = Response::new().add_event(
-        Event::new("reply_to_create_perpetual_market_open")
-            .add_attribute("perpetual_order_id", order_id.to_string())
-            .add_attribute(
-                "perpetual_trading_position_opened_id",
-                perpetual_resp.id.to_string(),
-            ),
-    );
+    if let Some(stop_loss_order) =
+        create_stop_loss_order(deps.storage, order_id, perpetual_resp.id)?
+    {
+        resp = resp.add_event(events::order_created(&stop_loss_order));
+    }
 
     Ok(resp)
 }
@@ -0,0 +1,38 @@
+use cosmwasm_std::{from_json, Binary, Coin, StdError, SubMsgResult};
+
+use super::*;
+
+pub fn reply_to_liquidation_guard_top_up(
+    deps: DepsMut<ElysQuery>,
+    data: Option<Binary>,
+    module_resp: SubMsgResult,
+) -> Result<Response<ElysMsg>, ContractError> {
+    let (owner, position_id, top_up): (String, u64, Coin) = from_json(data.unwrap())?;
+    let key = (owner.as_str(), position_id);
+
+    let mut guard = match LIQUIDATION_GUARDS.may_load(deps.storage, key)? {
+        Some(guard) => guard,
+        None => return Ok(Response::new()),
+    };
+
+    if let Err(reason) = module_resp.into_result() {
+        return Ok(
+            Response::new().add_event(events::liquidation_guard_failed(&guard, &top_up, reason))
+        );
+    }
+
+    guard.escrow.amount = guard
+        .escrow
+        .amount
+        .checked_sub(top_up.amount)
+        .map_err(StdError::from)?;
+    let event = events::liquidation_guard_triggered(&guard, &top_up);
+
+    if guard.escrow.amount.is_zero() {
+        LIQUIDATION_GUARDS.remove(deps.storage, key);
+    } else {
+        LIQUIDATION_GUARDS.save(deps.storage, key, &guard)?;
+    }
+
+    Ok(Response::new().add_event(event))
+}
@@ -1,32 +1,65 @@
-use cosmwasm_std::{from_json, Binary, SubMsgResult};
+use cosmwasm_std::{from_json, Binary, Coin, Env, SubMsgResult};
 
-use crate::helper::{get_response_from_reply, remove_perpetual_order};
+use crate::helper::{
+    collect_fee, create_stop_loss_order, get_response_from_reply, record_executed_perpetual_order,
+    record_failed_order, remove_perpetual_order,
+};
 
 use super::*;
 
 pub fn reply_to_open_perpetual_position(
     deps: DepsMut<ElysQuery>,
+    env: Env,
     data: Option<Binary>,
+    fee: Option<Coin>,
     module_resp: SubMsgResult,
 ) -> Result<Response<ElysMsg>, ContractError> {
     let order_id: u64 = from_json(&data.unwrap()).unwrap();
 
     let res: PerpetualOpenResponse = match get_response_from_reply(module_resp) {
         Ok(expr) => expr,
-        Err(err) => {
-            let bank_msg =
-                remove_perpetual_order(order_id, Status::Canceled, deps.storage, None)?.unwrap();
-            return Ok(err.add_message(bank_msg));
+        Err(reason) => {
+            let bank_msg = remove_perpetual_order(
+                order_id,
+                Status::Canceled,
+                deps.storage,
+                env.block.time,
+                None,
+            )?
+            .unwrap();
+            let order = PERPETUAL_ORDER_V2.load(deps.storage, order_id)?;
+            record_failed_order(deps.storage, env.block.time, (&order).into())?;
+            return Ok(Response::new()
+                .add_message(bank_msg)
+                .add_event(events::order_failed(&order, reason)));
         }
     };
 
-    remove_perpetual_order(order_id, Status::Executed, deps.storage, Some(res.id))?;
+    remove_perpetual_order(
+        order_id,
+        Status::Executed,
+        deps.storage,
+        env.block.time,
+        Some(res.id),
+    )?;
+    let order = PERPETUAL_ORDER_V2.load(deps.storage, order_id)?;
+    let querier = ElysQuerier::new(&deps.querier);
+    record_executed_perpetual_order(deps.storage, &querier, env.block.time, &order)?;
 
-    let resp: Response<ElysMsg> = Response::new().add_event(
-        Event::new("reply_to_open_perpetual_position")
-            .add_attribute("perpetual_order_id", order_id.to_string())
-            .add_attribute("perpetual_trading_position_opened_id", res.id.to_string()),
-    );
+    let mut executed =
+        events::order_executed(&order).add_attribute("position_id", res.id.to_string());
+    let mut resp: Response<ElysMsg> = Response::new();
+    if let Some(fee) = fee {
+        if let Some(bank_msg) = collect_fee(deps.storage, &order.owner, &fee)? {
+            resp = resp.add_message(bank_msg);
+        }
+        executed = executed.add_attribute(events::FEE_KEY, fee.to_string());
+    }
+    resp = resp.add_event(executed);
+
+    if let Some(stop_loss_order) = create_stop_loss_order(deps.storage, order_id, res.id)? {
+        resp = resp.add_event(events::order_created(&stop_loss_order));
+    }
 
     Ok(resp)
 }
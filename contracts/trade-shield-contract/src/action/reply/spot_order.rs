@@ -1,29 +1,129 @@
-use cosmwasm_std::{from_json, Binary, DepsMut, SubMsgResult};
+use cosmwasm_std::{
+    coin, from_json, Binary, Coin, DepsMut, Env, Event, StdError, StdResult, Storage, SubMsgResult,
+};
 
-use crate::helper::{get_response_from_reply, remove_spot_order};
+use crate::helper::{
+    amount_after_fee, collect_fee, get_response_from_reply, insert_pending_spot_order,
+    next_spot_order_id, record_executed_order, record_failed_order, remove_spot_order,
+    swap_slippage,
+};
 
 use super::*;
 
 pub fn reply_to_spot_order(
     deps: DepsMut<ElysQuery>,
+    env: Env,
     data: Option<Binary>,
+    fee: Option<Coin>,
     module_resp: SubMsgResult,
 ) -> Result<Response<ElysMsg>, ContractError> {
     let order_id: u64 = from_json(&data.unwrap()).unwrap();
 
-    let _: AmmSwapExactAmountInResp = match get_response_from_reply(module_resp) {
+    let swap_resp: AmmSwapExactAmountInResp = match get_response_from_reply(module_resp) {
         Ok(expr) => expr,
-        Err(err) => {
-            let bank_msg = remove_spot_order(order_id, Status::Canceled, deps.storage)?;
-            return Ok(err.add_message(bank_msg.unwrap()));
+        Err(reason) => {
+            let bank_msg =
+                remove_spot_order(order_id, Status::Canceled, deps.storage, env.block.time)?;
+            let order = SPOT_ORDER.load(deps.storage, order_id)?;
+            record_failed_order(deps.storage, env.block.time, (&order).into())?;
+            return Ok(Response::new()
+                .add_message(bank_msg.unwrap())
+                .add_event(events::order_failed(&order, reason)));
         }
     };
 
-    remove_spot_order(order_id, Status::Executed, deps.storage)?;
-
-    let resp: Response<ElysMsg> = Response::new().add_event(
-        Event::new("reply_to_spot_order").add_attribute("order_id", order_id.to_string()),
+    let grid_level = GRID_ORDERS.may_load(deps.storage, order_id)?;
+    remove_spot_order(order_id, Status::Executed, deps.storage, env.block.time)?;
+    let order = SPOT_ORDER.load(deps.storage, order_id)?;
+    let querier = ElysQuerier::new(&deps.querier);
+    let token_out_amount = u128::try_from(swap_resp.token_out_amount.i64())
+        .map_err(|e| StdError::generic_err(format!("token_out_amount: {}", e)))?;
+    let token_out = coin(token_out_amount, &order.order_target_denom);
+    let slippage = swap_slippage(
+        &querier,
+        &amount_after_fee(&order.order_amount, &fee),
+        &token_out,
     );
+    record_executed_order(
+        deps.storage,
+        &querier,
+        env.block.time,
+        (&order).into(),
+        slippage,
+    )?;
+
+    let mut executed = events::order_executed(&order);
+    let mut resp: Response<ElysMsg> = Response::new();
+    if let Some(fee) = fee {
+        if let Some(bank_msg) = collect_fee(deps.storage, order.owner_address.as_str(), &fee)? {
+            resp = resp.add_message(bank_msg);
+        }
+        executed = executed.add_attribute(events::FEE_KEY, fee.to_string());
+    }
+    resp = resp.add_event(executed);
+
+    if let Some(grid_level) = grid_level {
+        if let Some(event) = fill_grid_level(deps.storage, &env, grid_level, &order, token_out)? {
+            resp = resp.add_event(event);
+        }
+    }
 
     Ok(resp)
 }
+
+/// Places the opposite order one level away from the filled one, a buy is
+/// sold one level up and a sell is bought back one level down. Proceeds that
+/// fall outside of the grid are kept idle until it is closed.
+fn fill_grid_level(
+    storage: &mut dyn Storage,
+    env: &Env,
+    grid_level: GridLevel,
+    order: &SpotOrder,
+    token_out: Coin,
+) -> StdResult<Option<Event>> {
+    let mut grid = GRIDS.load(storage, grid_level.grid_id)?;
+    grid.filled_orders += 1;
+
+    let (next_level, order_type, cost) = if order.order_type == SpotOrderType::LimitBuy {
+        (
+            grid_level.level.checked_add(1),
+            SpotOrderType::LimitSell,
+            Some(order.order_amount.amount),
+        )
+    } else {
+        if let Some(cost) = grid_level.cost {
+            grid.realised_profit += token_out.amount.saturating_sub(cost);
+        }
+        (
+            grid_level.level.checked_sub(1),
+            SpotOrderType::LimitBuy,
+            None,
+        )
+    };
+
+    let event = match next_level.filter(|level| (*level as usize) < grid.prices.len()) {
+        Some(level) if grid.active && !token_out.amount.is_zero() => {
+            let order_id = next_spot_order_id(storage)?;
+            let next_order = grid.order(order_id, level, order_type, token_out, &env.block);
+            insert_pending_spot_order(storage, &next_order)?;
+            GRID_ORDERS.save(
+                storage,
+                order_id,
+                &GridLevel {
+                    grid_id: grid.grid_id,
+                    level,
+                    cost,
+                },
+            )?;
+            grid.order_ids.push(order_id);
+            Some(events::order_created(&next_order))
+        }
+        _ => {
+            grid.add_idle(token_out);
+            None
+        }
+    };
+
+    GRIDS.save(storage, grid.grid_id, &grid)?;
+    Ok(event)
+}
@@ -1,13 +1,19 @@
 use super::*;
-use cosmwasm_std::{from_json, Binary, DepsMut, StdError, SubMsgResult};
+use crate::helper::{amount_after_fee, collect_fee, record_executed_order, swap_slippage};
+use cosmwasm_std::{coin, from_json, Binary, Coin, DepsMut, Env, StdError, SubMsgResult};
 
 pub fn reply_to_spot_order_market(
     deps: DepsMut<ElysQuery>,
+    env: Env,
     data: Option<Binary>,
+    fee: Option<Coin>,
     module_resp: SubMsgResult,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    if let Err(err) = module_resp.into_result() {
-        return Err(StdError::generic_err(format!("{}: {}: {}", file!(), line!(), err)).into());
+    let swap_resp: Option<AmmSwapExactAmountInResp> = match module_resp.into_result() {
+        Ok(resp) => resp.data.and_then(|data| from_json(data).ok()),
+        Err(err) => {
+            return Err(StdError::generic_err(format!("{}: {}: {}", file!(), line!(), err)).into())
+        }
     };
 
     let order_id: u64 = match data {
@@ -30,9 +36,34 @@ pub fn reply_to_spot_order_market(
         .save(deps.storage, order_id, &order)
         .map_err(|e| StdError::generic_err(format!("{}: {}: {}", file!(), line!(), e)))?;
 
-    let resp: Response<ElysMsg> = Response::new().add_event(
-        Event::new("reply_to_spot_order_market").add_attribute("order_id", order_id.to_string()),
-    );
+    let querier = ElysQuerier::new(&deps.querier);
+    let token_out_amount = swap_resp
+        .map(|swap_resp| u128::try_from(swap_resp.token_out_amount.i64()))
+        .transpose()
+        .map_err(|e| StdError::generic_err(format!("token_out_amount: {}", e)))?;
+    let slippage = token_out_amount.and_then(|token_out_amount| {
+        swap_slippage(
+            &querier,
+            &amount_after_fee(&order.order_amount, &fee),
+            &coin(token_out_amount, &order.order_target_denom),
+        )
+    });
+    record_executed_order(
+        deps.storage,
+        &querier,
+        env.block.time,
+        (&order).into(),
+        slippage,
+    )?;
+
+    let mut executed = events::order_executed(&order);
+    let mut resp: Response<ElysMsg> = Response::new();
+    if let Some(fee) = fee {
+        if let Some(bank_msg) = collect_fee(deps.storage, order.owner_address.as_str(), &fee)? {
+            resp = resp.add_message(bank_msg);
+        }
+        executed = executed.add_attribute(events::FEE_KEY, fee.to_string());
+    }
 
-    Ok(resp)
+    Ok(resp.add_event(executed))
 }
@@ -0,0 +1,92 @@
+use crate::msg::{ExecuteMsg, ReplyType};
+use cosmwasm_std::{
+    to_json_binary, OverflowError, StdError, StdResult, Storage, SubMsg, Timestamp, WasmMsg,
+};
+use cw_storage_plus::Bound;
+
+use super::*;
+
+pub const MAX_AUTO_COMPOUNDS_PER_BLOCK: usize = 10;
+
+/// Runs the auto-compound subscriptions that are due among the next
+/// MAX_AUTO_COMPOUNDS_PER_BLOCK visited. Every run is executed by the contract
+/// itself so that a failing run is reverted on its own and recorded in the
+/// reply instead of failing the whole block.
+pub fn process_auto_compounds(
+    storage: &mut dyn Storage,
+    contract: &str,
+    now: Timestamp,
+    reply_info_id: &mut u64,
+    submsgs: &mut Vec<SubMsg<ElysMsg>>,
+) -> StdResult<()> {
+    let owners: Vec<String> = next_auto_compounds(storage)?
+        .into_iter()
+        .filter(|(_, subscription)| subscription.is_due(now))
+        .map(|(owner, _)| owner)
+        .collect();
+
+    for owner in owners {
+        *reply_info_id = match reply_info_id.checked_add(1) {
+            Some(id) => id,
+            None => {
+                return Err(StdError::overflow(OverflowError::new(
+                    cosmwasm_std::OverflowOperation::Add,
+                    "reply_info_max_id",
+                    "increment one",
+                )))
+            }
+        };
+
+        let reply_info = ReplyInfo {
+            id: *reply_info_id,
+            reply_type: ReplyType::AutoCompoundRun,
+            data: Some(to_json_binary(&owner)?),
+            fee: None,
+        };
+        REPLY_INFO.save(storage, *reply_info_id, &reply_info)?;
+
+        let msg = WasmMsg::Execute {
+            contract_addr: contract.to_string(),
+            msg: to_json_binary(&ExecuteMsg::RunAutoCompound { owner })?,
+            funds: vec![],
+        };
+        submsgs.push(SubMsg::reply_always(msg, *reply_info_id));
+    }
+
+    Ok(())
+}
+
+/// Visits at most MAX_AUTO_COMPOUNDS_PER_BLOCK subscriptions per block, starting
+/// after the last one visited and wrapping around to the first subscription.
+fn next_auto_compounds(storage: &mut dyn Storage) -> StdResult<Vec<(String, AutoCompound)>> {
+    let cursor = AUTO_COMPOUND_CURSOR.may_load(storage)?;
+
+    let mut subscriptions: Vec<(String, AutoCompound)> = AUTO_COMPOUNDS
+        .range(
+            storage,
+            cursor.as_deref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(MAX_AUTO_COMPOUNDS_PER_BLOCK)
+        .collect::<StdResult<_>>()?;
+    if subscriptions.len() < MAX_AUTO_COMPOUNDS_PER_BLOCK {
+        let wrapped: Vec<(String, AutoCompound)> = AUTO_COMPOUNDS
+            .range(
+                storage,
+                None,
+                cursor.as_deref().map(Bound::inclusive),
+                Order::Ascending,
+            )
+            .take(MAX_AUTO_COMPOUNDS_PER_BLOCK - subscriptions.len())
+            .collect::<StdResult<_>>()?;
+        subscriptions.extend(wrapped);
+    }
+
+    match subscriptions.last() {
+        Some((owner, _)) => AUTO_COMPOUND_CURSOR.save(storage, owner)?,
+        None => AUTO_COMPOUND_CURSOR.remove(storage),
+    }
+
+    Ok(subscriptions)
+}
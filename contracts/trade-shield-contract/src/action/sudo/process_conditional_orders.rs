@@ -0,0 +1,98 @@
+use crate::helper::get_trigger_market_price;
+use crate::msg::{ExecuteMsg, ReplyType};
+use cosmwasm_std::{to_json_binary, OverflowError, StdError, StdResult, Storage, SubMsg, WasmMsg};
+
+use super::*;
+
+/// Executes the conditional orders whose price condition is met, at most
+/// `n_orders` of them. Every order is executed by the contract itself so that
+/// a failing order is reverted on its own and refunded in the reply.
+pub fn process_conditional_orders(
+    storage: &mut dyn Storage,
+    querier: &ElysQuerier<'_>,
+    contract: &str,
+    n_orders: &mut Option<u128>,
+    reply_info_id: &mut u64,
+    submsgs: &mut Vec<SubMsg<ElysMsg>>,
+) -> StdResult<()> {
+    let sorted_orders: Vec<(String, Vec<u64>)> = SORTED_CONDITIONAL_ORDERS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(String, Vec<u64>)>>>()?;
+
+    for (key, mut order_ids) in sorted_orders {
+        if *n_orders == Some(0) {
+            break;
+        }
+
+        let (base_denom, quote_denom, direction) = ConditionalOrder::from_key(&key)?;
+
+        // the orders stay pending while the pair has no price, their owners can cancel them
+        let market_price = match get_trigger_market_price(querier, &base_denom, &quote_denom) {
+            Ok(market_price) => market_price,
+            Err(_) => continue,
+        };
+
+        // the ids are sorted by ascending rate, so the met conditions are a
+        // prefix of them when waiting for a rise and a suffix for a drop
+        let candidates: Box<dyn Iterator<Item = &u64>> = match direction {
+            PriceDirection::Above => Box::new(order_ids.iter()),
+            PriceDirection::Below => Box::new(order_ids.iter().rev()),
+        };
+        let mut triggered: Vec<u64> = vec![];
+        for id in candidates {
+            if *n_orders == Some(triggered.len() as u128) {
+                break;
+            }
+            let order = CONDITIONAL_ORDERS.load(storage, *id)?;
+            if !order.condition.is_met(market_price) {
+                break;
+            }
+            triggered.push(*id);
+        }
+
+        if triggered.is_empty() {
+            continue;
+        }
+
+        order_ids.retain(|id| !triggered.contains(id));
+        if order_ids.is_empty() {
+            SORTED_CONDITIONAL_ORDERS.remove(storage, &key);
+        } else {
+            SORTED_CONDITIONAL_ORDERS.save(storage, &key, &order_ids)?;
+        }
+
+        if let Some(n) = n_orders {
+            *n -= triggered.len() as u128;
+        }
+
+        for order_id in triggered {
+            *reply_info_id = match reply_info_id.checked_add(1) {
+                Some(id) => id,
+                None => {
+                    return Err(StdError::overflow(OverflowError::new(
+                        cosmwasm_std::OverflowOperation::Add,
+                        "reply_info_max_id",
+                        "increment one",
+                    )))
+                }
+            };
+
+            let reply_info = ReplyInfo {
+                id: *reply_info_id,
+                reply_type: ReplyType::ConditionalOrder,
+                data: Some(to_json_binary(&order_id)?),
+                fee: None,
+            };
+            REPLY_INFO.save(storage, *reply_info_id, &reply_info)?;
+
+            let msg = WasmMsg::Execute {
+                contract_addr: contract.to_string(),
+                msg: to_json_binary(&ExecuteMsg::ExecuteConditionalOrder { order_id })?,
+                funds: vec![],
+            };
+            submsgs.push(SubMsg::reply_always(msg, *reply_info_id));
+        }
+    }
+
+    Ok(())
+}
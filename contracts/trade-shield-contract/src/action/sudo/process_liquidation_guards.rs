@@ -0,0 +1,125 @@
+use crate::msg::ReplyType;
+use cosmwasm_std::{to_json_binary, OverflowError, StdError, StdResult, Storage, SubMsg};
+use cw_storage_plus::Bound;
+
+use super::*;
+
+pub const MAX_LIQUIDATION_GUARDS_PER_BLOCK: usize = 10;
+
+/// Tops up the positions whose health dropped below the threshold of their
+/// guard, and refunds the escrow of the guards whose position is gone.
+pub fn process_liquidation_guards(
+    storage: &mut dyn Storage,
+    querier: &ElysQuerier<'_>,
+    creator: &str,
+    reply_info_id: &mut u64,
+    submsgs: &mut Vec<SubMsg<ElysMsg>>,
+    bank_msgs: &mut Vec<BankMsg>,
+    events: &mut Vec<Event>,
+) -> StdResult<()> {
+    let guards = next_liquidation_guards(storage)?;
+
+    for guard in guards {
+        // a failing query is retried on a later block, only a position the
+        // module reports as missing releases the escrow
+        let mtp = match querier.mtp(guard.owner.clone(), guard.position_id) {
+            Ok(resp) => resp.mtp,
+            Err(_) => continue,
+        };
+
+        let mtp = match mtp {
+            Some(mtp) => mtp.mtp,
+            None => {
+                LIQUIDATION_GUARDS.remove(storage, (guard.owner.as_str(), guard.position_id));
+                bank_msgs.push(BankMsg::Send {
+                    to_address: guard.owner.clone(),
+                    amount: vec![guard.escrow.clone()],
+                });
+                events.push(events::liquidation_guard_closed(&guard, "position closed"));
+                continue;
+            }
+        };
+
+        if !guard.is_triggered(mtp.mtp_health) {
+            continue;
+        }
+
+        let top_up = guard.next_top_up();
+
+        *reply_info_id = match reply_info_id.checked_add(1) {
+            Some(id) => id,
+            None => {
+                return Err(StdError::overflow(OverflowError::new(
+                    cosmwasm_std::OverflowOperation::Add,
+                    "reply_info_max_id",
+                    "increment one",
+                )))
+            }
+        };
+
+        // the escrow is only debited once the perpetual module accepted the collateral
+        let reply_info = ReplyInfo {
+            id: *reply_info_id,
+            reply_type: ReplyType::LiquidationGuardTopUp,
+            data: Some(to_json_binary(&(
+                guard.owner.clone(),
+                guard.position_id,
+                top_up.clone(),
+            ))?),
+            fee: None,
+        };
+        REPLY_INFO.save(storage, *reply_info_id, &reply_info)?;
+
+        let msg = ElysMsg::perpetual_add_collateral(
+            creator,
+            guard.position_id,
+            top_up.amount.u128(),
+            &guard.owner,
+        );
+        submsgs.push(SubMsg::reply_always(msg, *reply_info_id));
+    }
+
+    Ok(())
+}
+
+/// Visits at most MAX_LIQUIDATION_GUARDS_PER_BLOCK guards per block, starting
+/// after the last one visited and wrapping around to the first guard.
+fn next_liquidation_guards(storage: &mut dyn Storage) -> StdResult<Vec<LiquidationGuard>> {
+    let cursor = LIQUIDATION_GUARD_CURSOR.may_load(storage)?;
+    let cursor = cursor
+        .as_ref()
+        .map(|(owner, position_id)| (owner.as_str(), *position_id));
+
+    let mut guards: Vec<LiquidationGuard> = LIQUIDATION_GUARDS
+        .range(
+            storage,
+            cursor.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(MAX_LIQUIDATION_GUARDS_PER_BLOCK)
+        .map(|res| res.map(|(_, guard)| guard))
+        .collect::<StdResult<_>>()?;
+    if guards.len() < MAX_LIQUIDATION_GUARDS_PER_BLOCK {
+        let wrapped: Vec<LiquidationGuard> = LIQUIDATION_GUARDS
+            .range(
+                storage,
+                None,
+                cursor.map(Bound::inclusive),
+                Order::Ascending,
+            )
+            .take(MAX_LIQUIDATION_GUARDS_PER_BLOCK - guards.len())
+            .map(|res| res.map(|(_, guard)| guard))
+            .collect::<StdResult<_>>()?;
+        guards.extend(wrapped);
+    }
+
+    match guards.last() {
+        Some(guard) => {
+            LIQUIDATION_GUARD_CURSOR.save(storage, &(guard.owner.clone(), guard.position_id))?
+        }
+        None => LIQUIDATION_GUARD_CURSOR.remove(storage),
+    }
+
+    Ok(guards)
+}
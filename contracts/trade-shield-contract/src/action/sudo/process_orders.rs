@@ -1,12 +1,15 @@
 use crate::{
-    helper::{get_discount, remove_perpetual_order},
+    helper::{
+        check_not_paused, compute_fee, get_discount, get_trigger_market_price,
+        remove_perpetual_order, remove_spot_order,
+    },
     msg::ReplyType,
 };
 use cosmwasm_std::{
-    coin, to_json_binary, Decimal, Int128, OverflowError, QuerierWrapper, StdError, StdResult,
-    Storage, SubMsg,
+    to_json_binary, Coin, Decimal, Int128, OverflowError, QuerierWrapper, StdError, StdResult,
+    Storage, SubMsg, Timestamp, Uint128,
 };
-use elys_bindings::query_resp::{Entry, QueryGetEntryResponse};
+use elys_bindings::query_resp::{AmmSwapEstimationByDenomResponse, Entry, QueryGetEntryResponse};
 
 use super::*;
 
@@ -14,7 +17,12 @@ pub fn process_orders(
     deps: DepsMut<ElysQuery>,
     env: Env,
 ) -> Result<Response<ElysMsg>, ContractError> {
-    let spot_orders: Vec<(String, Vec<u64>)> = if SWAP_ENABLED.load(deps.storage)? {
+    // checked here so that the end blocker and the keepers are both covered
+    check_not_paused(deps.storage)?;
+
+    let params = PARAMS.load(deps.storage)?;
+
+    let spot_orders: Vec<(String, Vec<u64>)> = if params.swap_enabled {
         SORTED_PENDING_SPOT_ORDER
             .prefix_range(deps.storage, None, None, Order::Ascending)
             .filter_map(|res| res.ok())
@@ -23,10 +31,11 @@ pub fn process_orders(
         vec![]
     };
 
-    let mut n_spot_order = LIMIT_PROCESS_ORDER.load(deps.storage)?;
+    let mut n_spot_order = params.limit_process_order;
     let mut n_perpetual_order = n_spot_order.clone();
+    let mut n_conditional_order = n_spot_order;
 
-    let perpetual_orders: Vec<(String, Vec<u64>)> = if PERPETUAL_ENABLED.load(deps.storage)? {
+    let perpetual_orders: Vec<(String, Vec<u64>)> = if params.perpetual_enabled {
         SORTED_PENDING_PERPETUAL_ORDER
             .prefix_range(deps.storage, None, None, Order::Ascending)
             .filter_map(|res| res.ok())
@@ -40,6 +49,7 @@ pub fn process_orders(
     let querier = ElysQuerier::new(&deps.querier);
     let mut submsgs: Vec<SubMsg<ElysMsg>> = vec![];
     let mut bank_msgs: Vec<BankMsg> = vec![];
+    let mut order_events: Vec<Event> = vec![];
 
     let QueryGetEntryResponse {
         entry: Entry {
@@ -52,14 +62,31 @@ pub fn process_orders(
             break;
         }
 
-        let (order_type, base_denom, quote_denom) = SpotOrder::from_key(key.as_str())?;
+        let (order_type, base_denom, quote_denom, is_trigger) = SpotOrder::from_key(key.as_str())?;
 
         if order_type == SpotOrderType::MarketBuy {
             SORTED_PENDING_SPOT_ORDER.remove(deps.storage, key.as_str());
             continue;
         }
 
-        let market_price =
+        let market_price = if is_trigger {
+            // trigger prices are compared with the oracle price of the pair as it is
+            match get_trigger_market_price(&querier, &base_denom, &quote_denom) {
+                Ok(market_price) => market_price,
+                // the orders are only cancelled when the pair cannot be priced at all
+                Err(e) if !e.is_price_missing() => continue,
+                Err(_) => {
+                    bank_msgs.extend(cancel_spot_orders(
+                        deps.storage,
+                        key,
+                        order_ids,
+                        None,
+                        &mut order_events,
+                    )?);
+                    continue;
+                }
+            }
+        } else {
             match querier.get_asset_price_from_denom_in_to_denom_out(&base_denom, &quote_denom) {
                 Ok(market_price) => {
                     if order_type == SpotOrderType::LimitBuy {
@@ -73,30 +100,20 @@ pub fn process_orders(
                         market_price
                     }
                 }
+                Err(e) if !e.is_price_missing() => continue,
                 Err(_) => {
-                    bank_msgs.extend(cancel_spot_orders(deps.storage, key, order_ids, None)?);
-                    continue;
-                }
-            };
-        let closest_spot_price = SpotOrder::binary_search(&market_price, deps.storage, &order_ids)?;
-        let routes = match querier.amm_swap_estimation_by_denom(
-            &coin(1000000, &base_denom),
-            &base_denom,
-            &quote_denom,
-            &Decimal::zero(),
-        ) {
-            Ok(r) => match r.in_route {
-                Some(routes) => routes,
-                None => {
-                    bank_msgs.extend(cancel_spot_orders(deps.storage, key, order_ids, None)?);
+                    bank_msgs.extend(cancel_spot_orders(
+                        deps.storage,
+                        key,
+                        order_ids,
+                        None,
+                        &mut order_events,
+                    )?);
                     continue;
                 }
-            },
-            Err(_) => {
-                bank_msgs.extend(cancel_spot_orders(deps.storage, key, order_ids, None)?);
-                continue;
             }
         };
+        let closest_spot_price = SpotOrder::binary_search(&market_price, deps.storage, &order_ids)?;
 
         let orders_to_process: Vec<u64> = split_spot_order(
             closest_spot_price,
@@ -108,13 +125,15 @@ pub fn process_orders(
         )?;
 
         process_spot_order(
-            routes,
             orders_to_process,
             &mut submsgs,
+            &mut bank_msgs,
+            &mut order_events,
             env.contract.address.as_str(),
             &mut reply_info_id,
             deps.storage,
             deps.querier,
+            env.block.time,
         )?;
     }
 
@@ -126,14 +145,16 @@ pub fn process_orders(
             PerpetualOrderV2::from_key(key.as_str())?;
 
         //get the price in usdc
-        let market_price =
-            match querier.get_asset_price_from_denom_in_to_denom_out(&quote_denom, &base_denom) {
-                Ok(market_price) => market_price,
-                Err(_) => {
-                    cancel_perpetual_orders(deps.storage, key, &order_ids, None)?;
-                    continue;
-                }
-            };
+        let market_price = match querier
+            .get_asset_price_from_denom_in_to_denom_out(&quote_denom, &base_denom)
+        {
+            Ok(market_price) => market_price,
+            Err(e) if !e.is_price_missing() => continue,
+            Err(_) => {
+                cancel_perpetual_orders(deps.storage, key, &order_ids, None, &mut order_events)?;
+                continue;
+            }
+        };
 
         let closest_index = PerpetualOrderV2::binary_search(
             &Some(OrderPrice {
@@ -158,21 +179,65 @@ pub fn process_orders(
         process_perpetual_order(
             order_to_execute,
             &mut submsgs,
+            &mut order_events,
             &mut reply_info_id,
+            deps.storage,
+            deps.querier,
+            env.contract.address.as_str(),
+            env.block.time,
+        )?;
+    }
+
+    process_conditional_orders(
+        deps.storage,
+        &querier,
+        env.contract.address.as_str(),
+        &mut n_conditional_order,
+        &mut reply_info_id,
+        &mut submsgs,
+    )?;
+
+    if params.perpetual_enabled {
+        process_liquidation_guards(
             deps.storage,
             &querier,
             env.contract.address.as_str(),
+            &mut reply_info_id,
+            &mut submsgs,
+            &mut bank_msgs,
+            &mut order_events,
+        )?;
+    }
+
+    if params.reward_enabled {
+        process_auto_compounds(
+            deps.storage,
+            env.contract.address.as_str(),
+            env.block.time,
+            &mut reply_info_id,
+            &mut submsgs,
         )?;
     }
 
     MAX_REPLY_ID.save(deps.storage, &reply_info_id)?;
 
+    // a failing order should not be able to consume the gas of the whole block
+    if let Some(gas_limit) = params.order_gas_limit {
+        submsgs = submsgs
+            .into_iter()
+            .map(|submsg| submsg.with_gas_limit(gas_limit))
+            .collect();
+    }
+
     let resp = if bank_msgs.is_empty() {
-        Response::new().add_submessages(submsgs)
+        Response::new()
+            .add_submessages(submsgs)
+            .add_events(order_events)
     } else {
         Response::new()
             .add_submessages(submsgs)
             .add_messages(bank_msgs)
+            .add_events(order_events)
     };
 
     Ok(resp)
@@ -181,19 +246,34 @@ pub fn process_orders(
 fn process_perpetual_order(
     orders_ids: Vec<u64>,
     submsgs: &mut Vec<SubMsg<ElysMsg>>,
+    order_events: &mut Vec<Event>,
     reply_info_id: &mut u64,
     storage: &mut dyn Storage,
-    querier: &ElysQuerier<'_>,
+    querier: QuerierWrapper<'_, ElysQuery>,
     creator: &str,
+    block_time: Timestamp,
 ) -> StdResult<()> {
+    let params = PARAMS.load(storage)?;
+    let elys_querier = ElysQuerier::new(&querier);
+
     for id in orders_ids {
         let order = PENDING_PERPETUAL_ORDER_V2.load(storage, id)?;
 
+        let mut fee = None;
         let (msg, reply_type) = if order.order_type == PerpetualOrderType::LimitOpen {
+            let discount = get_discount(storage, querier, block_time, order.owner.clone())?;
+            fee = compute_fee(&params, &order.collateral, discount);
+            let collateral = match &fee {
+                Some(fee) => Coin {
+                    denom: order.collateral.denom.clone(),
+                    amount: order.collateral.amount - fee.amount,
+                },
+                None => order.collateral.clone(),
+            };
             (
                 ElysMsg::perpetual_open_position(
                     creator,
-                    order.collateral.clone(),
+                    collateral,
                     &order.trading_asset,
                     order.position.clone(),
                     order.leverage.clone(),
@@ -203,13 +283,14 @@ fn process_perpetual_order(
                 ReplyType::PerpetualBrokerOpen,
             )
         } else {
-            let mtp = match querier
+            let mtp = match elys_querier
                 .mtp(order.owner.clone(), order.position_id.unwrap())?
                 .mtp
             {
                 Some(mtp) => mtp,
                 None => {
-                    remove_perpetual_order(id, Status::Canceled, storage, None)?;
+                    remove_perpetual_order(id, Status::Canceled, storage, block_time, None)?;
+                    order_events.push(events::order_cancelled(&order, "position not found"));
                     continue;
                 }
             };
@@ -242,8 +323,10 @@ fn process_perpetual_order(
             id: *reply_info_id,
             reply_type,
             data: Some(to_json_binary(&order.order_id)?),
+            fee,
         };
         submsgs.push(SubMsg::reply_always(msg, *reply_info_id));
+        order_events.push(events::order_triggered(&order));
 
         REPLY_INFO.save(storage, *reply_info_id, &reply_info)?;
     }
@@ -364,7 +447,7 @@ fn split_spot_order(
         Some(
             PENDING_SPOT_ORDER
                 .load(storage, ids[closest_index])?
-                .order_price
+                .trigger()
                 .rate,
         )
     } else {
@@ -417,20 +500,70 @@ fn split_spot_order(
 }
 
 fn process_spot_order(
-    routes: Vec<SwapAmountInRoute>,
     orders_ids: Vec<u64>,
     submsgs: &mut Vec<SubMsg<ElysMsg>>,
+    bank_msgs: &mut Vec<BankMsg>,
+    order_events: &mut Vec<Event>,
     sender: &str,
     reply_info_id: &mut u64,
     storage: &mut dyn Storage,
     querier: QuerierWrapper<'_, ElysQuery>,
+    block_time: Timestamp,
 ) -> StdResult<()> {
+    let params = PARAMS.load(storage)?;
+
     for id in orders_ids {
         let order = match PENDING_SPOT_ORDER.may_load(storage, id)? {
             Some(order) => order,
             None => continue,
         };
 
+        // a triggered limit order waits until its limit can be met as well
+        if let Some(min_amount_out) = limit_amount_out(&order, order.order_amount.amount)? {
+            let expected_out = ElysQuerier::new(&querier)
+                .get_asset_price_from_denom_in_to_denom_out(
+                    &order.order_amount.denom,
+                    &order.order_target_denom,
+                )
+                .ok()
+                .and_then(|price| order.order_amount.amount.checked_mul_floor(price).ok());
+            if expected_out.map_or(true, |expected_out| expected_out < min_amount_out) {
+                continue;
+            }
+        }
+
+        let discount = get_discount(
+            storage,
+            querier,
+            block_time,
+            order.owner_address.to_string(),
+        )?;
+
+        // the route is estimated with the order amount so the quote matches the size of the trade
+        let routes = match &order.routes {
+            Some(routes) => routes.clone(),
+            None => match ElysQuerier::new(&querier).amm_swap_estimation_by_denom(
+                &order.order_amount,
+                &order.order_amount.denom,
+                &order.order_target_denom,
+                &discount,
+            ) {
+                Ok(AmmSwapEstimationByDenomResponse {
+                    in_route: Some(routes),
+                    ..
+                }) => routes,
+                _ => {
+                    if let Some(bank_msg) =
+                        remove_spot_order(id, Status::Canceled, storage, block_time)?
+                    {
+                        bank_msgs.push(bank_msg);
+                    }
+                    order_events.push(events::order_cancelled(&order, "no swap route"));
+                    continue;
+                }
+            },
+        };
+
         *reply_info_id = match reply_info_id.checked_add(1) {
             Some(id) => id,
             None => {
@@ -442,35 +575,77 @@ fn process_spot_order(
                 .into())
             }
         };
+        let fee = compute_fee(&params, &order.order_amount, discount);
+        let swap_amount = match &fee {
+            Some(fee) => Coin {
+                denom: order.order_amount.denom.clone(),
+                amount: order.order_amount.amount - fee.amount,
+            },
+            None => order.order_amount.clone(),
+        };
+
         let reply_info = ReplyInfo {
             id: *reply_info_id,
             reply_type: ReplyType::SpotOrder,
             data: Some(to_json_binary(&order.order_id)?),
+            fee,
         };
         REPLY_INFO.save(storage, *reply_info_id, &reply_info)?;
 
-        let discount = get_discount(querier, order.owner_address.to_string())?;
+        // the proceeds of a grid level stay in the contract to fund the opposite order
+        let recipient = if GRID_ORDERS.has(storage, id) {
+            sender
+        } else {
+            order.owner_address.as_str()
+        };
 
         let msg = ElysMsg::amm_swap_exact_amount_in(
             sender,
-            &order.order_amount,
+            &swap_amount,
             &routes,
-            Int128::zero(),
+            match limit_amount_out(&order, swap_amount.amount)? {
+                Some(min_amount_out) => Int128::try_from(min_amount_out)?,
+                None => Int128::zero(),
+            },
             discount,
-            order.owner_address.as_str(),
+            recipient,
         );
 
         submsgs.push(SubMsg::reply_always(msg, *reply_info_id));
+        order_events.push(events::order_triggered(&order));
     }
 
     Ok(())
 }
 
+/// Minimum amount out of a triggered limit order swapping `amount`, its order
+/// price is the limit: target per source for a sell and source per target for a buy.
+fn limit_amount_out(order: &SpotOrder, amount: Uint128) -> StdResult<Option<Uint128>> {
+    if order.trigger_price.is_none() {
+        return Ok(None);
+    }
+    let rate = order.order_price.rate;
+    match order.order_type {
+        SpotOrderType::LimitSell => Ok(Some(
+            amount
+                .checked_mul_floor(rate)
+                .map_err(|e| StdError::generic_err(e.to_string()))?,
+        )),
+        SpotOrderType::LimitBuy => Ok(Some(
+            amount
+                .checked_div_floor(rate)
+                .map_err(|e| StdError::generic_err(e.to_string()))?,
+        )),
+        _ => Ok(None),
+    }
+}
+
 fn cancel_spot_orders(
     storage: &mut dyn Storage,
     key: &str,
     ids: &Vec<u64>,
     to_remove: Option<Vec<usize>>,
+    order_events: &mut Vec<Event>,
 ) -> StdResult<Vec<BankMsg>> {
     let mut bank_msg: Vec<BankMsg> = vec![];
 
@@ -490,6 +665,7 @@ fn cancel_spot_orders(
         spot_order.status = Status::Canceled;
         PENDING_SPOT_ORDER.remove(storage, id);
         SPOT_ORDER.save(storage, id, &spot_order)?;
+        order_events.push(events::order_cancelled(&spot_order, "price not available"));
         bank_msg.push(BankMsg::Send {
             to_address: spot_order.owner_address.to_string(),
             amount: vec![spot_order.order_amount],
@@ -504,6 +680,7 @@ fn cancel_perpetual_orders(
     key: &str,
     ids: &Vec<u64>,
     to_remove: Option<Vec<usize>>,
+    order_events: &mut Vec<Event>,
 ) -> StdResult<Vec<BankMsg>> {
     let mut bank_msg: Vec<BankMsg> = vec![];
 
@@ -523,6 +700,10 @@ fn cancel_perpetual_orders(
         perpetual_order.status = Status::Canceled;
         PENDING_PERPETUAL_ORDER_V2.remove(storage, id);
         PERPETUAL_ORDER_V2.save(storage, id, &perpetual_order)?;
+        order_events.push(events::order_cancelled(
+            &perpetual_order,
+            "price not available",
+        ));
         if perpetual_order.order_type == PerpetualOrderType::LimitOpen {
             bank_msg.push(BankMsg::Send {
                 to_address: perpetual_order.owner,
@@ -1,16 +1,15 @@
 use super::*;
 use crate::action::sudo::process_orders;
-use crate::helper::get_discount;
-use cosmwasm_std::{Int128, StdError};
-use elys_bindings::trade_shield::states::{
-    LEVERAGE_ENABLED, LIMIT_PROCESS_ORDER, MARKET_ORDER_ENABLED, PARAMS_ADMIN, PERPETUAL_ENABLED,
-    PROCESS_ORDERS_ENABLED, REWARD_ENABLED, STAKE_ENABLED, SWAP_ENABLED,
-};
+use crate::events;
+use crate::helper::{check_role, get_discount};
+use cosmwasm_std::Int128;
+use elys_bindings::trade_shield::states::PARAMS;
+use elys_bindings::trade_shield::types::{Role, SpotOrderExtras};
 use msg::ExecuteMsg;
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut<ElysQuery>,
+    mut deps: DepsMut<ElysQuery>,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
@@ -24,6 +23,8 @@ pub fn execute(
             order_source_denom,
             order_target_denom,
             order_price,
+            routes,
+            trigger_price,
         } => create_spot_order(
             env,
             deps,
@@ -31,19 +32,24 @@ pub fn execute(
             order_type,
             order_source_denom,
             order_target_denom,
-            order_price,
+            SpotOrderExtras {
+                order_price,
+                routes,
+                trigger_price,
+            },
         ),
-        CancelSpotOrder { order_id } => cancel_spot_order(info, deps, order_id),
+        CancelSpotOrder { order_id } => cancel_spot_order(info, deps, env, order_id),
         CancelSpotOrders {
             order_ids,
             order_type,
-        } => cancel_spot_orders(info, deps, order_ids, order_type),
+        } => cancel_spot_orders(info, deps, env, order_ids, order_type),
 
         CreatePerpetualOrder {
             position,
             leverage,
             trading_asset,
             take_profit_price,
+            stop_loss_price,
             order_type,
             trigger_price,
             position_id,
@@ -55,15 +61,16 @@ pub fn execute(
             leverage,
             trading_asset,
             take_profit_price,
+            stop_loss_price,
             order_type,
             trigger_price,
             position_id,
         ),
-        CancelPerpetualOrder { order_id } => cancel_perpetual_order(info, deps, order_id),
+        CancelPerpetualOrder { order_id } => cancel_perpetual_order(info, deps, env, order_id),
         CancelPerpetualOrders {
             order_ids,
             order_type,
-        } => cancel_perpetual_orders(info, deps, order_ids, order_type),
+        } => cancel_perpetual_orders(info, deps, env, order_ids, order_type),
         ClosePerpetualPosition { id, amount } => {
             close_perpetual_position(info, deps, env, id, amount)
         }
@@ -132,7 +139,12 @@ pub fn execute(
                 routes,
                 token_in: info.funds[0].clone(),
                 token_out_min_amount: Int128::zero(),
-                discount: get_discount(deps.querier, info.sender.to_string())?,
+                discount: get_discount(
+                    deps.storage,
+                    deps.querier,
+                    env.block.time,
+                    info.sender.to_string(),
+                )?,
                 recipient: "".to_string(),
             };
 
@@ -173,40 +185,127 @@ pub fn execute(
             reward_enabled,
             leverage_enabled,
             limit_process_order,
+            min_order_notional,
+            max_pending_orders_per_user,
+            max_leverage,
+            allowed_pairs,
+            fee_rate,
+            fee_collector,
+            referrer_fee_share,
+            order_gas_limit,
         } => {
-            let admin = PARAMS_ADMIN.load(deps.storage)?;
-
-            if admin.as_str() != info.sender.as_str() {
-                return Err(StdError::generic_err("Unauthorize: wrong sender").into());
-            }
+            // operators can tune the order limits, everything else is left to the admin
+            let admin_only = market_order_enabled.is_some()
+                || stake_enabled.is_some()
+                || process_order_enabled.is_some()
+                || swap_enabled.is_some()
+                || perpetual_enabled.is_some()
+                || reward_enabled.is_some()
+                || leverage_enabled.is_some()
+                || fee_rate.is_some()
+                || fee_collector.is_some()
+                || referrer_fee_share.is_some();
+            let role = if admin_only {
+                Role::Admin
+            } else {
+                Role::Operator
+            };
+            check_role(deps.storage, role, &info.sender)?;
+            let mut params = PARAMS.load(deps.storage)?;
+            let mut changes: Vec<(&str, String)> = vec![];
             if let Some(market_order_enabled) = market_order_enabled {
-                MARKET_ORDER_ENABLED.save(deps.storage, &market_order_enabled)?;
+                params.market_order_enabled = market_order_enabled;
+                changes.push(("market_order_enabled", market_order_enabled.to_string()));
             }
             if let Some(stake_enabled) = stake_enabled {
-                STAKE_ENABLED.save(deps.storage, &stake_enabled)?;
+                params.stake_enabled = stake_enabled;
+                changes.push(("stake_enabled", stake_enabled.to_string()));
             }
             if let Some(swap_enabled) = swap_enabled {
-                SWAP_ENABLED.save(deps.storage, &swap_enabled)?;
+                params.swap_enabled = swap_enabled;
+                changes.push(("swap_enabled", swap_enabled.to_string()));
             }
             if let Some(process_order_enabled) = process_order_enabled {
-                PROCESS_ORDERS_ENABLED.save(deps.storage, &process_order_enabled)?;
+                params.process_order_enabled = process_order_enabled;
+                changes.push(("process_order_enabled", process_order_enabled.to_string()));
             }
             if let Some(perpetual_enabled) = perpetual_enabled {
-                PERPETUAL_ENABLED.save(deps.storage, &perpetual_enabled)?;
+                params.perpetual_enabled = perpetual_enabled;
+                changes.push(("perpetual_enabled", perpetual_enabled.to_string()));
             }
             if let Some(reward_enabled) = reward_enabled {
-                REWARD_ENABLED.save(deps.storage, &reward_enabled)?;
+                params.reward_enabled = reward_enabled;
+                changes.push(("reward_enabled", reward_enabled.to_string()));
             }
             if let Some(leverage_enabled) = leverage_enabled {
-                LEVERAGE_ENABLED.save(deps.storage, &leverage_enabled)?;
+                params.leverage_enabled = leverage_enabled;
+                changes.push(("leverage_enabled", leverage_enabled.to_string()));
             }
             if let Some(limit_process_order) = limit_process_order {
-                match limit_process_order {
-                    0 => LIMIT_PROCESS_ORDER.save(deps.storage, &None)?,
-                    x => LIMIT_PROCESS_ORDER.save(deps.storage, &Some(x))?,
+                params.limit_process_order = match limit_process_order {
+                    0 => None,
+                    x => Some(x),
+                };
+                changes.push(("limit_process_order", limit_process_order.to_string()));
+            }
+            if let Some(min_order_notional) = min_order_notional {
+                params.min_order_notional = min_order_notional;
+                changes.push(("min_order_notional", min_order_notional.to_string()));
+            }
+            if let Some(max_pending_orders_per_user) = max_pending_orders_per_user {
+                params.max_pending_orders_per_user = match max_pending_orders_per_user {
+                    0 => None,
+                    x => Some(x),
                 };
+                changes.push((
+                    "max_pending_orders_per_user",
+                    max_pending_orders_per_user.to_string(),
+                ));
+            }
+            if let Some(max_leverage) = max_leverage {
+                let value = max_leverage
+                    .iter()
+                    .map(|leverage| format!("{}:{}", leverage.trading_asset, leverage.max_leverage))
+                    .collect::<Vec<String>>()
+                    .join(",");
+                params.max_leverage = max_leverage;
+                changes.push(("max_leverage", value));
+            }
+            if let Some(allowed_pairs) = allowed_pairs {
+                let value = allowed_pairs
+                    .iter()
+                    .map(|pair| format!("{}/{}", pair.base_denom, pair.quote_denom))
+                    .collect::<Vec<String>>()
+                    .join(",");
+                params.allowed_pairs = allowed_pairs;
+                changes.push(("allowed_pairs", value));
+            }
+            if let Some(fee_rate) = fee_rate {
+                params.fee_rate = fee_rate;
+                changes.push(("fee_rate", fee_rate.to_string()));
             }
-            Ok(Response::new())
+            if let Some(fee_collector) = fee_collector {
+                params.fee_collector = if fee_collector.is_empty() {
+                    None
+                } else {
+                    Some(fee_collector.clone())
+                };
+                changes.push(("fee_collector", fee_collector));
+            }
+            if let Some(referrer_fee_share) = referrer_fee_share {
+                params.referrer_fee_share = referrer_fee_share;
+                changes.push(("referrer_fee_share", referrer_fee_share.to_string()));
+            }
+            if let Some(order_gas_limit) = order_gas_limit {
+                params.order_gas_limit = match order_gas_limit {
+                    0 => None,
+                    x => Some(x),
+                };
+                changes.push(("order_gas_limit", order_gas_limit.to_string()));
+            }
+            params.validate(deps.api)?;
+            PARAMS.save(deps.storage, &params)?;
+            Ok(Response::new().add_event(events::params_changed(info.sender, changes)))
         }
         EstakingWithdrawElysStakingRewards {} => estaking_withdraw_elys_staking_rewards(info, deps),
 
@@ -216,9 +315,7 @@ pub fn execute(
             estaking_withdraw_reward(info, deps, validator_address)
         }
         ProcessOrders {} => {
-            if info.sender != PARAMS_ADMIN.load(deps.storage)? {
-                return Err(StdError::generic_err("Unauthorized").into());
-            }
+            check_role(deps.storage, Role::Keeper, &info.sender)?;
 
             let resp = process_orders(deps, env)?;
             Ok(resp)
@@ -227,6 +324,81 @@ pub fn execute(
             let resp = perpetual_add_collateral(info, deps, env, id);
             resp
         }
+        PerpetualUpdateTakeProfitPrice { id, price } => {
+            perpetual_update_take_profit_price(info, deps, env, id, price)
+        }
+        PerpetualUpdateStopLoss { id, price } => {
+            perpetual_update_stop_loss(info, deps, env, id, price)
+        }
+        WithdrawFees { denoms } => withdraw_fees(info, deps, denoms),
+        SetReferrer { referrer } => set_referrer(info, deps, referrer),
+        SetDiscountOverride {
+            address,
+            discount,
+            expires_at,
+        } => set_discount_override(env, info, deps, address, discount, expires_at),
+        RemoveDiscountOverride { address } => remove_discount_override(info, deps, address),
+        Pause {} => set_paused(info, deps, true),
+        Unpause {} => set_paused(info, deps, false),
+        ForceCancelAll { pair, limit } => force_cancel_all(info, deps, env, pair, limit),
+        GrantRole { role, address } => grant_role(info, deps, role, address),
+        RevokeRole { role, address } => revoke_role(info, deps, role, address),
+        CreateLiquidationGuard {
+            position_id,
+            health_threshold,
+            top_up_amount,
+        } => create_liquidation_guard(info, deps, position_id, health_threshold, top_up_amount),
+        CancelLiquidationGuard { position_id } => cancel_liquidation_guard(info, deps, position_id),
+        Grant {
+            grantee,
+            permissions,
+            spend_limit,
+            expires_at,
+        } => grant(
+            env,
+            info,
+            deps,
+            grantee,
+            permissions,
+            spend_limit,
+            expires_at,
+        ),
+        Revoke { grantee } => revoke(info, deps, grantee),
+        OnBehalfOf { granter, msg } => {
+            let (info, event) = on_behalf_of(&env, info, deps.branch(), granter, &msg)?;
+            execute(deps, env, info, *msg).map(|resp| resp.add_event(event))
+        }
+        CreateGridOrder {
+            base_denom,
+            quote_denom,
+            lower_price,
+            upper_price,
+            levels,
+        } => create_grid_order(
+            env,
+            info,
+            deps,
+            base_denom,
+            quote_denom,
+            (lower_price, upper_price),
+            levels,
+        ),
+        CloseGrid { grid_id } => close_grid(info, deps, env, grid_id),
+        SubscribeAutoCompound { policy, interval } => {
+            subscribe_auto_compound(env, info, deps, policy, interval)
+        }
+        UnsubscribeAutoCompound {} => unsubscribe_auto_compound(info, deps),
+        RunAutoCompound { owner } => run_auto_compound(env, info, deps, owner),
+        Batch { actions } => batch(env, info, deps, actions),
+        CreateConditionalOrder { condition, msg } => {
+            create_conditional_order(info, deps, condition, msg)
+        }
+        CancelConditionalOrder { order_id } => cancel_conditional_order(info, deps, order_id),
+        ExecuteConditionalOrder { order_id } => {
+            let (info, msg, event) =
+                execute_conditional_order(&env, info, deps.branch(), order_id)?;
+            execute(deps, env, info, msg).map(|resp| resp.add_event(event))
+        }
     }?;
 
     Ok(resp)
@@ -1,5 +1,6 @@
 use super::*;
 use crate::states::*;
+use crate::types::Params;
 use msg::InstantiateMsg;
 
 use cw2::set_contract_version;
@@ -21,15 +22,7 @@ pub fn instantiate(
     let admin = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w".to_string();
     PARAMS_ADMIN.save(deps.storage, &admin)?;
 
-    let state = true;
-    MARKET_ORDER_ENABLED.save(deps.storage, &state)?;
-    STAKE_ENABLED.save(deps.storage, &state)?;
-    SWAP_ENABLED.save(deps.storage, &state)?;
-    PROCESS_ORDERS_ENABLED.save(deps.storage, &state)?;
-    PERPETUAL_ENABLED.save(deps.storage, &state)?;
-    REWARD_ENABLED.save(deps.storage, &state)?;
-    LEVERAGE_ENABLED.save(deps.storage, &state)?;
-    LIMIT_PROCESS_ORDER.save(deps.storage, &None)?;
+    PARAMS.save(deps.storage, &Params::default())?;
     NUMBER_OF_PENDING_ORDER.save(deps.storage, &0)?;
     NUMBER_OF_EXECUTED_ORDER.save(deps.storage, &0)?;
     Ok(Response::new())
@@ -1,14 +1,16 @@
 use self::instantiate::{CONTRACT_NAME, CONTRACT_VERSION};
 
 use super::*;
-use cosmwasm_std::{Order, StdError};
+use cosmwasm_std::{Decimal, Order, StdError};
 use cw2::set_contract_version;
 use elys_bindings::trade_shield::{
     msg::MigrateMsg,
     states::{
-        LEVERAGE_ENABLED, LIMIT_PROCESS_ORDER, MARKET_ORDER_ENABLED, PARAMS_ADMIN,
-        PERPETUAL_ENABLED, PROCESS_ORDERS_ENABLED, REWARD_ENABLED, STAKE_ENABLED, SWAP_ENABLED,
+        DISCOUNT_OVERRIDES, LEVERAGE_ENABLED, LIMIT_PROCESS_ORDER, MARKET_ORDER_ENABLED, PARAMS,
+        PARAMS_ADMIN, PERPETUAL_ENABLED, PROCESS_ORDERS_ENABLED, REWARD_ENABLED, STAKE_ENABLED,
+        SWAP_ENABLED,
     },
+    types::{DiscountOverride, Params},
 };
 use semver::Version;
 use trade_shield::{
@@ -27,16 +29,51 @@ pub fn migrate(
     let admin = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w".to_string();
     PARAMS_ADMIN.save(deps.storage, &admin)?;
 
-    let state = true;
-    let limit_process_order: Option<u128> = Some(100);
-    STAKE_ENABLED.save(deps.storage, &state)?;
-    MARKET_ORDER_ENABLED.save(deps.storage, &state)?;
-    SWAP_ENABLED.save(deps.storage, &state)?;
-    PROCESS_ORDERS_ENABLED.save(deps.storage, &state)?;
-    PERPETUAL_ENABLED.save(deps.storage, &state)?;
-    REWARD_ENABLED.save(deps.storage, &state)?;
-    LEVERAGE_ENABLED.save(deps.storage, &state)?;
-    LIMIT_PROCESS_ORDER.save(deps.storage, &limit_process_order)?;
+    // move the legacy toggles into the versioned params
+    if PARAMS.may_load(deps.storage)?.is_none() {
+        let storage = &mut *deps.storage;
+        let legacy = |item: cw_storage_plus::Item<bool>| -> StdResult<bool> {
+            Ok(item.may_load(storage)?.unwrap_or(true))
+        };
+        let params = Params {
+            market_order_enabled: legacy(MARKET_ORDER_ENABLED)?,
+            stake_enabled: legacy(STAKE_ENABLED)?,
+            process_order_enabled: legacy(PROCESS_ORDERS_ENABLED)?,
+            swap_enabled: legacy(SWAP_ENABLED)?,
+            perpetual_enabled: legacy(PERPETUAL_ENABLED)?,
+            reward_enabled: legacy(REWARD_ENABLED)?,
+            leverage_enabled: legacy(LEVERAGE_ENABLED)?,
+            limit_process_order: LIMIT_PROCESS_ORDER.may_load(storage)?.unwrap_or(Some(100)),
+            ..Params::default()
+        };
+        PARAMS.save(storage, &params)?;
+
+        for item in [
+            MARKET_ORDER_ENABLED,
+            STAKE_ENABLED,
+            PROCESS_ORDERS_ENABLED,
+            SWAP_ENABLED,
+            PERPETUAL_ENABLED,
+            REWARD_ENABLED,
+            LEVERAGE_ENABLED,
+        ] {
+            item.remove(storage);
+        }
+        LIMIT_PROCESS_ORDER.remove(storage);
+    }
+
+    // the discount of this address used to be hardcoded in get_discount
+    let discounted_address = "elys1u8c28343vvhwgwhf29w6hlcz73hvq7lwxmrl46";
+    if !DISCOUNT_OVERRIDES.has(deps.storage, discounted_address) {
+        DISCOUNT_OVERRIDES.save(
+            deps.storage,
+            discounted_address,
+            &DiscountOverride {
+                discount: Decimal::percent(20),
+                expires_at: None,
+            },
+        )?;
+    }
 
     match std::env::var("IS_TEST_ENV") {
         Ok(val) => {
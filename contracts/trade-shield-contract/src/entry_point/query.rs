@@ -6,9 +6,8 @@ use elys_bindings::trade_shield::{
         GetSortedOrderListResp, NumberOfPendingOrderResp, OrdersStates, TradeShieldParamsResponse,
     },
     states::{
-        LEVERAGE_ENABLED, LIMIT_PROCESS_ORDER, MARKET_ORDER_ENABLED, PARAMS_ADMIN,
-        PENDING_PERPETUAL_ORDER_V2, PENDING_SPOT_ORDER, PERPETUAL_ENABLED, PROCESS_ORDERS_ENABLED,
-        REWARD_ENABLED, SORTED_PENDING_SPOT_ORDER, SPOT_ORDER, STAKE_ENABLED, SWAP_ENABLED,
+        PARAMS, PARAMS_ADMIN, PAUSED, PENDING_PERPETUAL_ORDER_V2, PENDING_SPOT_ORDER,
+        SORTED_PENDING_SPOT_ORDER, SPOT_ORDER,
     },
     types::{Date, OrderPrice, PerpetualOrderV2, SpotOrder, Status},
 };
@@ -73,6 +72,7 @@ pub fn query(deps: Deps<ElysQuery>, env: Env, msg: QueryMsg) -> Result<Binary, C
             denom_in,
             denom_out,
             user_address,
+            env,
         )?)?),
         GetPerpetualAsset { address } => Ok(to_json_binary(&query::get_perpetuals_assets(
             deps, address, env,
@@ -93,6 +93,7 @@ pub fn query(deps: Deps<ElysQuery>, env: Env, msg: QueryMsg) -> Result<Binary, C
             collateral,
             take_profit_price,
             user_address,
+            env,
         )?)?),
         PerpetualGetPositionsForAddress {
             address,
@@ -168,26 +169,13 @@ pub fn query(deps: Deps<ElysQuery>, env: Env, msg: QueryMsg) -> Result<Binary, C
         )?),
         GetParams {} => Ok(to_json_binary(&{
             let params_admin = PARAMS_ADMIN.load(deps.storage)?;
-
-            let market_order_enabled = MARKET_ORDER_ENABLED.load(deps.storage)?;
-            let stake_enabled = STAKE_ENABLED.load(deps.storage)?;
-            let process_order_enabled = PROCESS_ORDERS_ENABLED.load(deps.storage)?;
-            let swap_enabled = SWAP_ENABLED.load(deps.storage)?;
-            let perpetual_enabled = PERPETUAL_ENABLED.load(deps.storage)?;
-            let reward_enabled = REWARD_ENABLED.load(deps.storage)?;
-            let leverage_enabled = LEVERAGE_ENABLED.load(deps.storage)?;
-            let limit_process_order = LIMIT_PROCESS_ORDER.load(deps.storage)?;
+            let params = PARAMS.load(deps.storage)?;
+            let paused = PAUSED.may_load(deps.storage)?.unwrap_or_default();
 
             TradeShieldParamsResponse {
                 params_admin,
-                market_order_enabled,
-                stake_enabled,
-                process_order_enabled,
-                swap_enabled,
-                perpetual_enabled,
-                reward_enabled,
-                leverage_enabled,
-                limit_process_order,
+                params,
+                paused,
             }
         })?),
         GetSortedOrderList {
@@ -206,6 +194,8 @@ pub fn query(deps: Deps<ElysQuery>, env: Env, msg: QueryMsg) -> Result<Binary, C
                 order_amount: coin(0, ""),
                 owner_address: Addr::unchecked(""),
                 order_target_denom: "".to_string(),
+                routes: None,
+                trigger_price: None,
                 status: trade_shield::types::Status::Canceled,
                 date: Date {
                     height: 0,
@@ -243,6 +233,45 @@ pub fn query(deps: Deps<ElysQuery>, env: Env, msg: QueryMsg) -> Result<Binary, C
         GetSpotOrderStates { order_id } => Ok(to_json_binary(&query::get_spot_order_states(
             deps, order_id,
         )?)?),
+        GetUserPoolPositions { user_address } => Ok(to_json_binary(
+            &query::get_user_pool_positions(deps, user_address)?,
+        )?),
         Version {} => Ok(to_json_binary(&CONTRACT.load(deps.storage)?)?),
+        GetFeesCollected {} => Ok(to_json_binary(&query::get_fees_collected(deps)?)?),
+        GetReferralStats { address } => {
+            Ok(to_json_binary(&query::get_referral_stats(deps, address)?)?)
+        }
+        GetTradingStats {
+            scope,
+            from_day,
+            to_day,
+        } => Ok(to_json_binary(&query::get_trading_stats(
+            deps, scope, from_day, to_day,
+        )?)?),
+        GetTopTraders { day, limit } => {
+            Ok(to_json_binary(&query::get_top_traders(deps, day, limit)?)?)
+        }
+        GetDiscount { address } => Ok(to_json_binary(&query::get_discount(deps, env, address)?)?),
+        GetRoles { role } => Ok(to_json_binary(&query::get_roles(deps, role)?)?),
+        GetLiquidationGuards { owner, pagination } => Ok(to_json_binary(
+            &query::get_liquidation_guards(deps, owner, pagination)?,
+        )?),
+        GetGrid { grid_id } => Ok(to_json_binary(&query::get_grid(deps, grid_id)?)?),
+        GetAutoCompound { owner } => Ok(to_json_binary(&query::get_auto_compound(deps, owner)?)?),
+        GetConditionalOrders { owner, pagination } => Ok(to_json_binary(
+            &query::get_conditional_orders(deps, owner, pagination)?,
+        )?),
+        GetGrants {
+            granter,
+            pagination,
+        } => Ok(to_json_binary(&query::get_grants(
+            deps, granter, pagination,
+        )?)?),
+        GetReferees {
+            referrer,
+            pagination,
+        } => Ok(to_json_binary(&query::get_referees(
+            deps, referrer, pagination,
+        )?)?),
     }
 }
@@ -6,7 +6,7 @@ use msg::ReplyType;
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn reply(
     deps: DepsMut<ElysQuery>,
-    _env: Env,
+    env: Env,
     msg: Reply,
 ) -> Result<Response<ElysMsg>, ContractError> {
     let module_resp = msg.result;
@@ -15,21 +15,31 @@ pub fn reply(
     REPLY_INFO.remove(deps.storage, msg.id);
 
     match info.reply_type {
-        ReplyType::SpotOrder => reply_to_spot_order(deps, info.data, module_resp),
+        ReplyType::SpotOrder => reply_to_spot_order(deps, env, info.data, info.fee, module_resp),
         ReplyType::PerpetualBrokerMarketOpen => {
-            reply_to_create_perpetual_market_open(deps, info.data, module_resp)
+            reply_to_create_perpetual_market_open(deps, env, info.data, info.fee, module_resp)
         }
 
         ReplyType::PerpetualBrokerMarketClose => {
-            reply_to_create_perpetual_market_close(deps, info.data, module_resp)
+            reply_to_create_perpetual_market_close(deps, env, info.data, module_resp)
         }
 
         ReplyType::PerpetualBrokerClose => {
-            reply_to_close_perpetual_order(deps, info.data, module_resp)
+            reply_to_close_perpetual_order(deps, env, info.data, module_resp)
+        }
+        ReplyType::SpotOrderMarketBuy => {
+            reply_to_spot_order_market(deps, env, info.data, info.fee, module_resp)
         }
-        ReplyType::SpotOrderMarketBuy => reply_to_spot_order_market(deps, info.data, module_resp),
         ReplyType::PerpetualBrokerOpen => {
-            reply_to_open_perpetual_position(deps, info.data, module_resp)
+            reply_to_open_perpetual_position(deps, env, info.data, info.fee, module_resp)
+        }
+        ReplyType::AmmJoinPool => reply_to_amm_join_pool(deps, env, info.data, module_resp),
+        ReplyType::AmmExitPool => reply_to_amm_exit_pool(deps, info.data, module_resp),
+        ReplyType::LiquidationGuardTopUp => {
+            reply_to_liquidation_guard_top_up(deps, info.data, module_resp)
         }
+        ReplyType::AutoCompoundRun => reply_to_auto_compound_run(deps, env, info.data, module_resp),
+        ReplyType::BatchStep => reply_to_batch_step(deps, env, info.data, module_resp),
+        ReplyType::ConditionalOrder => reply_to_conditional_order(deps, info.data, module_resp),
     }
 }
@@ -1,5 +1,5 @@
 use cosmwasm_std::StdError;
-use elys_bindings::trade_shield::states::PROCESS_ORDERS_ENABLED;
+use elys_bindings::trade_shield::states::PARAMS;
 
 use super::*;
 use crate::action::sudo::*;
@@ -13,7 +13,7 @@ pub fn sudo(
 ) -> Result<Response<ElysMsg>, ContractError> {
     match msg {
         SudoMsg::ClockEndBlock {} => {
-            if PROCESS_ORDERS_ENABLED.load(deps.storage)? == false {
+            if !PARAMS.load(deps.storage)?.process_order_enabled {
                 return Err(StdError::generic_err("process order is disable").into());
             }
             process_orders(deps, env)
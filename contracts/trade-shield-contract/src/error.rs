@@ -1,5 +1,6 @@
-use cosmwasm_std::{Addr, StdError};
+use cosmwasm_std::{Addr, Coin, Decimal, SignedDecimal, StdError};
 use cw_utils::PaymentError;
+use elys_bindings::ElysBindingsError;
 use thiserror::Error;
 
 use crate::types::Status;
@@ -8,6 +9,8 @@ use crate::types::Status;
 pub enum ContractError {
     #[error("{0}")]
     StdError(#[from] StdError),
+    #[error("{0}")]
+    ElysBindings(#[from] ElysBindingsError),
     #[error("Payment error: {0}")]
     Payment(#[from] PaymentError),
     #[error("{order_id} : Not Found")]
@@ -34,4 +37,34 @@ pub enum ContractError {
     CancelStatusError { order_id: u64, status: Status },
     #[error("{balance} is smaller than {amount}")]
     InsufficientBalanceError { balance: u128, amount: u64 },
+    #[error("{base_denom}/{quote_denom} is not an allowed trading pair")]
+    PairNotAllowed {
+        base_denom: String,
+        quote_denom: String,
+    },
+    #[error("order notional {notional} is smaller than the minimum {minimum}")]
+    OrderNotionalTooLow { notional: Decimal, minimum: Decimal },
+    #[error("{owner} already has {limit} pending orders")]
+    TooManyPendingOrders { owner: String, limit: u64 },
+    #[error("leverage of {trading_asset} cannot exceed {max_leverage}")]
+    LeverageAboveMax {
+        trading_asset: String,
+        max_leverage: SignedDecimal,
+    },
+    #[error("stop loss price {stop_loss} of a {position} position must be on the losing side of the entry price {entry}")]
+    StopLossPriceSide {
+        position: String,
+        stop_loss: Decimal,
+        entry: Decimal,
+    },
+    #[error("trade shield is paused, only cancellations are allowed")]
+    Paused,
+    #[error("{grantee} is not allowed to {permission} for {granter}")]
+    GrantMissing {
+        granter: String,
+        grantee: String,
+        permission: String,
+    },
+    #[error("{amount} exceeds the spend limit granted to {grantee}")]
+    SpendLimitExceeded { grantee: String, amount: Coin },
 }
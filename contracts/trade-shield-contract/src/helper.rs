@@ -1,53 +1,72 @@
 use std::str::FromStr;
 
 use cosmwasm_std::{
-    from_json, BankMsg, Decimal, OverflowError, OverflowOperation, QuerierWrapper, Response,
-    StdError, StdResult, Storage, SubMsgResult, Uint128,
+    from_json, Addr, BankMsg, Coin, Coins, Decimal, Order, OverflowError, OverflowOperation,
+    QuerierWrapper, SignedDecimal, StdError, StdResult, Storage, SubMsgResult, Timestamp, Uint128,
 };
+use elys_bindings::query_resp::Validator;
+use elys_bindings::trade_shield::events::OrderAttributes;
 use elys_bindings::trade_shield::states::{
-    NUMBER_OF_EXECUTED_ORDER, NUMBER_OF_PENDING_ORDER, PENDING_PERPETUAL_ORDER_V2,
-    PENDING_SPOT_ORDER, PERPETUAL_ORDER_V2, SORTED_PENDING_PERPETUAL_ORDER,
-    SORTED_PENDING_SPOT_ORDER, SPOT_ORDER,
+    DAILY_TOP_TRADERS, DAILY_USER_VOLUME, DISCOUNT_OVERRIDES, FEES_COLLECTED, GLOBAL_TRADING_STATS,
+    GRIDS, GRID_ORDERS, NUMBER_OF_EXECUTED_ORDER, NUMBER_OF_PENDING_ORDER, PAIR_TRADING_STATS,
+    PARAMS, PARAMS_ADMIN, PAUSED, PENDING_PERPETUAL_ORDER_V2, PENDING_SPOT_ORDER,
+    PERPETUAL_ORDER_V2, REFEREES, REFERRALS, REFERRAL_VOLUME, ROLES,
+    SORTED_PENDING_PERPETUAL_ORDER, SORTED_PENDING_SPOT_ORDER, SPOT_ORDER, SPOT_ORDER_MAX_ID,
+    TRADING_GRANTS, USER_PERPETUAL_ORDER, USER_SPOT_ORDER, USER_TRADING_STATS,
 };
-use elys_bindings::trade_shield::types::{PerpetualOrderType, PerpetualOrderV2, SpotOrder, Status};
-use elys_bindings::ElysMsg;
-use elys_bindings::{ElysQuerier, ElysQuery};
+use elys_bindings::trade_shield::types::{
+    CoinValue, Params, PerpetualOrderType, PerpetualOrderV2, Role, SpotOrder, Status,
+    TradingPermission, TradingStats, SECONDS_PER_DAY, USD_DENOM,
+};
+use elys_bindings::{ElysBindingsError, ElysMsg, ElysQuerier, ElysQuery};
 
 use serde::de::DeserializeOwned;
 
+use crate::ContractError;
+
+/// Number of traders ranked per day for GetTopTraders.
+pub const MAX_TOP_TRADERS: usize = 100;
+
+/// Parses the module response of a reply, the error is the reason of the failure.
 pub fn get_response_from_reply<T: DeserializeOwned>(
     module_resp: SubMsgResult,
-) -> Result<T, Response<ElysMsg>> {
+) -> Result<T, String> {
     let response = match module_resp.into_result() {
         Ok(response) => response,
-        Err(err) => return Err(Response::new().add_attribute("error", err)),
+        Err(err) => return Err(err),
     };
 
     let data = match response.data {
         Some(data) => data,
-        None => return Err(Response::new().add_attribute("error", "No Data")),
+        None => return Err("No Data".to_string()),
     };
 
     match from_json::<T>(&data) {
         Ok(resp) => Ok(resp),
-        Err(err) => Err(Response::new().add_attribute("error", err.to_string())),
+        Err(err) => Err(err.to_string()),
     }
 }
 
+/// Discount applied to the fees of a user: its active override when the admin
+/// set one, the discount of its tier otherwise.
 pub fn get_discount(
+    storage: &dyn Storage,
     querier: QuerierWrapper<'_, ElysQuery>,
+    now: Timestamp,
     user_address: String,
 ) -> StdResult<Decimal> {
+    if let Some(discount_override) = DISCOUNT_OVERRIDES.may_load(storage, &user_address)? {
+        if discount_override.is_active(now) {
+            return Ok(discount_override.discount);
+        }
+    }
+
     let querier = ElysQuerier::new(&querier);
-    let mut discount_str = match querier.tier_calculate_discount(user_address.clone()) {
+    let discount_str = match querier.tier_calculate_discount(user_address) {
         Ok(resp) => resp.discount,
         Err(_) => "0".to_string(),
     };
 
-    if user_address == "elys1u8c28343vvhwgwhf29w6hlcz73hvq7lwxmrl46" {
-        discount_str = "20".to_string()
-    }
-
     let val = Uint128::from_str(&discount_str)?;
     let discount_str = match Decimal::from_atomics(val, 2) {
         Ok(resp) => resp,
@@ -56,15 +75,467 @@ pub fn get_discount(
     Ok(discount_str)
 }
 
+/// Fails unless the sender is the params admin or was granted the role or the
+/// admin role.
+pub fn check_role(storage: &dyn Storage, role: Role, sender: &Addr) -> Result<(), ContractError> {
+    if PARAMS_ADMIN.load(storage)? == sender.as_str()
+        || ROLES.has(storage, (Role::Admin.as_str(), sender.as_str()))
+        || ROLES.has(storage, (role.as_str(), sender.as_str()))
+    {
+        return Ok(());
+    }
+    Err(ContractError::Unauthorized {
+        sender: sender.clone(),
+    })
+}
+
+/// Fails unless `grantee` holds an active grant of `permission` from `granter`,
+/// the funds sent with the message are debited from the spend limit of the grant.
+pub fn use_trading_grant(
+    storage: &mut dyn Storage,
+    now: Timestamp,
+    granter: &str,
+    grantee: &str,
+    permission: &TradingPermission,
+    funds: &[Coin],
+) -> Result<(), ContractError> {
+    let mut grant = match TRADING_GRANTS.may_load(storage, (granter, grantee))? {
+        Some(grant) if grant.is_active(now) && grant.allows(permission) => grant,
+        _ => {
+            return Err(ContractError::GrantMissing {
+                granter: granter.to_string(),
+                grantee: grantee.to_string(),
+                permission: permission.to_string(),
+            })
+        }
+    };
+
+    for fund in funds {
+        let limit = grant
+            .spend_limit
+            .iter_mut()
+            .find(|limit| limit.denom == fund.denom && limit.amount >= fund.amount);
+        match limit {
+            Some(limit) => limit.amount -= fund.amount,
+            None => {
+                return Err(ContractError::SpendLimitExceeded {
+                    grantee: grantee.to_string(),
+                    amount: fund.clone(),
+                })
+            }
+        }
+    }
+    TRADING_GRANTS.save(storage, (granter, grantee), &grant)?;
+
+    Ok(())
+}
+
+/// Fails while the contract is paused by the admin. Cancellations never call it,
+/// nor check the endpoint flags, so that escrowed funds can always be refunded.
+pub fn check_not_paused(storage: &dyn Storage) -> Result<(), ContractError> {
+    if PAUSED.may_load(storage)?.unwrap_or_default() {
+        return Err(ContractError::Paused);
+    }
+    Ok(())
+}
+
+/// Checks a new order against the allowed pairs, the minimum notional and the
+/// number of pending orders of its owner.
+pub fn check_order_params(
+    storage: &dyn Storage,
+    querier: &ElysQuerier<'_>,
+    params: &Params,
+    owner: &str,
+    amount: &Coin,
+    pair: (&str, &str),
+    pending: bool,
+) -> Result<(), ContractError> {
+    let (base_denom, quote_denom) = pair;
+    if !params.is_pair_allowed(base_denom, quote_denom) {
+        return Err(ContractError::PairNotAllowed {
+            base_denom: base_denom.to_string(),
+            quote_denom: quote_denom.to_string(),
+        });
+    }
+
+    if !params.min_order_notional.is_zero() {
+        let notional = CoinValue::from_coin(amount, querier)?.amount_usd;
+        if notional < params.min_order_notional {
+            return Err(ContractError::OrderNotionalTooLow {
+                notional,
+                minimum: params.min_order_notional,
+            });
+        }
+    }
+
+    // market orders execute right away and never count as pending
+    if let (true, Some(limit)) = (pending, params.max_pending_orders_per_user) {
+        let pending_spot = USER_SPOT_ORDER
+            .may_load(storage, owner)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| PENDING_SPOT_ORDER.has(storage, *id))
+            .count();
+        let pending_perpetual = USER_PERPETUAL_ORDER
+            .may_load(storage, owner)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|id| PENDING_PERPETUAL_ORDER_V2.has(storage, *id))
+            .count();
+        if (pending_spot + pending_perpetual) as u64 >= limit {
+            return Err(ContractError::TooManyPendingOrders {
+                owner: owner.to_string(),
+                limit,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Protocol fee withheld from an order amount once the tier discount of the
+/// owner is applied. Returns None when there is nothing to withhold.
+pub fn compute_fee(params: &Params, amount: &Coin, discount: Decimal) -> Option<Coin> {
+    if params.fee_rate.is_zero() {
+        return None;
+    }
+    let discount = discount.min(Decimal::one());
+    let fee = amount.amount * (params.fee_rate * (Decimal::one() - discount));
+    if fee.is_zero() {
+        return None;
+    }
+    Some(Coin {
+        denom: amount.denom.clone(),
+        amount: fee,
+    })
+}
+
+/// Credits the fee of an executed order. The referrer share is paid to the
+/// referrer of the owner right away, the rest stays in `FEES_COLLECTED`.
+pub fn collect_fee(
+    storage: &mut dyn Storage,
+    owner: &str,
+    fee: &Coin,
+) -> StdResult<Option<BankMsg>> {
+    let params = PARAMS.load(storage)?;
+    let mut bank_msg = None;
+    let mut protocol_fee = fee.amount;
+
+    if let Some(referrer) = REFERRALS.may_load(storage, owner)? {
+        let share = fee.amount * params.referrer_fee_share;
+        if !share.is_zero() {
+            protocol_fee -= share;
+            bank_msg = Some(BankMsg::Send {
+                to_address: referrer,
+                amount: vec![Coin {
+                    denom: fee.denom.clone(),
+                    amount: share,
+                }],
+            });
+        }
+    }
+
+    FEES_COLLECTED.update(storage, &fee.denom, |collected| -> StdResult<Uint128> {
+        Ok(collected.unwrap_or_default().checked_add(protocol_fee)?)
+    })?;
+
+    Ok(bank_msg)
+}
+
+/// Records an executed order in the trading stats of the day and credits its
+/// USD volume to the referrer of its owner.
+pub fn record_executed_order(
+    storage: &mut dyn Storage,
+    querier: &ElysQuerier<'_>,
+    time: Timestamp,
+    order: OrderAttributes,
+    slippage: Option<SignedDecimal>,
+) -> StdResult<()> {
+    // an amount without usd valuation is not counted rather than failing the order
+    let mut volume = Decimal::zero();
+    for amount in order.amounts.iter() {
+        if let Ok(value) = CoinValue::from_coin(amount, querier) {
+            volume = volume.checked_add(value.amount_usd)?;
+        }
+    }
+
+    let day = time.seconds() / SECONDS_PER_DAY;
+    update_trading_stats(storage, day, &order, |stats| {
+        stats.record_executed(&order.order_type, volume, slippage)
+    })?;
+    let total = DAILY_USER_VOLUME.update(
+        storage,
+        (day, &order.owner),
+        |total| -> StdResult<Decimal> { Ok(total.unwrap_or_default().checked_add(volume)?) },
+    )?;
+    update_top_traders(storage, day, &order.owner, total)?;
+
+    credit_referral_volume(storage, &order.owner, volume)
+}
+
+/// Moves `owner` to its place in the top traders of `day` with its new daily
+/// volume, dropping the last trader once the list is full.
+fn update_top_traders(
+    storage: &mut dyn Storage,
+    day: u64,
+    owner: &str,
+    volume: Decimal,
+) -> StdResult<()> {
+    let mut traders = DAILY_TOP_TRADERS
+        .may_load(storage, day)?
+        .unwrap_or_default();
+    traders.retain(|(address, _)| address != owner);
+    let index = traders.partition_point(|(_, trader_volume)| *trader_volume >= volume);
+    if index < MAX_TOP_TRADERS {
+        traders.insert(index, (owner.to_string(), volume));
+        traders.truncate(MAX_TOP_TRADERS);
+        DAILY_TOP_TRADERS.save(storage, day, &traders)?;
+    }
+    Ok(())
+}
+
+/// Records an executed perpetual order, its volume is the notional of the
+/// position rather than the posted collateral.
+pub fn record_executed_perpetual_order(
+    storage: &mut dyn Storage,
+    querier: &ElysQuerier<'_>,
+    time: Timestamp,
+    order: &PerpetualOrderV2,
+) -> StdResult<()> {
+    let mut attributes: OrderAttributes = order.into();
+    attributes.amounts = vec![order.notional()?];
+    record_executed_order(storage, querier, time, attributes, None)
+}
+
+/// Records an order that failed during its execution in the trading stats of the day.
+pub fn record_failed_order(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    order: OrderAttributes,
+) -> StdResult<()> {
+    let day = time.seconds() / SECONDS_PER_DAY;
+    update_trading_stats(storage, day, &order, |stats| {
+        stats.record_failed(&order.order_type);
+        Ok(())
+    })
+}
+
+/// Records an order removed from the book with the `Canceled` status in the
+/// trading stats of the day, whether cancelled by a user or by a failure.
+pub fn record_cancelled_order(
+    storage: &mut dyn Storage,
+    time: Timestamp,
+    order: OrderAttributes,
+) -> StdResult<()> {
+    let day = time.seconds() / SECONDS_PER_DAY;
+    update_trading_stats(storage, day, &order, |stats| {
+        stats.record_cancelled();
+        Ok(())
+    })
+}
+
+/// Amount left once the protocol fee withheld from it is removed.
+pub fn amount_after_fee(amount: &Coin, fee: &Option<Coin>) -> Coin {
+    match fee {
+        Some(fee) => Coin {
+            denom: amount.denom.clone(),
+            amount: amount.amount.saturating_sub(fee.amount),
+        },
+        None => amount.clone(),
+    }
+}
+
+/// Messages claiming every pending staking and liquidity mining reward of
+/// `address`, with the amounts reported by the estaking and masterchef modules.
+pub fn claim_rewards_msgs(
+    querier: &ElysQuerier<'_>,
+    address: &str,
+) -> StdResult<(Vec<ElysMsg>, Coins)> {
+    let mut claimed = Coins::default();
+
+    // estaking withdraw elys staking rewards
+    let mut msgs = vec![ElysMsg::estaking_withdraw_elys_staking_rewards(
+        address.to_string(),
+    )];
+
+    // estaking withdraw reward
+    let estaking_reward = querier.get_estaking_rewards(address.to_string())?;
+    for coin in estaking_reward.total.iter() {
+        claimed.add(coin.clone())?;
+    }
+
+    for validator in [Validator::Eden, Validator::EdenBoost] {
+        let validator_address = validator.to_string();
+        if !estaking_reward.get_validator_rewards(validator).rewards[0]
+            .reward
+            .is_empty()
+        {
+            msgs.push(ElysMsg::estaking_withdraw_reward(
+                address.to_string(),
+                validator_address,
+            ));
+        }
+    }
+
+    let master_chef_pending_rewards =
+        querier.get_masterchef_pending_rewards(address.to_string())?;
+
+    if !master_chef_pending_rewards.total_rewards.is_empty() {
+        let pools_ids_to_claim: Vec<u64> = master_chef_pending_rewards
+            .rewards
+            .iter()
+            .filter_map(|reward| {
+                if reward.reward.is_empty() {
+                    None
+                } else {
+                    Some(reward.pool_id)
+                }
+            })
+            .collect();
+
+        msgs.push(ElysMsg::get_masterchef_claim_rewards(
+            address.to_string(),
+            pools_ids_to_claim,
+        ));
+        for coin in master_chef_pending_rewards.total_rewards {
+            claimed.add(coin)?;
+        }
+    }
+
+    // the leveragelp module does not report the rewards of a claim
+    let ids = querier.leveragelp_pool_position_ids_for_address(address.to_string())?;
+    if !ids.is_empty() {
+        msgs.push(ElysMsg::leveragelp_withdraw_reward(
+            address.to_string(),
+            ids,
+        ));
+    }
+
+    Ok((msgs, claimed))
+}
+
+/// Slippage of a swap, measured from the usd value of what was sent and received.
+pub fn swap_slippage(
+    querier: &ElysQuerier<'_>,
+    token_in: &Coin,
+    token_out: &Coin,
+) -> Option<SignedDecimal> {
+    let value_in = CoinValue::from_coin(token_in, querier).ok()?.amount_usd;
+    let value_out = CoinValue::from_coin(token_out, querier).ok()?.amount_usd;
+    if value_in.is_zero() {
+        return None;
+    }
+    if value_out <= value_in {
+        SignedDecimal::try_from((value_in - value_out) / value_in).ok()
+    } else {
+        SignedDecimal::try_from((value_out - value_in) / value_in)
+            .ok()
+            .map(|slippage| -slippage)
+    }
+}
+
+fn update_trading_stats<F>(
+    storage: &mut dyn Storage,
+    day: u64,
+    order: &OrderAttributes,
+    update: F,
+) -> StdResult<()>
+where
+    F: Fn(&mut TradingStats) -> StdResult<()>,
+{
+    let mut stats = USER_TRADING_STATS
+        .may_load(storage, (&order.owner, day))?
+        .unwrap_or_default();
+    update(&mut stats)?;
+    USER_TRADING_STATS.save(storage, (&order.owner, day), &stats)?;
+
+    let mut stats = PAIR_TRADING_STATS
+        .may_load(storage, (&order.pair, day))?
+        .unwrap_or_default();
+    update(&mut stats)?;
+    PAIR_TRADING_STATS.save(storage, (&order.pair, day), &stats)?;
+
+    let mut stats = GLOBAL_TRADING_STATS
+        .may_load(storage, day)?
+        .unwrap_or_default();
+    update(&mut stats)?;
+    GLOBAL_TRADING_STATS.save(storage, day, &stats)
+}
+
+fn credit_referral_volume(
+    storage: &mut dyn Storage,
+    owner: &str,
+    volume: Decimal,
+) -> StdResult<()> {
+    let referrer = match REFERRALS.may_load(storage, owner)? {
+        Some(referrer) => referrer,
+        None => return Ok(()),
+    };
+
+    REFEREES.update(storage, (&referrer, owner), |total| -> StdResult<Decimal> {
+        Ok(total.unwrap_or_default().checked_add(volume)?)
+    })?;
+    REFERRAL_VOLUME.update(storage, &referrer, |total| -> StdResult<Decimal> {
+        Ok(total.unwrap_or_default().checked_add(volume)?)
+    })?;
+
+    Ok(())
+}
+
+/// Reserves the id of a new spot order.
+pub fn next_spot_order_id(storage: &mut dyn Storage) -> StdResult<u64> {
+    let order_id = match SPOT_ORDER_MAX_ID.load(storage)?.checked_add(1) {
+        Some(id) => id,
+        None => {
+            return Err(StdError::overflow(OverflowError::new(
+                OverflowOperation::Add,
+                "spot_order_max_id",
+                "increment one",
+            )))
+        }
+    };
+    SPOT_ORDER_MAX_ID.save(storage, &order_id)?;
+    Ok(order_id)
+}
+
+/// Stores a new pending spot order and inserts it in the sorted pending orders
+/// of its pair.
+pub fn insert_pending_spot_order(storage: &mut dyn Storage, order: &SpotOrder) -> StdResult<()> {
+    SPOT_ORDER.save(storage, order.order_id, order)?;
+    PENDING_SPOT_ORDER.save(storage, order.order_id, order)?;
+
+    let key = order.gen_key()?;
+    let mut vec = SORTED_PENDING_SPOT_ORDER
+        .may_load(storage, key.as_str())?
+        .unwrap_or(vec![]);
+    let index = SpotOrder::binary_search(&order.trigger().rate, storage, &vec)?;
+    if vec.len() <= index {
+        vec.push(order.order_id)
+    } else {
+        vec.insert(index, order.order_id);
+    }
+    SORTED_PENDING_SPOT_ORDER.save(storage, key.as_str(), &vec)?;
+
+    let mut ids = USER_SPOT_ORDER
+        .may_load(storage, order.owner_address.as_str())?
+        .unwrap_or(vec![]);
+    ids.push(order.order_id);
+    USER_SPOT_ORDER.save(storage, order.owner_address.as_str(), &ids)?;
+
+    let number_of_pending_order = NUMBER_OF_PENDING_ORDER.load(storage)? + 1;
+    NUMBER_OF_PENDING_ORDER.save(storage, &number_of_pending_order)
+}
+
 pub fn remove_spot_order(
     order_id: u64,
     new_status: Status,
     storage: &mut dyn Storage,
+    time: Timestamp,
 ) -> StdResult<Option<BankMsg>> {
     let mut order = PENDING_SPOT_ORDER.load(storage, order_id)?;
     let key = order.gen_key()?;
     let mut vec: Vec<u64> = SORTED_PENDING_SPOT_ORDER.load(storage, key.as_str())?;
-    let mut index = SpotOrder::binary_search(&order.order_price.rate, storage, &vec)?;
+    let mut index = SpotOrder::binary_search(&order.trigger().rate, storage, &vec)?;
     let size_of_vec = vec.len();
     while vec[index] != order_id && index < size_of_vec {
         index += 1;
@@ -78,6 +549,15 @@ pub fn remove_spot_order(
     SPOT_ORDER.save(storage, order.order_id, &order)?;
     PENDING_SPOT_ORDER.remove(storage, order.order_id);
     change_the_number_of_order(storage, &order.status)?;
+    if let Some(grid_level) = GRID_ORDERS.may_load(storage, order.order_id)? {
+        GRID_ORDERS.remove(storage, order.order_id);
+        let mut grid = GRIDS.load(storage, grid_level.grid_id)?;
+        grid.order_ids.retain(|id| *id != order.order_id);
+        GRIDS.save(storage, grid.grid_id, &grid)?;
+    }
+    if order.status == Status::Canceled {
+        record_cancelled_order(storage, time, (&order).into())?;
+    }
     let bank_msg = if order.status == Status::Canceled {
         Some(BankMsg::Send {
             to_address: order.owner_address.to_string(),
@@ -93,6 +573,7 @@ pub fn remove_perpetual_order(
     order_id: u64,
     new_status: Status,
     storage: &mut dyn Storage,
+    time: Timestamp,
     position_id: Option<u64>,
 ) -> StdResult<Option<BankMsg>> {
     let mut order = PENDING_PERPETUAL_ORDER_V2.load(storage, order_id).unwrap();
@@ -115,6 +596,9 @@ pub fn remove_perpetual_order(
     PERPETUAL_ORDER_V2.save(storage, order.order_id, &order)?;
     PENDING_PERPETUAL_ORDER_V2.remove(storage, order.order_id);
     change_the_number_of_order(storage, &order.status)?;
+    if order.status == Status::Canceled {
+        record_cancelled_order(storage, time, (&order).into())?;
+    }
     let bank_msg =
         if order.status == Status::Canceled && order.order_type == PerpetualOrderType::LimitOpen {
             Some(BankMsg::Send {
@@ -127,6 +611,123 @@ pub fn remove_perpetual_order(
     Ok(bank_msg)
 }
 
+/// Registers the pending `StopLoss` order attached to an open order once the
+/// position id is known. Returns the created order, if any.
+pub fn create_stop_loss_order(
+    storage: &mut dyn Storage,
+    open_order_id: u64,
+    position_id: u64,
+) -> StdResult<Option<PerpetualOrderV2>> {
+    let open_order = PERPETUAL_ORDER_V2.load(storage, open_order_id)?;
+
+    if open_order.stop_loss_price.is_none() {
+        return Ok(None);
+    }
+
+    let orders: Vec<PerpetualOrderV2> = PERPETUAL_ORDER_V2
+        .prefix_range(storage, None, None, Order::Ascending)
+        .filter_map(|res| res.ok().map(|r| r.1))
+        .collect();
+
+    let order = PerpetualOrderV2::new_close(
+        &open_order.owner,
+        open_order.position.clone() as i32,
+        &PerpetualOrderType::StopLoss,
+        &open_order.collateral,
+        &open_order.trading_asset,
+        &open_order.leverage,
+        position_id,
+        &open_order.stop_loss_price,
+        &open_order.take_profit_price,
+        &orders,
+    )?;
+
+    PERPETUAL_ORDER_V2.save(storage, order.order_id, &order)?;
+    PENDING_PERPETUAL_ORDER_V2.save(storage, order.order_id, &order)?;
+
+    let mut ids = USER_PERPETUAL_ORDER
+        .may_load(storage, order.owner.as_str())?
+        .unwrap_or(vec![]);
+    ids.push(order.order_id);
+    USER_PERPETUAL_ORDER.save(storage, order.owner.as_str(), &ids)?;
+
+    let key = order.gen_key()?;
+    let mut vec = SORTED_PENDING_PERPETUAL_ORDER
+        .may_load(storage, key.as_str())?
+        .unwrap_or(vec![]);
+    let index = PerpetualOrderV2::binary_search(&order.trigger_price, storage, &vec)?;
+    if vec.len() <= index {
+        vec.push(order.order_id)
+    } else {
+        vec.insert(index, order.order_id);
+    }
+    SORTED_PENDING_PERPETUAL_ORDER.save(storage, key.as_str(), &vec)?;
+
+    let number_of_pending_order = match NUMBER_OF_PENDING_ORDER.load(storage)?.checked_add(1) {
+        Some(number) => Ok(number),
+        None => Err(StdError::overflow(OverflowError::new(
+            OverflowOperation::Add,
+            "number_of_pending_order",
+            1,
+        ))),
+    }?;
+    NUMBER_OF_PENDING_ORDER.save(storage, &number_of_pending_order)?;
+
+    Ok(Some(order))
+}
+
+/// Moves the pending orders of `order_type` linked to a position to a new
+/// trigger rate, keeping the sorted index in order. Returns the updated orders.
+pub fn reprice_perpetual_orders(
+    storage: &mut dyn Storage,
+    owner: &str,
+    position_id: u64,
+    order_type: PerpetualOrderType,
+    rate: Decimal,
+) -> StdResult<Vec<PerpetualOrderV2>> {
+    let ids = USER_PERPETUAL_ORDER
+        .may_load(storage, owner)?
+        .unwrap_or(vec![]);
+    let mut updated_orders = vec![];
+
+    for id in ids {
+        let mut order = match PENDING_PERPETUAL_ORDER_V2.may_load(storage, id)? {
+            Some(order) => order,
+            None => continue,
+        };
+        if order.position_id != Some(position_id) || order.order_type != order_type {
+            continue;
+        }
+        let mut price = match order.trigger_price.clone() {
+            Some(price) => price,
+            None => continue,
+        };
+
+        let key = order.gen_key()?;
+        let mut vec = SORTED_PENDING_PERPETUAL_ORDER
+            .may_load(storage, key.as_str())?
+            .unwrap_or(vec![]);
+        vec.retain(|order_id| *order_id != id);
+
+        price.rate = rate;
+        order.trigger_price = Some(price);
+        PERPETUAL_ORDER_V2.save(storage, id, &order)?;
+        PENDING_PERPETUAL_ORDER_V2.save(storage, id, &order)?;
+
+        let index = PerpetualOrderV2::binary_search(&order.trigger_price, storage, &vec)?;
+        if vec.len() <= index {
+            vec.push(id)
+        } else {
+            vec.insert(index, id);
+        }
+        SORTED_PENDING_PERPETUAL_ORDER.save(storage, key.as_str(), &vec)?;
+
+        updated_orders.push(order);
+    }
+
+    Ok(updated_orders)
+}
+
 fn change_the_number_of_order(storage: &mut dyn Storage, status: &Status) -> StdResult<()> {
     let number_of_pending_order = match NUMBER_OF_PENDING_ORDER.load(storage)?.checked_sub(1) {
         Some(number) => Ok(number),
@@ -151,3 +752,20 @@ fn change_the_number_of_order(storage: &mut dyn Storage, status: &Status) -> Std
     };
     Ok(())
 }
+
+/// Oracle price of `base_denom` in `quote_denom`, either of them can be `USD_DENOM`.
+pub fn get_trigger_market_price(
+    querier: &ElysQuerier<'_>,
+    base_denom: &str,
+    quote_denom: &str,
+) -> Result<Decimal, ElysBindingsError> {
+    let usd_price = |denom: &str| -> Result<Decimal, ElysBindingsError> {
+        if denom == USD_DENOM {
+            Ok(Decimal::one())
+        } else {
+            querier.get_asset_price(denom)
+        }
+    };
+
+    Ok(usd_price(base_denom)?.checked_div(usd_price(quote_denom)?)?)
+}
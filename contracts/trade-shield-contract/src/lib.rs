@@ -10,6 +10,7 @@ mod action;
 mod error;
 mod helper;
 
+use elys_bindings::trade_shield::events;
 use elys_bindings::trade_shield::states;
 
 #[cfg(test)]
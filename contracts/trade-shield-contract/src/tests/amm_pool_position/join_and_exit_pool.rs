@@ -0,0 +1,181 @@
+use crate::msg::query_resp::GetUserPoolPositionsResp;
+use cosmwasm_std::SignedDecimal;
+
+use super::*;
+
+const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+// This test case verifies that joining a pool records the deposit composition and its USD value,
+// that the impermanent loss follows the price of the deposited assets and that exiting the pool
+// reduces the position proportionally.
+#[test]
+fn join_and_exit_pool() {
+    let wallet = vec![(
+        "user",
+        vec![coin(1_000_000, "uelys"), coin(3_000_000, USDC)],
+    )];
+
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    app.init_modules(|router, _, store| {
+        router.custom.set_prices(
+            store,
+            &vec![
+                Price::new("uelys", Decimal::from_str("3.0").unwrap()),
+                Price::new(USDC, Decimal::one()),
+            ],
+        )
+    })
+    .unwrap();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // The user deposits 3 USD worth of ELYS and 3 USD worth of USDC for 6 shares.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::AmmJoinPoolRequest {
+            pool_id: 1,
+            max_amounts_in: vec![coin(1_000_000, "uelys"), coin(3_000_000, "uusdc")],
+            share_amount_out: Uint128::new(6_000_000_000_000_000_000),
+            no_remaining: false,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let resp: GetUserPoolPositionsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetUserPoolPositions {
+                user_address: "user".to_string(),
+            },
+        )
+        .unwrap();
+
+    assert_eq!(resp.positions.len(), 1);
+    let position = &resp.positions[0];
+    assert_eq!(position.position.pool_id, 1);
+    assert_eq!(
+        position.position.token_in,
+        vec![coin(1_000_000, "uelys"), coin(3_000_000, USDC)]
+    );
+    assert_eq!(
+        position.position.deposit_usd_value,
+        Decimal::from_str("6").unwrap()
+    );
+    assert_eq!(position.current_usd_value, Decimal::from_str("6").unwrap());
+    assert_eq!(position.hold_usd_value, Decimal::from_str("6").unwrap());
+    assert_eq!(position.impermanent_loss, SignedDecimal::zero());
+
+    // ELYS doubles in price, holding would now be worth 9 USD while the shares are still worth 6 USD.
+    app.init_modules(|router, _, store| {
+        router.custom.set_prices(
+            store,
+            &vec![
+                Price::new("uelys", Decimal::from_str("6.0").unwrap()),
+                Price::new(USDC, Decimal::one()),
+            ],
+        )
+    })
+    .unwrap();
+
+    let resp: GetUserPoolPositionsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetUserPoolPositions {
+                user_address: "user".to_string(),
+            },
+        )
+        .unwrap();
+
+    let position = &resp.positions[0];
+    assert_eq!(position.hold_usd_value, Decimal::from_str("9").unwrap());
+    assert_eq!(
+        position.impermanent_loss,
+        SignedDecimal::from_str("-3").unwrap()
+    );
+
+    // The user exits half of the shares.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::AmmExitPoolRequest {
+            pool_id: 1,
+            min_amounts_out: vec![],
+            share_amount_in: Uint128::new(3_000_000_000_000_000_000),
+            token_out_denom: "uusdc".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let resp: GetUserPoolPositionsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetUserPoolPositions {
+                user_address: "user".to_string(),
+            },
+        )
+        .unwrap();
+
+    let position = &resp.positions[0];
+    assert_eq!(
+        position.position.shares,
+        Uint128::new(3_000_000_000_000_000_000)
+    );
+    assert_eq!(
+        position.position.token_in,
+        vec![coin(500_000, "uelys"), coin(1_500_000, USDC)]
+    );
+    assert_eq!(
+        position.position.deposit_usd_value,
+        Decimal::from_str("3").unwrap()
+    );
+
+    // Exiting the remaining shares removes the position.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::AmmExitPoolRequest {
+            pool_id: 1,
+            min_amounts_out: vec![],
+            share_amount_in: Uint128::new(3_000_000_000_000_000_000),
+            token_out_denom: "uusdc".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let resp: GetUserPoolPositionsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetUserPoolPositions {
+                user_address: "user".to_string(),
+            },
+        )
+        .unwrap();
+
+    assert!(resp.positions.is_empty());
+}
@@ -0,0 +1,156 @@
+use cosmwasm_std::Coin;
+use elys_bindings::query_resp::{
+    DelegationDelegatorReward, MasterchefUserPendingRewardData, Validator,
+};
+
+use super::*;
+
+const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+fn get_auto_compound(app: &ElysApp, addr: &Addr) -> AutoCompound {
+    let resp: GetAutoCompoundResp = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::GetAutoCompound {
+                owner: "user".to_string(),
+            },
+        )
+        .unwrap();
+    resp.subscription.unwrap()
+}
+
+fn set_masterchef_rewards(app: &mut ElysApp, reward: Coin) {
+    app.init_modules(|router, _, store| {
+        router.custom.set_masterchef_rewards(
+            store,
+            "user",
+            vec![MasterchefUserPendingRewardData {
+                pool_id: 1,
+                reward: vec![reward],
+            }],
+        )
+    })
+    .unwrap();
+}
+
+// This test case verifies that the auto-compound subscriptions are run from the
+// end blocker once due, and that a failing run is recorded without failing the block.
+// - "user" subscribes to stake its Eden and swap its USDC to BTC every hour.
+// - After an hour, 500 Eden and 40000 USDC are claimed, the Eden is staked and
+//   the USDC is swapped to 2 BTC.
+// - An hour later, the 30000 USDC claimed can't be swapped within the slippage,
+//   the run is reverted and its error recorded.
+#[test]
+fn clock_end_block_runs() {
+    let mut app = ElysApp::new_with_wallets(vec![("user", vec![])]);
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("20000").unwrap()),
+        Price::new(USDC, Decimal::one()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::SubscribeAutoCompound {
+            policy: CompoundPolicy {
+                eden: EdenPolicy::Stake,
+                usdc: UsdcPolicy::Swap {
+                    denom_out: "btc".to_string(),
+                    max_slippage: Decimal::percent(1),
+                },
+            },
+            interval: 3600,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.init_modules(|router, _, store| {
+        router.custom.set_estaking_rewards(
+            store,
+            "user",
+            vec![DelegationDelegatorReward {
+                validator_address: Validator::Eden.to_string(),
+                reward: vec![coin(500, "ueden")],
+            }],
+        )
+    })
+    .unwrap();
+    set_masterchef_rewards(&mut app, coin(40000, USDC));
+
+    // Only the owner and the keepers can run a subscription.
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        addr.clone(),
+        &ExecuteMsg::RunAutoCompound {
+            owner: "user".to_string(),
+        },
+        &[],
+    )
+    .unwrap_err();
+
+    // The subscription is not due yet.
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+    assert_eq!(get_auto_compound(&app, &addr).runs, 0);
+
+    app.advance_seconds(3600);
+    let now = app.block_info().time;
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let subscription = get_auto_compound(&app, &addr);
+    assert_eq!(subscription.runs, 1);
+    assert_eq!(subscription.next_run, now.plus_seconds(3600));
+    assert_eq!(
+        subscription.last_run,
+        Some(CompoundRun {
+            time: now,
+            claimed: vec![coin(40000, USDC), coin(500, "ueden")],
+            compounded: vec![coin(500, "ueden"), coin(40000, USDC)],
+            error: None,
+        })
+    );
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        vec![coin(2, "btc"), coin(500, "ueden")]
+    );
+
+    // 30000 USDC are worth 1.5 BTC but only 1 BTC comes out of the swap.
+    set_masterchef_rewards(&mut app, coin(30000, USDC));
+    app.advance_seconds(3600);
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let subscription = get_auto_compound(&app, &addr);
+    assert_eq!(subscription.runs, 2);
+    let last_run = subscription.last_run.unwrap();
+    assert!(last_run.claimed.is_empty());
+    assert!(last_run.error.is_some());
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        vec![coin(2, "btc"), coin(500, "ueden")]
+    );
+}
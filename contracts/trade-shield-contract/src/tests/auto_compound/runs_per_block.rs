@@ -0,0 +1,83 @@
+use super::*;
+
+// This test case verifies that each block visits a bounded number of
+// subscriptions and that the next block resumes after the last one visited.
+// - Eleven users subscribe every hour, one more than a block visits.
+// - After an hour, the first block runs the first ten subscriptions.
+// - The next block runs the eleventh one, the others are no longer due.
+#[test]
+fn runs_per_block() {
+    let owners: Vec<String> = (0..11).map(|i| format!("user{:02}", i)).collect();
+    let mut app = ElysApp::new_with_wallets(
+        owners
+            .iter()
+            .map(|owner| (owner.as_str(), vec![]))
+            .collect(),
+    );
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    for owner in owners.iter() {
+        app.execute_contract(
+            Addr::unchecked(owner),
+            addr.clone(),
+            &ExecuteMsg::SubscribeAutoCompound {
+                policy: CompoundPolicy {
+                    eden: EdenPolicy::Keep,
+                    usdc: UsdcPolicy::Keep,
+                },
+                interval: 3600,
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let runs = |app: &ElysApp| -> Vec<u64> {
+        owners
+            .iter()
+            .map(|owner| {
+                let resp: GetAutoCompoundResp = app
+                    .wrap()
+                    .query_wasm_smart(
+                        &addr,
+                        &QueryMsg::GetAutoCompound {
+                            owner: owner.clone(),
+                        },
+                    )
+                    .unwrap();
+                resp.subscription.unwrap().runs
+            })
+            .collect()
+    };
+
+    app.advance_seconds(3600);
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let mut expected = vec![1; 10];
+    expected.push(0);
+    assert_eq!(runs(&app), expected);
+
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    assert_eq!(runs(&app), vec![1; 11]);
+}
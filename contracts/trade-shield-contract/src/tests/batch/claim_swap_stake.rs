@@ -0,0 +1,146 @@
+use cosmwasm_std::Coin;
+use elys_bindings::query_resp::MasterchefUserPendingRewardData;
+
+use super::*;
+
+const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+fn set_masterchef_rewards(app: &mut ElysApp, reward: Coin) {
+    app.init_modules(|router, _, store| {
+        router.custom.set_masterchef_rewards(
+            store,
+            "user",
+            vec![MasterchefUserPendingRewardData {
+                pool_id: 1,
+                reward: vec![reward],
+            }],
+        )
+    })
+    .unwrap();
+}
+
+fn claim_swap_stake_msg(min_amount_out: u128) -> ExecuteMsg {
+    ExecuteMsg::Batch {
+        actions: vec![
+            BatchAction::ClaimRewards {},
+            BatchAction::Swap {
+                denom_in: USDC.to_string(),
+                amount: BatchAmount::Output { step: 0 },
+                denom_out: "uelys".to_string(),
+                min_amount_out: Uint128::new(min_amount_out),
+            },
+            BatchAction::Stake {
+                asset: "uelys".to_string(),
+                amount: BatchAmount::Output { step: 1 },
+                validator_address: Some("validator".to_string()),
+            },
+        ],
+    }
+}
+
+// This test case verifies that the steps of a batch spend the outputs of the
+// steps before them and that a failing step reverts the whole batch.
+// - "user" claims 40000 USDC of rewards, swaps them to 20000 ELYS and stakes them.
+// - The same batch with 30000 USDC of rewards fails on the swap minimum and
+//   nothing is claimed.
+// - A step can't spend the output of a later step.
+#[test]
+fn claim_swap_stake() {
+    let mut app = ElysApp::new_with_wallets(vec![("user", vec![])]);
+    let prices = vec![
+        Price::new("uelys", Decimal::from_str("2").unwrap()),
+        Price::new(USDC, Decimal::one()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    set_masterchef_rewards(&mut app, coin(40000, USDC));
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &claim_swap_stake_msg(1),
+            &[],
+        )
+        .unwrap();
+
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        vec![coin(20000, "uelys")]
+    );
+    assert_eq!(
+        app.init_modules(|router, _, store| router.custom.get_last_module(store))
+            .unwrap(),
+        Some("Commitment".to_string())
+    );
+    assert!(resp.has_event(
+        &Event::new(format!("wasm-{}", events::BATCH_EXECUTED))
+            .add_attribute(events::STEPS_KEY, "3")
+            .add_attribute(events::OUTPUTS_KEY, format!("40000{};20000uelys;", USDC))
+    ));
+
+    // 30000 USDC only buy 15000 ELYS, the claim is reverted with the swap.
+    set_masterchef_rewards(&mut app, coin(30000, USDC));
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &claim_swap_stake_msg(20000),
+        &[],
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        vec![coin(20000, "uelys")]
+    );
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::Batch {
+            actions: vec![
+                BatchAction::Stake {
+                    asset: "uelys".to_string(),
+                    amount: BatchAmount::Output { step: 1 },
+                    validator_address: Some("validator".to_string()),
+                },
+                BatchAction::ClaimRewards {},
+            ],
+        },
+        &[],
+    )
+    .unwrap_err();
+
+    // The rewards are still pending after the failed batch.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::Batch {
+            actions: vec![BatchAction::ClaimRewards {}],
+        },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        vec![coin(30000, USDC), coin(20000, "uelys")]
+    );
+}
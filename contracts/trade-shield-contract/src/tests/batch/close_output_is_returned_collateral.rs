@@ -0,0 +1,107 @@
+use cosmwasm_std::{Int128, SignedDecimal, SignedDecimal256};
+
+use super::*;
+
+fn mtp() -> Mtp {
+    Mtp {
+        address: "user".to_string(),
+        amm_pool_id: 1,
+        borrow_interest_paid_collateral: Int128::zero(),
+        borrow_interest_paid_custody: Int128::zero(),
+        borrow_interest_unpaid_collateral: Int128::zero(),
+        collateral_asset: "uusdc".to_string(),
+        collateral: Int128::new(1000000),
+        consolidate_leverage: SignedDecimal::zero(),
+        custody: Int128::new(5000000),
+        custody_asset: "uusdc".to_string(),
+        funding_fee_paid_collateral: Int128::zero(),
+        funding_fee_paid_custody: Int128::zero(),
+        funding_fee_received_collateral: Int128::zero(),
+        funding_fee_received_custody: Int128::zero(),
+        id: 2,
+        leverage: SignedDecimal::from_str("5.0").unwrap(),
+        liabilities: Int128::zero(),
+        liabilities_asset: "uusdc".to_string(),
+        mtp_health: SignedDecimal::one(),
+        open_price: SignedDecimal::zero(),
+        position: 1,
+        sum_collateral: Int128::zero(),
+        take_profit_borrow_rate: SignedDecimal::zero(),
+        take_profit_custody: Int128::zero(),
+        take_profit_liabilities: Int128::zero(),
+        take_profit_price: SignedDecimal256::from_str("30").unwrap(),
+        trading_asset: "uatom".to_string(),
+        stop_loss_price: SignedDecimal::zero(),
+        last_interest_calc_time: None,
+        last_interest_calc_block: None,
+        last_funding_calc_time: None,
+        last_funding_calc_block: None,
+    }
+}
+
+fn close_msg(then_stake: bool) -> ExecuteMsg {
+    let mut actions = vec![BatchAction::ClosePerpetualPosition {
+        id: 2,
+        amount: Int128::new(5000000),
+    }];
+    if then_stake {
+        actions.push(BatchAction::Stake {
+            asset: "uusdc".to_string(),
+            amount: BatchAmount::Output { step: 0 },
+            validator_address: None,
+        });
+    }
+    ExecuteMsg::Batch { actions }
+}
+
+// This test case verifies that the output of a position close is the collateral
+// returned to the owner and not the custody reported by the perpetual module.
+// - The mock perpetual module closes the 5000000 uusdc custody without paying
+//   anything back, the 300 uusdc already held by "user" are not an output.
+// - Staking the output of the close fails as there is nothing to spend.
+// - The close alone outputs 0 uusdc.
+#[test]
+fn close_output_is_returned_collateral() {
+    let mut app = ElysApp::new_with_wallets(vec![("user", coins(300, "uusdc"))]);
+    app.init_modules(|router, _, store| router.custom.set_mtp(store, &vec![mtp()]))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let err = app
+        .execute_contract(Addr::unchecked("user"), addr.clone(), &close_msg(true), &[])
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Generic error: step 1: nothing to spend in uusdc"
+    );
+
+    let resp = app
+        .execute_contract(Addr::unchecked("user"), addr, &close_msg(false), &[])
+        .unwrap();
+
+    assert!(resp.has_event(
+        &Event::new(format!("wasm-{}", events::BATCH_EXECUTED))
+            .add_attribute(events::STEPS_KEY, "1")
+            .add_attribute(events::OUTPUTS_KEY, "0uusdc")
+    ));
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        coins(300, "uusdc")
+    );
+}
@@ -24,6 +24,7 @@ fn succesful_cancel_an_order() {
                 quote_denom: "usdc".to_string(),
                 rate: Decimal::from_str("20000.0").unwrap(),
             }),
+            &None,
             &vec![],
             DecCoin::new(Decimal256::zero(), ""),
             SignedDecimal::zero(),
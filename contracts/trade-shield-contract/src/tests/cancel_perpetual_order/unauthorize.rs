@@ -23,6 +23,7 @@ fn unauthorize() {
                 quote_denom: "usdc".to_string(),
                 rate: Decimal::from_str("20000.0").unwrap(),
             }),
+            &None,
             &vec![],
             DecCoin::new(Decimal256::zero(), ""),
             SignedDecimal::zero(),
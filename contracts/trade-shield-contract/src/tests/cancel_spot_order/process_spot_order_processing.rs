@@ -26,6 +26,8 @@ fn process_spot_order_processing() {
         order_amount: coin(120, "usdc"), // 120 USDC to be used for buying,
         owner_address: Addr::unchecked("user"),
         order_target_denom: "ubtc".to_string(),
+        routes: None,
+        trigger_price: None,
         status: Status::Executed,
         date: Date {
             height: 20,
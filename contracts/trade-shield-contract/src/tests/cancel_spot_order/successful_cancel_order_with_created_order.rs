@@ -53,6 +53,8 @@ fn successful_cancel_order_with_created_order() {
                 }),
                 order_source_denom: "eth".to_owned(),
                 order_target_denom: "btc".to_string(),
+                routes: None,
+                trigger_price: None,
             },
             &coins(45, "eth"),
         )
@@ -22,6 +22,8 @@ fn successfully_cancel_orders_ids() {
             order_amount: coin(10, "btc"),
             owner_address: Addr::unchecked("user"),
             order_target_denom: "".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -39,6 +41,8 @@ fn successfully_cancel_orders_ids() {
             order_amount: coin(5, "eth"),
             owner_address: Addr::unchecked("user"),
             order_target_denom: "".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -56,6 +60,8 @@ fn successfully_cancel_orders_ids() {
             order_amount: coin(20, "usdt"),
             owner_address: Addr::unchecked("user1"),
             order_target_denom: "".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -73,6 +79,8 @@ fn successfully_cancel_orders_ids() {
             order_amount: coin(6, "btc"),
             owner_address: Addr::unchecked("user"),
             order_target_denom: "".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -20,6 +20,8 @@ fn unauthorize() {
             order_amount: coin(10_000000, "uelys"),
             owner_address: Addr::unchecked("user"),
             order_target_denom: "uusdc".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 5,
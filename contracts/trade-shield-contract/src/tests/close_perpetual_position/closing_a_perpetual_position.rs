@@ -1,6 +1,9 @@
 use cosmwasm_std::{Int128, SignedDecimal, SignedDecimal256};
 use elys_bindings::{query_resp::PerpetualGetPositionsForAddressResponse, ElysQuery};
 
+use crate::events;
+use crate::tests::get_order_id_from_events::{get_attr_from_events, get_events_of_type};
+
 use super::*;
 
 #[test]
@@ -67,16 +70,28 @@ fn closing_perpetualg_position() {
         )
         .unwrap();
 
-    app.execute_contract(
-        Addr::unchecked("user"),
-        addr,
-        &ExecuteMsg::ClosePerpetualPosition {
-            id: 2,
-            amount: Int128::new(5000000),
-        },
-        &[],
-    )
-    .unwrap();
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr,
+            &ExecuteMsg::ClosePerpetualPosition {
+                id: 2,
+                amount: Int128::new(5000000),
+            },
+            &[],
+        )
+        .unwrap();
+
+    let closed = get_events_of_type(&resp.events, events::POSITION_CLOSED);
+    assert_eq!(closed.len(), 1);
+    assert_eq!(
+        get_attr_from_events(&resp.events, "pair"),
+        Some("uusdc/uatom".to_string())
+    );
+    assert_eq!(
+        get_attr_from_events(&resp.events, "amounts"),
+        Some("5000000uusdc".to_string())
+    );
 
     let r: PerpetualGetPositionsForAddressResponse = app
         .wrap()
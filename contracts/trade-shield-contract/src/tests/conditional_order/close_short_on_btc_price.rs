@@ -0,0 +1,236 @@
+use cosmwasm_std::{Int128, SignedDecimal, SignedDecimal256};
+use elys_bindings::{query_resp::PerpetualGetPositionsForAddressResponse, ElysQuery};
+
+use super::*;
+
+const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+fn get_conditional_orders(app: &ElysApp, addr: &Addr) -> Vec<ConditionalOrder> {
+    let resp: GetConditionalOrdersResp = app
+        .wrap()
+        .query_wasm_smart(
+            addr,
+            &QueryMsg::GetConditionalOrders {
+                owner: "user".to_string(),
+                pagination: None,
+            },
+        )
+        .unwrap();
+    resp.orders
+}
+
+fn set_btc_price(app: &mut ElysApp, price: &str) {
+    let prices = vec![
+        Price::new("btc", Decimal::from_str(price).unwrap()),
+        Price::new("ueth", Decimal::from_str("3000").unwrap()),
+        Price::new(USDC, Decimal::one()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+}
+
+fn btc_above(rate: &str) -> PriceCondition {
+    PriceCondition {
+        price: OrderPrice {
+            base_denom: "btc".to_string(),
+            quote_denom: USD_DENOM.to_string(),
+            rate: Decimal::from_str(rate).unwrap(),
+        },
+        direction: PriceDirection::Above,
+    }
+}
+
+// This test case verifies that a conditional order watches a pair that is not
+// the one of the order it wraps.
+// - "user" holds an ETH short and wants it closed once BTC/USD goes over 80000.
+// - The wrapped messages are checked and the pair must be priced by the oracle.
+// - While BTC is at 70000 the end blocker leaves the order pending.
+// - Once BTC is at 85000 the position is closed on behalf of "user".
+// - A second order, escrowing USDC for a spot order, is cancelled and refunded.
+#[test]
+fn close_short_on_btc_price() {
+    let mut app = ElysApp::new_with_wallets(vec![("user", coins(1000, USDC))]);
+    set_btc_price(&mut app, "70000");
+
+    let mtps = vec![Mtp {
+        address: "user".to_string(),
+        amm_pool_id: 1,
+        borrow_interest_paid_collateral: Int128::zero(),
+        borrow_interest_paid_custody: Int128::zero(),
+        borrow_interest_unpaid_collateral: Int128::zero(),
+        collateral_asset: "uusdc".to_string(),
+        collateral: Int128::new(1000000),
+        consolidate_leverage: SignedDecimal::zero(),
+        custody: Int128::new(5000000),
+        custody_asset: "uusdc".to_string(),
+        funding_fee_paid_collateral: Int128::zero(),
+        funding_fee_paid_custody: Int128::zero(),
+        funding_fee_received_collateral: Int128::zero(),
+        funding_fee_received_custody: Int128::zero(),
+        id: 2,
+        leverage: SignedDecimal::from_str("5.0").unwrap(),
+        liabilities: Int128::zero(),
+        liabilities_asset: "ueth".to_string(),
+        mtp_health: SignedDecimal::one(),
+        open_price: SignedDecimal::zero(),
+        position: 2,
+        sum_collateral: Int128::zero(),
+        take_profit_borrow_rate: SignedDecimal::zero(),
+        take_profit_custody: Int128::zero(),
+        take_profit_liabilities: Int128::zero(),
+        take_profit_price: SignedDecimal256::from_str("2000").unwrap(),
+        trading_asset: "ueth".to_string(),
+        stop_loss_price: SignedDecimal::zero(),
+        last_interest_calc_time: None,
+        last_interest_calc_block: None,
+        last_funding_calc_time: None,
+        last_funding_calc_block: None,
+    }];
+    app.init_modules(|router, _, store| router.custom.set_mtp(store, &mtps))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let close_position = Box::new(ExecuteMsg::ClosePerpetualPosition {
+        id: 2,
+        amount: Int128::new(5000000),
+    });
+
+    // Only orders and position closes can be conditional.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateConditionalOrder {
+            condition: btc_above("80000"),
+            msg: Box::new(ExecuteMsg::ClaimRewardsRequest {}),
+        },
+        &[],
+    )
+    .unwrap_err();
+
+    // The oracle has no price for doge.
+    let mut doge_above = btc_above("1");
+    doge_above.price.base_denom = "doge".to_string();
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateConditionalOrder {
+            condition: doge_above,
+            msg: close_position.clone(),
+        },
+        &[],
+    )
+    .unwrap_err();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateConditionalOrder {
+            condition: btc_above("80000"),
+            msg: close_position,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateConditionalOrder {
+            condition: btc_above("90000"),
+            msg: Box::new(ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::MarketBuy,
+                order_source_denom: USDC.to_string(),
+                order_target_denom: "btc".to_string(),
+                order_price: None,
+                routes: None,
+                trigger_price: None,
+            }),
+        },
+        &coins(1000, USDC),
+    )
+    .unwrap();
+    assert!(app.wrap().query_all_balances("user").unwrap().is_empty());
+
+    // BTC is still under 80000.
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+    let orders = get_conditional_orders(&app, &addr);
+    assert_eq!(orders.len(), 2);
+    assert!(orders.iter().all(|order| order.status == Status::Pending));
+
+    set_btc_price(&mut app, "85000");
+    let resp = app
+        .wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+    assert!(resp.has_event(
+        &Event::new(format!("wasm-{}", events::CONDITIONAL_ORDER_TRIGGERED))
+            .add_attribute(events::ORDER_ID_KEY, "1")
+    ));
+
+    let last_module_used = app
+        .init_modules(|router, _, storage| router.custom.get_last_module(storage).unwrap())
+        .unwrap();
+    assert_eq!(last_module_used, "PerpetualClose");
+
+    let r: PerpetualGetPositionsForAddressResponse = app
+        .wrap()
+        .query(&ElysQuery::perpetual_get_position_for_address("user".to_string(), None).into())
+        .unwrap();
+    assert!(r.mtps.is_empty());
+
+    let orders = get_conditional_orders(&app, &addr);
+    assert_eq!(orders[0].status, Status::Executed);
+    assert_eq!(orders[1].status, Status::Pending);
+
+    // Only the owner can cancel its order.
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        addr.clone(),
+        &ExecuteMsg::CancelConditionalOrder { order_id: 2 },
+        &[],
+    )
+    .unwrap_err();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CancelConditionalOrder { order_id: 2 },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        coins(1000, USDC)
+    );
+    assert_eq!(
+        get_conditional_orders(&app, &addr)[1].status,
+        Status::Canceled
+    );
+
+    // Executed orders can't be cancelled.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CancelConditionalOrder { order_id: 1 },
+        &[],
+    )
+    .unwrap_err();
+}
@@ -88,6 +88,7 @@ fn successful_create_perpetual_order() {
                 leverage: None,
                 trading_asset: None,
                 take_profit_price: None,
+                stop_loss_price: None,
                 order_type: PerpetualOrderType::StopLoss,
                 trigger_price: Some(OrderPrice {
                     base_denom:
@@ -103,9 +104,9 @@ fn successful_create_perpetual_order() {
         .unwrap();
 
     // Verify that an order ID is emitted in the contract's events.
-    assert!(get_attr_from_events(&resp.events, "perpetual_order_id").is_some());
+    assert!(get_attr_from_events(&resp.events, "order_id").is_some());
 
-    let order_id: u64 = get_attr_from_events(&resp.events, "perpetual_order_id")
+    let order_id: u64 = get_attr_from_events(&resp.events, "order_id")
         .unwrap()
         .parse()
         .unwrap();
@@ -128,6 +129,7 @@ fn successful_create_perpetual_order() {
                 leverage: None,
                 trading_asset: None,
                 take_profit_price: None,
+                stop_loss_price: None,
                 order_type: PerpetualOrderType::StopLoss,
                 trigger_price: Some(OrderPrice {
                     base_denom:
@@ -142,9 +144,9 @@ fn successful_create_perpetual_order() {
         )
         .unwrap();
 
-    assert!(get_attr_from_events(&resp.events, "perpetual_order_id").is_some());
+    assert!(get_attr_from_events(&resp.events, "order_id").is_some());
 
-    let same_order_id: u64 = get_attr_from_events(&resp.events, "perpetual_order_id")
+    let same_order_id: u64 = get_attr_from_events(&resp.events, "order_id")
         .unwrap()
         .parse()
         .unwrap();
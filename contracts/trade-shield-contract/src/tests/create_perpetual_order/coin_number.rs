@@ -38,6 +38,7 @@ fn coin_number() {
             leverage: Some(SignedDecimal::from_atomics(Int64::new(500), 2).unwrap()),
             trading_asset: Some("uatom".to_string()),
             take_profit_price: Some(SignedDecimal256::from_atomics(Uint128::new(500), 2).unwrap()),
+            stop_loss_price: None,
             order_type: PerpetualOrderType::LimitOpen,
             trigger_price: Some(OrderPrice {
                 base_denom: "uatom".to_string(),
@@ -0,0 +1,177 @@
+use cosmwasm_std::{Addr, Decimal, Int64, SignedDecimal};
+use elys_bindings::trade_shield::msg::query_resp::GetPerpetualOrderResp;
+use std::str::FromStr;
+
+use crate::events;
+use crate::tests::get_order_id_from_events::{get_attr_from_events, get_events_of_type};
+
+use super::*;
+
+// This test case verifies that a stop loss price set on a market open order
+// registers a pending StopLoss order linked to the newly opened position.
+#[test]
+fn market_open_with_stop_loss() {
+    // Create a wallet for the "user" with an initial balance of 10 BTC.
+    let wallet = vec![("user", coins(10, "btc"))];
+
+    // Initialize the ElysApp instance with the specified wallet.
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    // Create a mock message to instantiate the contract with no initial orders.
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    // Create a contract wrapper and store its code.
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    // Instantiate the contract with "owner" as the deployer.
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // BTC enters at 2 USDC, the stop loss sits below it.
+    let prices = vec![
+        Price::new(
+            "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65",
+            Decimal::one(),
+        ),
+        Price::new("btc", Decimal::from_str("2").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let stop_loss_rate = Decimal::from_str("1.2").unwrap();
+
+    // User "user" opens a long position with a stop loss attached.
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreatePerpetualOrder {
+                position: Some(PerpetualPosition::Long),
+                leverage: Some(SignedDecimal::from_atomics(Int64::new(215), 2).unwrap()),
+                trading_asset: Some("btc".to_string()),
+                take_profit_price: None,
+                stop_loss_price: Some(OrderPrice {
+                    base_denom:
+                        "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
+                            .to_string(),
+                    quote_denom: "btc".to_string(),
+                    rate: stop_loss_rate,
+                }),
+                order_type: PerpetualOrderType::MarketOpen,
+                trigger_price: None,
+                position_id: None,
+            },
+            &coins(10, "btc"),
+        )
+        .unwrap();
+
+    let open_order_id: u64 = get_attr_from_events(&resp.events, "order_id")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let position_id: u64 = get_attr_from_events(&resp.events, "position_id")
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    // The stop loss order is announced with its own created event.
+    let created = get_events_of_type(&resp.events, events::ORDER_CREATED);
+    assert_eq!(created.len(), 2);
+    let stop_loss_order_id: u64 = created[1]
+        .attributes
+        .iter()
+        .find(|attr| attr.key == events::ORDER_ID_KEY)
+        .unwrap()
+        .value
+        .parse()
+        .unwrap();
+
+    assert_ne!(open_order_id, stop_loss_order_id);
+
+    let GetPerpetualOrderResp {
+        order: PerpetualOrderPlus { order, .. },
+    } = app
+        .wrap()
+        .query_wasm_smart(
+            addr.as_str(),
+            &QueryMsg::GetPerpetualOrder {
+                id: stop_loss_order_id,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(order.order_type, PerpetualOrderType::StopLoss);
+    assert_eq!(order.status, Status::Pending);
+    assert_eq!(order.position, PerpetualPosition::Long);
+    assert_eq!(order.position_id, Some(position_id));
+    assert_eq!(order.trigger_price.unwrap().rate, stop_loss_rate);
+}
+
+// A stop loss price is only accepted on orders that open a position.
+#[test]
+fn stop_loss_price_on_close_order() {
+    let mut app = ElysApp::new();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let price = OrderPrice {
+        base_denom: "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
+            .to_string(),
+        quote_denom: "btc".to_string(),
+        rate: Decimal::from_str("1.2").unwrap(),
+    };
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreatePerpetualOrder {
+                position: None,
+                leverage: None,
+                trading_asset: None,
+                take_profit_price: None,
+                stop_loss_price: Some(price.clone()),
+                order_type: PerpetualOrderType::LimitClose,
+                trigger_price: Some(price),
+                position_id: Some(1),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        ContractError::StdError(cosmwasm_std::StdError::generic_err(
+            "stop_loss_price: can only be set on a LimitOpen or MarketOpen order"
+        )),
+        err.downcast().unwrap()
+    );
+}
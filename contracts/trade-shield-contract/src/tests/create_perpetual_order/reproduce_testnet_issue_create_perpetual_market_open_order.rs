@@ -293,6 +293,7 @@ fn reproduce_testnet_issue_create_perpetual_market_open_order() {
                 "ibc/E2D2F6ADCC68AA3384B2F5DFACCA437923D137C14E86FB8A10207CF3BED0C8D4".to_string(),
             ), // atom
             take_profit_price: None,
+            stop_loss_price: None,
             order_type: PerpetualOrderType::MarketOpen,
             trigger_price: None,
             position_id: None,
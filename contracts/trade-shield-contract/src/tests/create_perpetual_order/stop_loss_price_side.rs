@@ -0,0 +1,187 @@
+use cosmwasm_std::{Addr, Decimal, Int128, Int64, SignedDecimal, SignedDecimal256};
+use std::str::FromStr;
+
+use super::*;
+
+const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+fn setup() -> (ElysApp, Addr) {
+    let wallet = vec![("user", coins(20, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new(USDC, Decimal::one()),
+        Price::new("btc", Decimal::from_str("2").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    (app, addr)
+}
+
+fn price(rate: &str) -> OrderPrice {
+    OrderPrice {
+        base_denom: USDC.to_string(),
+        quote_denom: "btc".to_string(),
+        rate: Decimal::from_str(rate).unwrap(),
+    }
+}
+
+// A long position entering at the 2 USDC market price cannot stop out above it.
+#[test]
+fn long_stop_loss_above_entry() {
+    let (mut app, addr) = setup();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr,
+            &ExecuteMsg::CreatePerpetualOrder {
+                position: Some(PerpetualPosition::Long),
+                leverage: Some(SignedDecimal::from_atomics(Int64::new(215), 2).unwrap()),
+                trading_asset: Some("btc".to_string()),
+                take_profit_price: None,
+                stop_loss_price: Some(price("2.5")),
+                order_type: PerpetualOrderType::MarketOpen,
+                trigger_price: None,
+                position_id: None,
+            },
+            &coins(10, "btc"),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        ContractError::StopLossPriceSide {
+            position: "Long".to_string(),
+            stop_loss: Decimal::from_str("2.5").unwrap(),
+            entry: Decimal::from_str("2").unwrap(),
+        },
+        err.downcast().unwrap()
+    );
+}
+
+// A short limit order entering at its 3 USDC trigger cannot stop out below it,
+// even when the stop loss is above the current market price.
+#[test]
+fn short_stop_loss_below_entry() {
+    let (mut app, addr) = setup();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr,
+            &ExecuteMsg::CreatePerpetualOrder {
+                position: Some(PerpetualPosition::Short),
+                leverage: Some(SignedDecimal::from_atomics(Int64::new(215), 2).unwrap()),
+                trading_asset: Some("btc".to_string()),
+                take_profit_price: None,
+                stop_loss_price: Some(price("2.5")),
+                order_type: PerpetualOrderType::LimitOpen,
+                trigger_price: Some(price("3")),
+                position_id: None,
+            },
+            &coins(10, "btc"),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        ContractError::StopLossPriceSide {
+            position: "Short".to_string(),
+            stop_loss: Decimal::from_str("2.5").unwrap(),
+            entry: Decimal::from_str("3").unwrap(),
+        },
+        err.downcast().unwrap()
+    );
+}
+
+// Moving the stop loss of an open long above the 2 USDC market price would
+// close it at once, the update is rejected while a stop loss below is accepted.
+#[test]
+fn long_stop_loss_update_above_market() {
+    let (mut app, addr) = setup();
+    let mtps = vec![Mtp {
+        address: "user".to_string(),
+        amm_pool_id: 1,
+        borrow_interest_paid_collateral: Int128::zero(),
+        borrow_interest_paid_custody: Int128::zero(),
+        borrow_interest_unpaid_collateral: Int128::zero(),
+        collateral_asset: "btc".to_string(),
+        collateral: Int128::new(10),
+        consolidate_leverage: SignedDecimal::zero(),
+        custody: Int128::new(20),
+        custody_asset: "btc".to_string(),
+        funding_fee_paid_collateral: Int128::zero(),
+        funding_fee_paid_custody: Int128::zero(),
+        funding_fee_received_collateral: Int128::zero(),
+        funding_fee_received_custody: Int128::zero(),
+        id: 1,
+        leverage: SignedDecimal::from_str("2").unwrap(),
+        liabilities: Int128::new(20),
+        liabilities_asset: USDC.to_string(),
+        mtp_health: SignedDecimal::one(),
+        open_price: SignedDecimal::from_str("2").unwrap(),
+        position: PerpetualPosition::Long as i32,
+        sum_collateral: Int128::zero(),
+        take_profit_borrow_rate: SignedDecimal::zero(),
+        take_profit_custody: Int128::zero(),
+        take_profit_liabilities: Int128::zero(),
+        take_profit_price: SignedDecimal256::from_str("3").unwrap(),
+        trading_asset: "btc".to_string(),
+        stop_loss_price: SignedDecimal::from_str("1.5").unwrap(),
+        last_interest_calc_time: None,
+        last_interest_calc_block: None,
+        last_funding_calc_time: None,
+        last_funding_calc_block: None,
+    }];
+    app.init_modules(|router, _, store| router.custom.set_mtp(store, &mtps))
+        .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::PerpetualUpdateStopLoss {
+                id: 1,
+                price: SignedDecimal::from_str("2.5").unwrap(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        ContractError::StopLossPriceSide {
+            position: "Long".to_string(),
+            stop_loss: Decimal::from_str("2.5").unwrap(),
+            entry: Decimal::from_str("2").unwrap(),
+        },
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr,
+        &ExecuteMsg::PerpetualUpdateStopLoss {
+            id: 1,
+            price: SignedDecimal::from_str("1.8").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+}
@@ -82,6 +82,7 @@ fn successful_create_perpetual_market_open_order() {
             leverage: None,
             trading_asset: None,
             take_profit_price: None,
+            stop_loss_price: None,
             order_type: PerpetualOrderType::MarketClose,
             trigger_price: None,
         },
@@ -42,6 +42,7 @@ fn successful_create_perpetual_market_open_order() {
             leverage: Some(SignedDecimal::from_atomics(Int64::new(215), 2).unwrap()),
             trading_asset: Some("btc".to_string()),
             take_profit_price: Some(SignedDecimal256::from_atomics(Uint128::new(200), 2).unwrap()),
+            stop_loss_price: None,
             order_type: PerpetualOrderType::MarketOpen,
             trigger_price: Some(OrderPrice {
                 base_denom: "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
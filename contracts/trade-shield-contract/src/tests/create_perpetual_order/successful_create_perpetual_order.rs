@@ -53,6 +53,7 @@ fn successful_create_perpetual_order() {
                 take_profit_price: Some(
                     SignedDecimal256::from_atomics(Uint128::new(200), 2).unwrap(),
                 ),
+                stop_loss_price: None,
                 order_type: PerpetualOrderType::LimitOpen,
                 trigger_price: Some(OrderPrice {
                     base_denom:
@@ -97,5 +98,5 @@ fn successful_create_perpetual_order() {
     );
 
     // Verify that an order ID is emitted in the contract's events.
-    assert!(get_attr_from_events(&resp.events, "perpetual_order_id").is_some());
+    assert!(get_attr_from_events(&resp.events, "order_id").is_some());
 }
@@ -44,6 +44,8 @@ fn coin_number() {
                 }),
                 order_source_denom: "eth".to_owned(),
                 order_target_denom: "btc".to_string(),
+                routes: None,
+                trigger_price: None,
             },
             &[],
         )
@@ -0,0 +1,97 @@
+use cosmwasm_std::StdError;
+
+use super::*;
+
+// This test case verifies that a "limit sell" order keeps the routes given by the user
+// and that routes not ending with the target denom are rejected.
+#[test]
+fn explicit_routes() {
+    let wallets = vec![("user", coins(20, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let prices = vec![
+        Price::new(
+            "btc",
+            Decimal::from_atomics(Uint128::new(30000), 0).unwrap(),
+        ),
+        Price::new("usdc", Decimal::from_atomics(Uint128::new(1), 0).unwrap()),
+    ];
+
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    let code = ContractWrapper::new(execute, instantiate, query);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let order_price = Some(OrderPrice {
+        base_denom: "btc".to_string(),
+        quote_denom: "usdc".to_string(),
+        rate: Decimal::from_atomics(Uint128::new(40000), 0).unwrap(),
+    });
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::LimitSell,
+                order_source_denom: "btc".to_string(),
+                order_target_denom: "usdc".to_string(),
+                order_price: order_price.clone(),
+                routes: Some(vec![SwapAmountInRoute::new(1, "eth")]),
+                trigger_price: None,
+            },
+            &coins(10, "btc"),
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        ContractError::StdError(StdError::generic_err(
+            "routes: the last route should output the target denom"
+        )),
+        err.downcast().unwrap()
+    );
+
+    let routes = vec![
+        SwapAmountInRoute::new(1, "eth"),
+        SwapAmountInRoute::new(2, "usdc"),
+    ];
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateSpotOrder {
+            order_type: SpotOrderType::LimitSell,
+            order_source_denom: "btc".to_string(),
+            order_target_denom: "usdc".to_string(),
+            order_price,
+            routes: Some(routes.clone()),
+            trigger_price: None,
+        },
+        &coins(10, "btc"),
+    )
+    .unwrap();
+
+    let resp: GetSpotOrderResp = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetSpotOrder { order_id: 1 })
+        .unwrap();
+
+    assert_eq!(resp.order.routes, Some(routes));
+}
@@ -28,6 +28,8 @@ fn not_enough_fund() {
 
         order_source_denom: "eth".to_string(),
         order_target_denom: "btc".to_string(),
+        routes: None,
+        trigger_price: None,
     };
 
     // Create a contract wrapper and store its code.
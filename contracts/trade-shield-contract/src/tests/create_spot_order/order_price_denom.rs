@@ -27,6 +27,8 @@ fn order_price_denom() {
 
         order_source_denom: "eth".to_string(),
         order_target_denom: "btc".to_string(),
+        routes: None,
+        trigger_price: None,
     };
 
     let code = ContractWrapper::new(execute, instantiate, query);
@@ -23,6 +23,8 @@ fn order_same_denom() {
 
         order_source_denom: "eth".to_string(),
         order_target_denom: "eth".to_string(), // Same denomination for base and quote tokens.
+        routes: None,
+        trigger_price: None,
     };
 
     let code = ContractWrapper::new(execute, instantiate, query);
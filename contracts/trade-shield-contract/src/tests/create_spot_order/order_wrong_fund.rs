@@ -24,6 +24,8 @@ fn order_wrong_fund() {
 
         order_source_denom: "usdc".to_string(), // Incorrect source denomination.
         order_target_denom: "btc".to_string(),
+        routes: None,
+        trigger_price: None,
     };
 
     let code = ContractWrapper::new(execute, instantiate, query);
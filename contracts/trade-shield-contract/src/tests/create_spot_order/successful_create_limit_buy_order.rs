@@ -56,6 +56,8 @@ fn successful_create_limit_buy_order() {
                 }),
                 order_source_denom: "usdc".to_string(),
                 order_target_denom: "btc".to_string(),
+                routes: None,
+                trigger_price: None,
             },
             &coins(100, "usdc"), // User's USDC balance.
         )
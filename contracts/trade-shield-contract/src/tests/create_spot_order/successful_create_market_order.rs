@@ -60,6 +60,8 @@ fn successful_create_market_buy_order() {
             order_price: None,
             order_source_denom: "btc".to_string(),
             order_target_denom: "usdc".to_string(),
+            routes: None,
+            trigger_price: None,
         },
         &coins(2, "btc"), // User's BTC balance.
     )
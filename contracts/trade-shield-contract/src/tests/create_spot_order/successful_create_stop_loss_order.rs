@@ -56,6 +56,8 @@ fn successful_create_stop_loss_order() {
 
                 order_source_denom: "btc".to_string(),
                 order_target_denom: "usdc".to_string(),
+                routes: None,
+                trigger_price: None,
             },
             &coins(2, "btc"), // User's BTC balance.
         )
@@ -0,0 +1,171 @@
+use elys_bindings::trade_shield::msg::query_resp::{GetDiscountResp, GetFeesCollectedResp};
+
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+// This test case verifies that a discount override set by the admin replaces
+// the tier discount of an address until it expires.
+// - The fee rate is 10% and "user" gets a 50% discount for 100 seconds.
+// - A market order of 100 BTC is charged 5 BTC of fee during that time.
+// - Once expired, the tier discount of 0% is applied again.
+#[test]
+fn expiring_discount() {
+    let wallet = vec![("user", coins(200, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::SetParams {
+            market_order_enabled: None,
+            stake_enabled: None,
+            process_order_enabled: None,
+            swap_enabled: None,
+            perpetual_enabled: None,
+            reward_enabled: None,
+            leverage_enabled: None,
+            limit_process_order: None,
+            min_order_notional: None,
+            max_pending_orders_per_user: None,
+            max_leverage: None,
+            allowed_pairs: None,
+            fee_rate: Some(Decimal::percent(10)),
+            fee_collector: Some("collector".to_string()),
+            referrer_fee_share: None,
+            order_gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let expires_at = app.block_info().time.plus_seconds(100);
+    let set_override = ExecuteMsg::SetDiscountOverride {
+        address: "user".to_string(),
+        discount: Decimal::percent(50),
+        expires_at: Some(expires_at),
+    };
+
+    // Only the params admin can set a discount override.
+    let err = app
+        .execute_contract(Addr::unchecked("user"), addr.clone(), &set_override, &[])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Unauthorized {
+            sender: Addr::unchecked("user")
+        },
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(Addr::unchecked(ADMIN), addr.clone(), &set_override, &[])
+        .unwrap();
+
+    let resp: GetDiscountResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetDiscount {
+                address: "user".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(resp.discount, Decimal::percent(50));
+    assert_eq!(resp.discount_override.unwrap().expires_at, Some(expires_at));
+
+    let market_order = ExecuteMsg::CreateSpotOrder {
+        order_type: SpotOrderType::MarketBuy,
+        order_price: None,
+        order_source_denom: "btc".to_string(),
+        order_target_denom: "usdc".to_string(),
+        routes: None,
+        trigger_price: None,
+    };
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &market_order,
+        &coins(100, "btc"),
+    )
+    .unwrap();
+
+    let GetFeesCollectedResp { fees } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetFeesCollected {})
+        .unwrap();
+    assert_eq!(fees, coins(5, "btc"));
+
+    // The override is ignored once expired.
+    app.update_block(|block| block.time = expires_at);
+
+    let resp: GetDiscountResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetDiscount {
+                address: "user".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(resp.discount, Decimal::zero());
+    assert_eq!(resp.discount_override, None);
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &market_order,
+        &coins(100, "btc"),
+    )
+    .unwrap();
+
+    let GetFeesCollectedResp { fees } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetFeesCollected {})
+        .unwrap();
+    assert_eq!(fees, coins(15, "btc"));
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::RemoveDiscountOverride {
+            address: "user".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // Removing an override that does not exist fails.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::RemoveDiscountOverride {
+            address: "user".to_string(),
+        },
+        &[],
+    )
+    .unwrap_err();
+}
@@ -0,0 +1,52 @@
+use elys_bindings::trade_shield::msg::{query_resp::GetDiscountResp, MigrateMsg};
+
+use super::*;
+use crate::entry_point::migrate;
+
+const DISCOUNTED_ADDRESS: &str = "elys1u8c28343vvhwgwhf29w6hlcz73hvq7lwxmrl46";
+
+// This test case verifies that the migration moves the discount that used to
+// be hardcoded in get_discount into a discount override without expiry.
+#[test]
+fn migrated_discount() {
+    // skip the contract version checks of the migration
+    std::env::set_var("IS_TEST_ENV", "TESTING");
+
+    let mut app = ElysApp::new();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_migrate(migrate);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            Some("owner".to_string()),
+        )
+        .unwrap();
+
+    let get_discount = QueryMsg::GetDiscount {
+        address: DISCOUNTED_ADDRESS.to_string(),
+    };
+
+    let resp: GetDiscountResp = app.wrap().query_wasm_smart(&addr, &get_discount).unwrap();
+    assert_eq!(resp.discount, Decimal::zero());
+
+    app.migrate_contract(
+        Addr::unchecked("owner"),
+        addr.clone(),
+        &MigrateMsg {},
+        code_id,
+    )
+    .unwrap();
+
+    let resp: GetDiscountResp = app.wrap().query_wasm_smart(&addr, &get_discount).unwrap();
+    assert_eq!(resp.discount, Decimal::from_str("0.2").unwrap());
+    assert_eq!(resp.discount_override.unwrap().expires_at, None);
+}
@@ -1,10 +1,13 @@
 use super::*;
+use crate::events::ORDER_ID_KEY;
 
 pub fn get_order_id_from_events(events: &Vec<Event>) -> Option<u64> {
-    let key = "order_id";
-
     for event in events {
-        if let Some(attr) = event.attributes.iter().find(|attr| attr.key == key) {
+        if let Some(attr) = event
+            .attributes
+            .iter()
+            .find(|attr| attr.key == ORDER_ID_KEY)
+        {
             if let Ok(id) = attr.value.parse::<u64>() {
                 return Some(id);
             }
@@ -21,3 +24,11 @@ pub fn get_attr_from_events(events: &Vec<Event>, key: &str) -> Option<String> {
     }
     None
 }
+
+// contract events are prefixed with "wasm-" by the runtime
+pub fn get_events_of_type<'a>(events: &'a [Event], ty: &str) -> Vec<&'a Event> {
+    events
+        .iter()
+        .filter(|event| event.ty == format!("wasm-{}", ty))
+        .collect()
+}
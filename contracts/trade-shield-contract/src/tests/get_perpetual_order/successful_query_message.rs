@@ -21,6 +21,7 @@ fn successful_query_message() {
             quote_denom: "usdc".to_string(),
             rate: Decimal::from_str("35").unwrap(),
         }),
+        &None,
         &vec![],
         DecCoin::new(Decimal256::zero(), ""),
         SignedDecimal::zero(),
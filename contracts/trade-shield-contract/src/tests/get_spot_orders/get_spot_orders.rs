@@ -84,6 +84,63 @@ fn get_spot_orders() {
     assert_eq!(&resp.orders, last_order);
 }
 
+#[test]
+fn get_spot_orders_reverse_with_total() {
+    let spot_orders: Vec<SpotOrder> = create_orders();
+    let mut app = ElysApp::new();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: spot_orders.clone(),
+        perpetual_orders: vec![],
+    };
+
+    let code = ContractWrapper::new(execute, instantiate, query);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let mut page_req = PageRequest::new(1);
+    page_req.reverse = true;
+    page_req.count_total = true;
+
+    let query_msg = |page_req: &PageRequest| QueryMsg::GetSpotOrders {
+        pagination: Some(page_req.clone()),
+        order_owner: None,
+        order_type: Some(SpotOrderType::LimitBuy),
+        order_status: None,
+    };
+
+    let resp: GetSpotOrdersResp = app
+        .wrap()
+        .query_wasm_smart(&addr, &query_msg(&page_req))
+        .unwrap();
+    let page_response = resp.page_response.unwrap();
+
+    assert_eq!(resp.orders, vec![spot_orders[4].clone()]);
+    assert_eq!(page_response.total, Some(2));
+
+    page_req.update(page_response.next_key);
+
+    let resp: GetSpotOrdersResp = app
+        .wrap()
+        .query_wasm_smart(&addr, &query_msg(&page_req))
+        .unwrap();
+    let page_response = resp.page_response.unwrap();
+
+    assert_eq!(resp.orders, vec![spot_orders[0].clone()]);
+    assert_eq!(page_response.total, Some(2));
+    assert!(page_response.next_key.is_none());
+}
+
 fn create_orders() -> Vec<SpotOrder> {
     vec![
         SpotOrder {
@@ -97,6 +154,8 @@ fn create_orders() -> Vec<SpotOrder> {
             order_amount: coin(255, "btc"),
             owner_address: Addr::unchecked("userA"),
             order_target_denom: "btc".to_owned(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -114,6 +173,8 @@ fn create_orders() -> Vec<SpotOrder> {
             order_amount: coin(100, "eth"),
             owner_address: Addr::unchecked("userB"),
             order_target_denom: "eth".to_owned(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -131,6 +192,8 @@ fn create_orders() -> Vec<SpotOrder> {
             order_amount: coin(500, "xrp"),
             owner_address: Addr::unchecked("userC"),
             order_target_denom: "xrp".to_owned(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -148,6 +211,8 @@ fn create_orders() -> Vec<SpotOrder> {
             order_amount: coin(75, "ltc"),
             owner_address: Addr::unchecked("userD"),
             order_target_denom: "ltc".to_owned(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -165,6 +230,8 @@ fn create_orders() -> Vec<SpotOrder> {
             order_amount: coin(200, "ada"),
             owner_address: Addr::unchecked("userE"),
             order_target_denom: "ada".to_owned(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
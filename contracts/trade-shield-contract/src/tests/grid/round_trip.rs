@@ -0,0 +1,147 @@
+use cosmwasm_std::Coin;
+use elys_bindings::trade_shield::msg::query_resp::GetGridResp;
+
+use super::*;
+
+const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+fn set_btc_price(app: &mut ElysApp, btc: u128) {
+    let prices = vec![
+        Price::new("btc", Decimal::from_atomics(btc, 0).unwrap()),
+        Price::new(USDC, Decimal::one()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+}
+
+// This test case verifies that a grid replaces every filled level by the
+// opposite order one level away and refunds its levels once closed.
+// - "user" opens a 5 levels BTC/USDC grid from 20000 to 40000 while BTC is at
+//   30000: 25000 USDC are bought at 20000 and 25000, 1 BTC is sold at 35000
+//   and 40000.
+// - BTC drops to 25000, the buy fills and 1 BTC is sold at 30000.
+// - BTC comes back to 30000, the sell fills for a 5000 USDC profit and the
+//   30000 USDC are bought back at 25000.
+// - Closing the grid refunds the four pending levels.
+#[test]
+fn round_trip() {
+    let wallets = vec![("user", vec![coin(2, "btc"), coin(100000, USDC)])];
+    let mut app = ElysApp::new_with_wallets(wallets);
+    set_btc_price(&mut app, 30000);
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateGridOrder {
+            base_denom: "btc".to_string(),
+            quote_denom: USDC.to_string(),
+            lower_price: Decimal::from_str("20000").unwrap(),
+            upper_price: Decimal::from_str("40000").unwrap(),
+            levels: 5,
+        },
+        &[coin(2, "btc"), coin(50000, USDC)],
+    )
+    .unwrap();
+
+    let get_grid = |app: &ElysApp| -> GetGridResp {
+        app.wrap()
+            .query_wasm_smart(&addr, &QueryMsg::GetGrid { grid_id: 1 })
+            .unwrap()
+    };
+    let levels = |resp: &GetGridResp| -> Vec<(SpotOrderType, u64, Coin)> {
+        let mut levels: Vec<(SpotOrderType, u64, Coin)> = resp
+            .orders
+            .iter()
+            .map(|order| {
+                (
+                    order.order_type.clone(),
+                    order.order_price.rate.to_uint_floor().u128() as u64,
+                    order.order_amount.clone(),
+                )
+            })
+            .collect();
+        levels.sort_by_key(|(_, price, _)| *price);
+        levels
+    };
+
+    let resp = get_grid(&app);
+    assert_eq!(
+        levels(&resp),
+        vec![
+            (SpotOrderType::LimitBuy, 20000, coin(25000, USDC)),
+            (SpotOrderType::LimitBuy, 25000, coin(25000, USDC)),
+            (SpotOrderType::LimitSell, 35000, coin(1, "btc")),
+            (SpotOrderType::LimitSell, 40000, coin(1, "btc")),
+        ]
+    );
+
+    set_btc_price(&mut app, 25000);
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let resp = get_grid(&app);
+    assert_eq!(resp.grid.filled_orders, 1);
+    assert!(levels(&resp).contains(&(SpotOrderType::LimitSell, 30000, coin(1, "btc"))));
+
+    set_btc_price(&mut app, 30000);
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let resp = get_grid(&app);
+    assert_eq!(resp.grid.filled_orders, 2);
+    assert_eq!(resp.grid.realised_profit, Uint128::new(5000));
+    assert_eq!(
+        levels(&resp),
+        vec![
+            (SpotOrderType::LimitBuy, 20000, coin(25000, USDC)),
+            (SpotOrderType::LimitBuy, 25000, coin(30000, USDC)),
+            (SpotOrderType::LimitSell, 35000, coin(1, "btc")),
+            (SpotOrderType::LimitSell, 40000, coin(1, "btc")),
+        ]
+    );
+
+    // Only the owner of the grid can close it.
+    app.execute_contract(
+        Addr::unchecked("bob"),
+        addr.clone(),
+        &ExecuteMsg::CloseGrid { grid_id: 1 },
+        &[],
+    )
+    .unwrap_err();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CloseGrid { grid_id: 1 },
+        &[],
+    )
+    .unwrap();
+
+    let resp = get_grid(&app);
+    assert!(!resp.grid.active);
+    assert!(resp.orders.is_empty());
+    assert_eq!(
+        app.wrap().query_all_balances("user").unwrap(),
+        vec![coin(2, "btc"), coin(105000, USDC)]
+    );
+    assert!(app.wrap().query_all_balances(&addr).unwrap().is_empty());
+}
@@ -0,0 +1,381 @@
+use std::collections::{BTreeMap, HashMap};
+
+use cosmwasm_std::{testing::MockStorage, Order, SignedDecimal, StdResult, Storage};
+use elys_bindings::trade_shield::states::{
+    FEES_COLLECTED, LIQUIDATION_GUARDS, NUMBER_OF_PENDING_ORDER, PENDING_PERPETUAL_ORDER_V2,
+    PENDING_SPOT_ORDER, SORTED_PENDING_PERPETUAL_ORDER, SORTED_PENDING_SPOT_ORDER,
+};
+
+use super::*;
+
+pub const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+pub const USERS: [&str; 3] = ["user-a", "user-b", "user-c"];
+
+/// Small xorshift generator, a failing sequence is replayed from its seed.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // the state of a xorshift generator cannot be zero
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Uniform value in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low + 1)
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        let index = self.range(0, items.len() as u64 - 1) as usize;
+        items.get(index)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Action {
+    CreateSpotOrder {
+        user: &'static str,
+        order_type: SpotOrderType,
+        rate: u64,
+        amount: u128,
+    },
+    CancelSpotOrder {
+        user: &'static str,
+        order_id: u64,
+    },
+    CreatePerpetualOrder {
+        user: &'static str,
+        position: PerpetualPosition,
+        rate: u64,
+        amount: u128,
+    },
+    CancelPerpetualOrder {
+        user: &'static str,
+        order_id: u64,
+    },
+    MovePrice {
+        btc: u64,
+    },
+    ClockEndBlock,
+}
+
+impl Action {
+    /// Draws the next action, cancellations target an order pending in `snapshot`.
+    pub fn random(rng: &mut Rng, snapshot: &dyn Storage) -> Self {
+        let user = *rng.pick(&USERS).unwrap();
+        match rng.range(0, 9) {
+            0..=2 => Action::CreateSpotOrder {
+                user,
+                order_type: rng
+                    .pick(&[
+                        SpotOrderType::LimitSell,
+                        SpotOrderType::LimitBuy,
+                        SpotOrderType::StopLoss,
+                    ])
+                    .unwrap()
+                    .clone(),
+                rate: rng.range(20, 40) * 1000,
+                amount: rng.range(1, 5) as u128,
+            },
+            3..=4 => Action::CreatePerpetualOrder {
+                user,
+                position: rng
+                    .pick(&[PerpetualPosition::Long, PerpetualPosition::Short])
+                    .unwrap()
+                    .clone(),
+                rate: rng.range(20, 40) * 1000,
+                amount: rng.range(10, 50) as u128 * 1000,
+            },
+            5 => {
+                let pending =
+                    pending_ids(PENDING_SPOT_ORDER.keys(snapshot, None, None, Order::Ascending));
+                match rng.pick(&pending) {
+                    Some(order_id) => Action::CancelSpotOrder {
+                        user: owner_of(
+                            PENDING_SPOT_ORDER
+                                .load(snapshot, *order_id)
+                                .unwrap()
+                                .owner_address
+                                .as_str(),
+                        ),
+                        order_id: *order_id,
+                    },
+                    None => Action::ClockEndBlock,
+                }
+            }
+            6 => {
+                let pending = pending_ids(PENDING_PERPETUAL_ORDER_V2.keys(
+                    snapshot,
+                    None,
+                    None,
+                    Order::Ascending,
+                ));
+                match rng.pick(&pending) {
+                    Some(order_id) => Action::CancelPerpetualOrder {
+                        user: owner_of(
+                            PENDING_PERPETUAL_ORDER_V2
+                                .load(snapshot, *order_id)
+                                .unwrap()
+                                .owner
+                                .as_str(),
+                        ),
+                        order_id: *order_id,
+                    },
+                    None => Action::ClockEndBlock,
+                }
+            }
+            7..=8 => Action::MovePrice {
+                btc: rng.range(20, 40) * 1000,
+            },
+            _ => Action::ClockEndBlock,
+        }
+    }
+
+    /// Applies the action, a rejected message leaves the state untouched so
+    /// its error is ignored.
+    pub fn apply(&self, app: &mut ElysApp, addr: &Addr) {
+        let _ = match self {
+            Action::CreateSpotOrder {
+                user,
+                order_type,
+                rate,
+                amount,
+            } => {
+                // the order price is expressed in target denom per source denom
+                let (source, target, rate, amount) = match order_type {
+                    SpotOrderType::LimitBuy => (
+                        USDC,
+                        "btc",
+                        Decimal::from_ratio(1u128, *rate),
+                        *amount * *rate as u128,
+                    ),
+                    _ => (
+                        "btc",
+                        USDC,
+                        Decimal::from_atomics(Uint128::new(*rate as u128), 0).unwrap(),
+                        *amount,
+                    ),
+                };
+                app.execute_contract(
+                    Addr::unchecked(*user),
+                    addr.clone(),
+                    &ExecuteMsg::CreateSpotOrder {
+                        order_type: order_type.clone(),
+                        order_source_denom: source.to_string(),
+                        order_target_denom: target.to_string(),
+                        order_price: Some(OrderPrice {
+                            base_denom: source.to_string(),
+                            quote_denom: target.to_string(),
+                            rate,
+                        }),
+                        routes: None,
+                        trigger_price: None,
+                    },
+                    &coins(amount, source),
+                )
+            }
+            Action::CancelSpotOrder { user, order_id } => app.execute_contract(
+                Addr::unchecked(*user),
+                addr.clone(),
+                &ExecuteMsg::CancelSpotOrder {
+                    order_id: *order_id,
+                },
+                &[],
+            ),
+            Action::CreatePerpetualOrder {
+                user,
+                position,
+                rate,
+                amount,
+            } => app.execute_contract(
+                Addr::unchecked(*user),
+                addr.clone(),
+                &ExecuteMsg::CreatePerpetualOrder {
+                    position: Some(position.clone()),
+                    leverage: Some(SignedDecimal::from_str("2").unwrap()),
+                    trading_asset: Some("btc".to_string()),
+                    take_profit_price: None,
+                    stop_loss_price: None,
+                    order_type: PerpetualOrderType::LimitOpen,
+                    trigger_price: Some(OrderPrice {
+                        base_denom: USDC.to_string(),
+                        quote_denom: "btc".to_string(),
+                        rate: Decimal::from_atomics(Uint128::new(*rate as u128), 0).unwrap(),
+                    }),
+                    position_id: None,
+                },
+                &coins(*amount, USDC),
+            ),
+            Action::CancelPerpetualOrder { user, order_id } => app.execute_contract(
+                Addr::unchecked(*user),
+                addr.clone(),
+                &ExecuteMsg::CancelPerpetualOrder {
+                    order_id: *order_id,
+                },
+                &[],
+            ),
+            Action::MovePrice { btc } => {
+                set_btc_price(app, *btc);
+                return;
+            }
+            Action::ClockEndBlock => app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {}),
+        };
+    }
+}
+
+fn owner_of(address: &str) -> &'static str {
+    USERS.iter().find(|user| **user == address).unwrap()
+}
+
+fn pending_ids(keys: Box<dyn Iterator<Item = StdResult<u64>> + '_>) -> Vec<u64> {
+    keys.collect::<StdResult<Vec<u64>>>().unwrap()
+}
+
+pub fn set_btc_price(app: &mut ElysApp, btc: u64) {
+    let prices = vec![
+        Price::new(
+            "btc",
+            Decimal::from_atomics(Uint128::new(btc as u128), 0).unwrap(),
+        ),
+        Price::new(USDC, Decimal::one()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+}
+
+/// Copy of the contract storage, read with the same `states` as the contract.
+pub fn snapshot(app: &ElysApp, addr: &Addr) -> MockStorage {
+    let mut storage = MockStorage::new();
+    for (key, value) in app.dump_wasm_raw(addr) {
+        storage.set(&key, &value);
+    }
+    storage
+}
+
+/// Checks the order book invariants, `context` describes the last action in
+/// the failure message.
+pub fn check_invariants(app: &ElysApp, addr: &Addr, context: &str) {
+    let storage = snapshot(app, addr);
+
+    let pending_spot: BTreeMap<u64, SpotOrder> = PENDING_SPOT_ORDER
+        .range(&storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .unwrap();
+    let pending_perpetual: BTreeMap<u64, PerpetualOrderV2> = PENDING_PERPETUAL_ORDER_V2
+        .range(&storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()
+        .unwrap();
+
+    // every pending spot order is in the sorted vector of its key exactly once
+    let mut sorted_keys: HashMap<u64, String> = HashMap::new();
+    for entry in SORTED_PENDING_SPOT_ORDER.range(&storage, None, None, Order::Ascending) {
+        let (key, ids) = entry.unwrap();
+        let mut previous_rate = Decimal::zero();
+        for id in ids {
+            let order = pending_spot.get(&id).unwrap_or_else(|| {
+                panic!("{context}: spot order {id} is sorted in {key:?} but not pending")
+            });
+            assert!(
+                sorted_keys.insert(id, key.clone()).is_none(),
+                "{context}: spot order {id} is sorted twice"
+            );
+            let rate = order.trigger().rate;
+            assert!(
+                previous_rate <= rate,
+                "{context}: {key:?} is not sorted by price"
+            );
+            previous_rate = rate;
+        }
+    }
+    for (id, order) in pending_spot.iter() {
+        assert_eq!(order.status, Status::Pending, "{context}: spot order {id}");
+        assert_eq!(
+            sorted_keys.get(id),
+            Some(&order.gen_key().unwrap()),
+            "{context}: spot order {id} is not in the vector of its key"
+        );
+    }
+
+    // same for the perpetual orders
+    let mut sorted_keys: HashMap<u64, String> = HashMap::new();
+    for entry in SORTED_PENDING_PERPETUAL_ORDER.range(&storage, None, None, Order::Ascending) {
+        let (key, ids) = entry.unwrap();
+        let mut previous_rate = Decimal::zero();
+        for id in ids {
+            let order = pending_perpetual.get(&id).unwrap_or_else(|| {
+                panic!("{context}: perpetual order {id} is sorted in {key:?} but not pending")
+            });
+            assert!(
+                sorted_keys.insert(id, key.clone()).is_none(),
+                "{context}: perpetual order {id} is sorted twice"
+            );
+            let rate = order.trigger_price.as_ref().unwrap().rate;
+            assert!(
+                previous_rate <= rate,
+                "{context}: {key:?} is not sorted by price"
+            );
+            previous_rate = rate;
+        }
+    }
+    for (id, order) in pending_perpetual.iter() {
+        assert_eq!(
+            order.status,
+            Status::Pending,
+            "{context}: perpetual order {id}"
+        );
+        assert_eq!(
+            sorted_keys.get(id),
+            Some(&order.gen_key().unwrap()),
+            "{context}: perpetual order {id} is not in the vector of its key"
+        );
+    }
+
+    // the contract holds the escrow of the pending orders and liquidation
+    // guards, and the collected fees
+    let mut escrowed: BTreeMap<String, Uint128> = BTreeMap::new();
+    let mut escrow = |denom: &str, amount: Uint128| {
+        *escrowed.entry(denom.to_string()).or_default() += amount;
+    };
+    for order in pending_spot.values() {
+        escrow(&order.order_amount.denom, order.order_amount.amount);
+    }
+    for order in pending_perpetual.values() {
+        if order.order_type == PerpetualOrderType::LimitOpen {
+            escrow(&order.collateral.denom, order.collateral.amount);
+        }
+    }
+    for entry in LIQUIDATION_GUARDS.range(&storage, None, None, Order::Ascending) {
+        let (_, guard) = entry.unwrap();
+        escrow(&guard.escrow.denom, guard.escrow.amount);
+    }
+    for entry in FEES_COLLECTED.range(&storage, None, None, Order::Ascending) {
+        let (denom, amount) = entry.unwrap();
+        escrow(&denom, amount);
+    }
+    let balances: BTreeMap<String, Uint128> = app
+        .wrap()
+        .query_all_balances(addr)
+        .unwrap()
+        .into_iter()
+        .map(|coin| (coin.denom, coin.amount))
+        .filter(|(_, amount)| !amount.is_zero())
+        .collect();
+    escrowed.retain(|_, amount| !amount.is_zero());
+    assert_eq!(balances, escrowed, "{context}: escrowed balances");
+
+    assert_eq!(
+        NUMBER_OF_PENDING_ORDER.load(&storage).unwrap(),
+        (pending_spot.len() + pending_perpetual.len()) as u64,
+        "{context}: number of pending orders"
+    );
+}
@@ -0,0 +1,58 @@
+use super::harness::*;
+use super::*;
+
+/// Seeds run by default, raise it with `INVARIANT_SEEDS` to fuzz longer.
+const DEFAULT_SEEDS: u64 = 16;
+const STEPS: usize = 40;
+
+// This test case runs random sequences of order creations, cancellations,
+// price moves and order processing on spot and perpetual orders, and checks
+// the order book invariants after every step.
+#[test]
+fn random_order_book_sequences() {
+    let seeds = std::env::var("INVARIANT_SEEDS")
+        .ok()
+        .and_then(|seeds| seeds.parse().ok())
+        .unwrap_or(DEFAULT_SEEDS);
+
+    for seed in 0..seeds {
+        run_sequence(seed);
+    }
+}
+
+fn run_sequence(seed: u64) {
+    let wallets = USERS
+        .iter()
+        .map(|user| (*user, vec![coin(1_000, "btc"), coin(100_000_000, USDC)]))
+        .collect();
+    let mut app = ElysApp::new_with_wallets(wallets);
+    set_btc_price(&mut app, 30000);
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let mut rng = Rng::new(seed);
+    let mut history: Vec<Action> = vec![];
+    for _ in 0..STEPS {
+        let action = Action::random(&mut rng, &snapshot(&app, &addr));
+        action.apply(&mut app, &addr);
+        history.push(action);
+        check_invariants(&app, &addr, &format!("seed {seed}, actions {history:?}"));
+    }
+}
@@ -0,0 +1,80 @@
+use cosmwasm_std::{Int128, SignedDecimal};
+
+use super::top_up_and_refund::{mtp, USDC};
+use super::*;
+use crate::action::sudo::process_liquidation_guards::MAX_LIQUIDATION_GUARDS_PER_BLOCK;
+
+// This test case verifies that each block visits a bounded number of guards
+// and that the next block resumes after the last guard visited.
+// - "user" guards eleven unhealthy positions, one more than a block visits.
+// - The first block tops up positions 1 to 10.
+// - The second block tops up position 11 then wraps around to positions 1 to 9,
+//   position 10 is left for the third block.
+#[test]
+fn guards_per_block() {
+    let n_positions = MAX_LIQUIDATION_GUARDS_PER_BLOCK as u64 + 1;
+    let wallets = vec![("user", coins(500 * n_positions as u128, USDC))];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let mtps: Vec<Mtp> = (1..=n_positions)
+        .map(|id| mtp(id, SignedDecimal::one()))
+        .collect();
+    app.init_modules(|router, _, store| router.custom.set_mtp(store, &mtps))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    for position_id in 1..=n_positions {
+        app.execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreateLiquidationGuard {
+                position_id,
+                health_threshold: SignedDecimal::from_str("1.2").unwrap(),
+                top_up_amount: Uint128::new(300),
+            },
+            &coins(500, USDC),
+        )
+        .unwrap();
+    }
+
+    let collaterals = |app: &mut ElysApp| -> Vec<Int128> {
+        app.init_modules(|_, _, store| PERPETUAL_OPENED_POSITION.load(store).unwrap())
+            .iter()
+            .map(|mtp| mtp.collateral)
+            .collect()
+    };
+
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let mut expected = vec![Int128::new(1300); MAX_LIQUIDATION_GUARDS_PER_BLOCK];
+    expected.push(Int128::new(1000));
+    assert_eq!(collaterals(&mut app), expected);
+
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    // the second top up is capped by the 200 USDC left in the escrow
+    let mut expected = vec![Int128::new(1500); MAX_LIQUIDATION_GUARDS_PER_BLOCK - 1];
+    expected.push(Int128::new(1300));
+    expected.push(Int128::new(1300));
+    assert_eq!(collaterals(&mut app), expected);
+}
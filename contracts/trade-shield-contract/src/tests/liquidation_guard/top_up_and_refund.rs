@@ -0,0 +1,178 @@
+use cosmwasm_std::{Int128, SignedDecimal, SignedDecimal256};
+use elys_bindings::trade_shield::msg::query_resp::GetLiquidationGuardsResp;
+
+use super::*;
+
+pub const USDC: &str = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+pub fn mtp(id: u64, mtp_health: SignedDecimal) -> Mtp {
+    Mtp {
+        address: "user".to_owned(),
+        amm_pool_id: 1,
+        borrow_interest_paid_collateral: Int128::zero(),
+        borrow_interest_paid_custody: Int128::zero(),
+        borrow_interest_unpaid_collateral: Int128::zero(),
+        collateral_asset: USDC.to_owned(),
+        collateral: Int128::new(1000),
+        consolidate_leverage: SignedDecimal::zero(),
+        custody: Int128::new(5000),
+        custody_asset: USDC.to_owned(),
+        funding_fee_paid_collateral: Int128::zero(),
+        funding_fee_paid_custody: Int128::zero(),
+        funding_fee_received_collateral: Int128::zero(),
+        funding_fee_received_custody: Int128::zero(),
+        id,
+        leverage: SignedDecimal::from_atomics(Int128::new(5), 0).unwrap(),
+        liabilities: Int128::zero(),
+        liabilities_asset: USDC.to_owned(),
+        mtp_health,
+        open_price: SignedDecimal::zero(),
+        position: PerpetualPosition::Long as i32,
+        sum_collateral: Int128::zero(),
+        take_profit_borrow_rate: SignedDecimal::zero(),
+        take_profit_custody: Int128::zero(),
+        take_profit_liabilities: Int128::zero(),
+        take_profit_price: SignedDecimal256::zero(),
+        trading_asset: "uatom".to_owned(),
+        stop_loss_price: SignedDecimal::zero(),
+        last_interest_calc_time: None,
+        last_interest_calc_block: None,
+        last_funding_calc_time: None,
+        last_funding_calc_block: None,
+    }
+}
+
+// This test case verifies that a liquidation guard tops up its position from
+// the escrow and refunds what is left once the position is closed.
+// - "user" escrows 500 USDC to add 300 USDC whenever the health of position 2
+//   drops below 1.2.
+// - The position health is 1.0, the first block adds 300 USDC of collateral.
+// - The position recovers, nothing is added on the next block.
+// - The position is closed, the guard is kept while its query fails and the
+//   200 USDC left are refunded to "user" once it is cancelled.
+#[test]
+fn top_up_and_refund() {
+    let wallets = vec![("user", coins(1000, USDC))];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    app.init_modules(|router, _, store| {
+        router
+            .custom
+            .set_mtp(store, &vec![mtp(2, SignedDecimal::one())])
+    })
+    .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let create_msg = |position_id: u64| ExecuteMsg::CreateLiquidationGuard {
+        position_id,
+        health_threshold: SignedDecimal::from_str("1.2").unwrap(),
+        top_up_amount: Uint128::new(300),
+    };
+
+    // The escrow must be in the collateral denom of an existing position.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &create_msg(7),
+        &coins(500, USDC),
+    )
+    .unwrap_err();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &create_msg(2),
+        &coins(500, USDC),
+    )
+    .unwrap();
+
+    let guards = |app: &ElysApp| -> Vec<LiquidationGuard> {
+        let resp: GetLiquidationGuardsResp = app
+            .wrap()
+            .query_wasm_smart(
+                &addr,
+                &QueryMsg::GetLiquidationGuards {
+                    owner: "user".to_string(),
+                    pagination: None,
+                },
+            )
+            .unwrap();
+        resp.guards
+    };
+    let collateral = |app: &mut ElysApp| -> Int128 {
+        app.init_modules(|_, _, store| PERPETUAL_OPENED_POSITION.load(store).unwrap())
+            .iter()
+            .find(|mtp| mtp.id == 2)
+            .unwrap()
+            .collateral
+    };
+
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    assert_eq!(collateral(&mut app), Int128::new(1300));
+    assert_eq!(guards(&app)[0].escrow, coin(200, USDC));
+    assert_eq!(
+        app.wrap().query_balance(&addr, USDC).unwrap(),
+        coin(200, USDC)
+    );
+
+    app.init_modules(|router, _, store| {
+        router.custom.set_mtp(
+            store,
+            &vec![mtp(2, SignedDecimal::from_str("1.5").unwrap())],
+        )
+    })
+    .unwrap();
+
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    assert_eq!(guards(&app)[0].escrow, coin(200, USDC));
+
+    app.init_modules(|router, _, store| router.custom.set_mtp(store, &vec![]))
+        .unwrap();
+
+    // The mocked module fails the query of a closed position, the guard is
+    // kept until the owner cancels it.
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    assert_eq!(guards(&app)[0].escrow, coin(200, USDC));
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CancelLiquidationGuard { position_id: 2 },
+        &[],
+    )
+    .unwrap();
+
+    assert!(guards(&app).is_empty());
+    assert_eq!(
+        app.wrap().query_balance("user", USDC).unwrap(),
+        coin(700, USDC)
+    );
+    assert_eq!(
+        app.wrap().query_balance(&addr, USDC).unwrap(),
+        coin(0, USDC)
+    );
+}
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use crate::{
     states::*,
-    types::{PerpetualOrderV2, SpotOrder, Status},
+    types::{Params, PerpetualOrderV2, SpotOrder, Status},
 };
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError, StdResult};
@@ -35,7 +35,7 @@ pub fn instantiate(
             let mut vec = SORTED_PENDING_SPOT_ORDER
                 .may_load(deps.storage, key.as_str())?
                 .unwrap_or(vec![]);
-            let index = SpotOrder::binary_search(&order.order_price.rate, deps.storage, &vec)?;
+            let index = SpotOrder::binary_search(&order.trigger().rate, deps.storage, &vec)?;
             if vec.len() <= index {
                 vec.push(order.order_id)
             } else {
@@ -108,15 +108,7 @@ pub fn instantiate(
     let admin = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w".to_string();
     PARAMS_ADMIN.save(deps.storage, &admin)?;
 
-    let state = true;
-    STAKE_ENABLED.save(deps.storage, &state)?;
-    MARKET_ORDER_ENABLED.save(deps.storage, &state)?;
-    SWAP_ENABLED.save(deps.storage, &state)?;
-    PROCESS_ORDERS_ENABLED.save(deps.storage, &state)?;
-    PERPETUAL_ENABLED.save(deps.storage, &state)?;
-    REWARD_ENABLED.save(deps.storage, &state)?;
-    LEVERAGE_ENABLED.save(deps.storage, &state)?;
-    LIMIT_PROCESS_ORDER.save(deps.storage, &None)?;
+    PARAMS.save(deps.storage, &Params::default())?;
 
     Ok(Response::new())
 }
@@ -16,7 +16,9 @@ use std::str::FromStr;
 
 mod create_spot_order {
     use super::*;
+    use crate::msg::query_resp::GetSpotOrderResp;
     mod coin_number;
+    mod explicit_routes;
     mod not_enough_fund;
     mod order_price_denom;
     mod order_same_denom;
@@ -66,13 +68,17 @@ mod process_spot_order {
     mod successful_process_5_of_10_orders;
     mod successful_process_limit_sell_order;
     mod successful_process_stop_loss_order;
+    mod successful_process_stop_loss_order_with_usd_trigger;
+    mod triggered_limit_sell_waits_for_limit;
 }
 
 mod create_perpetual_order {
     use super::*;
     mod change_trigger_price;
     mod coin_number;
+    mod market_open_with_stop_loss;
     mod reproduce_testnet_issue_create_perpetual_market_open_order;
+    mod stop_loss_price_side;
     mod successful_create_perpetual_market_close;
     mod successful_create_perpetual_market_order;
     mod successful_create_perpetual_order;
@@ -126,6 +132,105 @@ mod leveragelp_open {
     mod invalid_leverage;
 }
 
+mod amm_pool_position {
+    use super::*;
+    mod join_and_exit_pool;
+}
+
+mod order_events {
+    use super::*;
+    mod spot_order_lifecycle;
+}
+
+mod perpetual_update_position {
+    use super::*;
+    mod reprice_linked_orders;
+}
+
+mod set_params {
+    use super::*;
+    mod order_limits;
+}
+
+mod protocol_fees {
+    use super::*;
+    mod market_order_fee;
+    mod referrer_fee_share;
+}
+
+mod discount_override {
+    use super::*;
+    mod expiring_discount;
+    mod migrated_discount;
+}
+
+mod pause {
+    use super::*;
+    mod force_cancel_all;
+    mod process_orders_paused;
+}
+
+mod roles {
+    use super::*;
+    mod role_permissions;
+}
+
+mod liquidation_guard {
+    use super::*;
+    mod guards_per_block;
+    mod top_up_and_refund;
+}
+
+mod auto_compound {
+    use super::*;
+    use crate::msg::query_resp::GetAutoCompoundResp;
+    mod clock_end_block_runs;
+    mod runs_per_block;
+}
+
+mod batch {
+    use super::*;
+    use elys_bindings::trade_shield::events;
+    mod claim_swap_stake;
+    mod close_output_is_returned_collateral;
+}
+
+mod conditional_order {
+    use super::*;
+    use crate::msg::query_resp::GetConditionalOrdersResp;
+    use elys_bindings::trade_shield::events;
+    mod close_short_on_btc_price;
+}
+
+mod grid {
+    use super::*;
+    mod round_trip;
+}
+
+mod trading_grant {
+    use super::*;
+    use crate::msg::query_resp::GetSpotOrderResp;
+    mod delegated_orders;
+}
+
+mod referral {
+    use super::*;
+    mod referral_volume;
+}
+
+mod trading_stats {
+    use super::*;
+    mod cancelled_orders;
+    mod daily_volume;
+    mod perpetual_notional;
+}
+
+mod invariants {
+    use super::*;
+    mod harness;
+    mod order_book;
+}
+
 pub use mock::instantiate::*;
 mod mock {
     pub mod instantiate;
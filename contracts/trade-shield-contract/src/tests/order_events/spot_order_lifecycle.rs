@@ -0,0 +1,142 @@
+use crate::events;
+use crate::tests::get_order_id_from_events::get_events_of_type;
+
+use super::*;
+
+fn attr(event: &Event, key: &str) -> String {
+    event
+        .attributes
+        .iter()
+        .find(|attr| attr.key == key)
+        .map(|attr| attr.value.clone())
+        .unwrap()
+}
+
+// This test case follows a "limit sell" order through its lifecycle and verifies
+// that each step is announced with its typed event and consistent attributes.
+// - The order is created at a rate of 25,000 USDC per BTC.
+// - The BTC price rises to 30,000 USDC and the order is triggered then executed.
+// - A second order is created and cancelled by its owner.
+#[test]
+fn spot_order_lifecycle() {
+    let usdc = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+    let wallet = vec![("user", coins(4, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices_at_t0 = vec![
+        Price::new("btc", Decimal::from_str("20000.0").unwrap()),
+        Price::new(usdc, Decimal::from_str("1.0").unwrap()),
+    ];
+    let prices_at_t1 = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new(usdc, Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices_at_t0))
+        .unwrap();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let create_order_msg = ExecuteMsg::CreateSpotOrder {
+        order_type: SpotOrderType::LimitSell,
+        order_price: Some(OrderPrice {
+            base_denom: "btc".to_string(),
+            quote_denom: usdc.to_string(),
+            rate: Decimal::from_str("25000").unwrap(),
+        }),
+        order_source_denom: "btc".to_string(),
+        order_target_denom: usdc.to_string(),
+        routes: None,
+        trigger_price: None,
+    };
+
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &create_order_msg,
+            &coins(2, "btc"),
+        )
+        .unwrap();
+
+    let created = get_events_of_type(&resp.events, events::ORDER_CREATED);
+    assert_eq!(created.len(), 1);
+    let order_id = attr(created[0], events::ORDER_ID_KEY);
+    assert_eq!(attr(created[0], events::ORDER_KIND_KEY), "spot");
+    assert_eq!(attr(created[0], events::OWNER_KEY), "user");
+    assert_eq!(attr(created[0], events::PAIR_KEY), format!("btc/{}", usdc));
+    assert_eq!(attr(created[0], events::PRICE_KEY), "25000");
+    assert_eq!(attr(created[0], events::AMOUNTS_KEY), "2btc");
+
+    // The price is not met, nothing is triggered.
+    let resp = app
+        .wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+    assert!(get_events_of_type(&resp.events, events::ORDER_TRIGGERED).is_empty());
+
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices_at_t1))
+        .unwrap();
+
+    let resp = app
+        .wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+
+    let triggered = get_events_of_type(&resp.events, events::ORDER_TRIGGERED);
+    let executed = get_events_of_type(&resp.events, events::ORDER_EXECUTED);
+    assert_eq!(triggered.len(), 1);
+    assert_eq!(executed.len(), 1);
+    assert_eq!(attr(triggered[0], events::ORDER_ID_KEY), order_id);
+    assert_eq!(attr(executed[0], events::ORDER_ID_KEY), order_id);
+
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &create_order_msg,
+            &coins(2, "btc"),
+        )
+        .unwrap();
+    let second_order_id: u64 = attr(
+        get_events_of_type(&resp.events, events::ORDER_CREATED)[0],
+        events::ORDER_ID_KEY,
+    )
+    .parse()
+    .unwrap();
+
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CancelSpotOrder {
+                order_id: second_order_id,
+            },
+            &[],
+        )
+        .unwrap();
+
+    let cancelled = get_events_of_type(&resp.events, events::ORDER_CANCELLED);
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(
+        attr(cancelled[0], events::ORDER_ID_KEY),
+        second_order_id.to_string()
+    );
+    assert_eq!(attr(cancelled[0], events::REASON_KEY), "cancelled by owner");
+}
@@ -0,0 +1,175 @@
+use cosmwasm_std::{BlockInfo, Timestamp};
+
+use crate::tests::get_order_id_from_events::get_attr_from_events;
+
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+// This test case verifies that pausing the contract blocks new orders and the
+// processing of orders while pending orders can still be cancelled.
+// - "user" has three pending limit sell orders of 2 BTC on BTC/USDC.
+// - While paused, "user" cancels one order and the admin force cancels the
+//   other two, one per call.
+// - Every escrowed BTC is refunded to "user".
+#[test]
+fn force_cancel_all() {
+    let wallets = vec![("owner", coins(6, "btc")), ("user", coins(10, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let spot_orders: Vec<SpotOrder> = (0..3)
+        .map(|order_id| {
+            SpotOrder::new(
+                order_id,
+                SpotOrderType::LimitSell,
+                coin(2, "btc"),
+                Addr::unchecked("user"),
+                "usdc".to_string(),
+                SpotOrderExtras {
+                    order_price: Some(OrderPrice {
+                        base_denom: "btc".to_string(),
+                        quote_denom: "usdc".to_string(),
+                        rate: Decimal::from_str("40000").unwrap(),
+                    }),
+                    ..Default::default()
+                },
+                &BlockInfo {
+                    height: 50,
+                    time: Timestamp::from_seconds(600),
+                    chain_id: "elys-app".to_string(),
+                },
+            )
+        })
+        .collect();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders,
+                perpetual_orders: vec![],
+            },
+            &coins(6, "btc"),
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // Only the params admin can pause the contract.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Unauthorized {
+            sender: Addr::unchecked("user")
+        },
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::Pause {},
+        &[],
+    )
+    .unwrap();
+
+    // New orders are rejected.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::LimitSell,
+                order_price: Some(OrderPrice {
+                    base_denom: "btc".to_string(),
+                    quote_denom: "usdc".to_string(),
+                    rate: Decimal::from_str("40000").unwrap(),
+                }),
+                order_source_denom: "btc".to_string(),
+                order_target_denom: "usdc".to_string(),
+                routes: None,
+                trigger_price: None,
+            },
+            &coins(2, "btc"),
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Paused, err.downcast().unwrap());
+
+    // Orders are not processed.
+    let err = app
+        .wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap_err();
+    assert_eq!(ContractError::Paused, err.downcast().unwrap());
+
+    // The owner of an order can still cancel it.
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CancelSpotOrder { order_id: 0 },
+        &[],
+    )
+    .unwrap();
+
+    let force_cancel = ExecuteMsg::ForceCancelAll {
+        pair: TradingPair {
+            base_denom: "usdc".to_string(),
+            quote_denom: "btc".to_string(),
+        },
+        limit: Some(1),
+    };
+
+    let resp = app
+        .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &force_cancel, &[])
+        .unwrap();
+    assert_eq!(
+        get_attr_from_events(&resp.events, "remaining"),
+        Some("1".to_string())
+    );
+
+    let resp = app
+        .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &force_cancel, &[])
+        .unwrap();
+    assert_eq!(
+        get_attr_from_events(&resp.events, "remaining"),
+        Some("0".to_string())
+    );
+
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "btc")
+            .unwrap()
+            .amount
+            .u128(),
+        16
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance(&addr, "btc")
+            .unwrap()
+            .amount
+            .u128(),
+        0
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+    app.wasm_sudo(addr.clone(), &SudoMsg::ClockEndBlock {})
+        .unwrap();
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+// This test case verifies that a keeper cannot process orders through the
+// ProcessOrders message while the contract is paused.
+#[test]
+fn process_orders_paused() {
+    let mut app = ElysApp::new();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::GrantRole {
+            role: Role::Keeper,
+            address: "keeper".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::Pause {},
+        &[],
+    )
+    .unwrap();
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("keeper"),
+            addr.clone(),
+            &ExecuteMsg::ProcessOrders {},
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(ContractError::Paused, err.downcast().unwrap());
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("keeper"),
+        addr,
+        &ExecuteMsg::ProcessOrders {},
+        &[],
+    )
+    .unwrap();
+}
@@ -0,0 +1,201 @@
+use cosmwasm_std::{Addr, Decimal, Int128, SignedDecimal, SignedDecimal256};
+use elys_bindings::trade_shield::msg::query_resp::GetPerpetualOrderResp;
+use std::str::FromStr;
+
+use crate::tests::get_order_id_from_events::get_attr_from_events;
+
+use super::*;
+
+// This test case verifies that updating the stop loss and take profit of an open position
+// forwards the update to the perpetual module and reprices the linked pending orders.
+#[test]
+fn reprice_linked_orders() {
+    let mut app = ElysApp::new();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    let mtp_id = 2;
+    let usdc = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+    let mtps = vec![Mtp {
+        address: "user".to_owned(),
+        amm_pool_id: 1,
+        borrow_interest_paid_collateral: Int128::zero(),
+        borrow_interest_paid_custody: Int128::zero(),
+        borrow_interest_unpaid_collateral: Int128::zero(),
+        collateral_asset: usdc.to_owned(),
+        collateral: Int128::new(100000000),
+        consolidate_leverage: SignedDecimal::zero(),
+        custody: Int128::new(100000000 * 5),
+        custody_asset: usdc.to_owned(),
+        funding_fee_paid_collateral: Int128::zero(),
+        funding_fee_paid_custody: Int128::zero(),
+        funding_fee_received_collateral: Int128::zero(),
+        funding_fee_received_custody: Int128::zero(),
+        id: mtp_id,
+        leverage: SignedDecimal::from_atomics(Int128::new(5), 0).unwrap(),
+        liabilities: Int128::zero(),
+        liabilities_asset: usdc.to_owned(),
+        mtp_health: SignedDecimal::one(),
+        open_price: SignedDecimal::from_str("0.228252865828856914").unwrap(),
+        position: PerpetualPosition::Short as i32,
+        sum_collateral: Int128::zero(),
+        take_profit_borrow_rate: SignedDecimal::zero(),
+        take_profit_custody: Int128::zero(),
+        take_profit_liabilities: Int128::zero(),
+        take_profit_price: SignedDecimal256::from_str("0.18").unwrap(),
+        trading_asset: "uelys".to_owned(),
+        stop_loss_price: SignedDecimal::zero(),
+        last_interest_calc_time: None,
+        last_interest_calc_block: None,
+        last_funding_calc_time: None,
+        last_funding_calc_block: None,
+    }];
+
+    app.init_modules(|router, _, store| router.custom.set_mtp(store, &mtps))
+        .unwrap();
+    let prices = vec![
+        Price::new(usdc, Decimal::one()),
+        Price::new("uelys", Decimal::from_str("0.23").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // Create a StopLoss and a LimitClose order linked to the position.
+    let mut order_ids = vec![];
+    for (order_type, rate) in [
+        (PerpetualOrderType::StopLoss, "0.25"),
+        (PerpetualOrderType::LimitClose, "0.18"),
+    ] {
+        let resp = app
+            .execute_contract(
+                Addr::unchecked("user"),
+                addr.clone(),
+                &ExecuteMsg::CreatePerpetualOrder {
+                    position: None,
+                    leverage: None,
+                    trading_asset: None,
+                    take_profit_price: None,
+                    stop_loss_price: None,
+                    order_type,
+                    trigger_price: Some(OrderPrice {
+                        base_denom: usdc.to_string(),
+                        quote_denom: "uelys".to_string(),
+                        rate: Decimal::from_str(rate).unwrap(),
+                    }),
+                    position_id: Some(mtp_id),
+                },
+                &[],
+            )
+            .unwrap();
+        let order_id: u64 = get_attr_from_events(&resp.events, "order_id")
+            .unwrap()
+            .parse()
+            .unwrap();
+        order_ids.push(order_id);
+    }
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::PerpetualUpdateStopLoss {
+            id: mtp_id,
+            price: SignedDecimal::from_str("0.3").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let last_module = app
+        .init_modules(|router, _, store| router.custom.get_last_module(store).unwrap())
+        .unwrap();
+    assert_eq!(last_module, "PerpetualUpdateStopLoss");
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::PerpetualUpdateTakeProfitPrice {
+            id: mtp_id,
+            price: SignedDecimal256::from_str("0.15").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let mtp = app
+        .init_modules(|_, _, store| PERPETUAL_OPENED_POSITION.load(store).unwrap())
+        .remove(0);
+    assert_eq!(mtp.stop_loss_price, SignedDecimal::from_str("0.3").unwrap());
+    assert_eq!(
+        mtp.take_profit_price,
+        SignedDecimal256::from_str("0.15").unwrap()
+    );
+
+    for (order_id, rate) in order_ids.into_iter().zip(["0.3", "0.15"]) {
+        let GetPerpetualOrderResp {
+            order: PerpetualOrderPlus { order, .. },
+        } = app
+            .wrap()
+            .query_wasm_smart(addr.as_str(), &QueryMsg::GetPerpetualOrder { id: order_id })
+            .unwrap();
+
+        assert_eq!(order.status, Status::Pending);
+        assert_eq!(
+            order.trigger_price.unwrap().rate,
+            Decimal::from_str(rate).unwrap()
+        );
+    }
+}
+
+// Updating a position that the sender does not own is rejected.
+#[test]
+fn position_not_found() {
+    let mut app = ElysApp::new();
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![],
+        perpetual_orders: vec![],
+    };
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr,
+        &ExecuteMsg::PerpetualUpdateStopLoss {
+            id: 7,
+            price: SignedDecimal::from_str("0.3").unwrap(),
+        },
+        &[],
+    )
+    .unwrap_err();
+}
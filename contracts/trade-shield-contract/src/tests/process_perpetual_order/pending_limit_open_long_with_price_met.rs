@@ -102,6 +102,15 @@ impl Module for ElysModuleWrapper {
                         decimal: 6,
                     },
                 })?),
+                USDC_DENOM => Ok(to_json_binary(&OracleAssetInfoResponse {
+                    asset_info: OracleAssetInfo {
+                        denom,
+                        display: "USDC".to_string(),
+                        band_ticker: "".to_string(),
+                        elys_ticker: "".to_string(),
+                        decimal: 6,
+                    },
+                })?),
                 _ => panic!("OracleAssetInfo: notfound: {}", denom),
             },
             ElysQuery::AmmPriceByDenom { token_in, .. } => match token_in.denom.as_str() {
@@ -365,6 +374,7 @@ fn pending_limit_open_long_order_with_price_met() {
                 leverage: Some(SignedDecimal::from_str("5.0").unwrap()),
                 trading_asset: Some(ATOM_DENOM.to_string()),
                 take_profit_price: Some(SignedDecimal256::from_str("30.0").unwrap()),
+                stop_loss_price: None,
                 order_type: PerpetualOrderType::LimitOpen,
                 trigger_price: Some(OrderPrice {
                     base_denom: USDC_DENOM.to_string(),
@@ -377,7 +387,7 @@ fn pending_limit_open_long_order_with_price_met() {
         )
         .unwrap();
 
-    let order_id = get_attr_from_events(&resp.events, "perpetual_order_id").unwrap();
+    let order_id = get_attr_from_events(&resp.events, "order_id").unwrap();
 
     let GetPerpetualOrderResp {
         order: PerpetualOrderPlus { order, .. },
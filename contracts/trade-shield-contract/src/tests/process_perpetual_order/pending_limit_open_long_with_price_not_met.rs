@@ -365,6 +365,7 @@ fn pending_limit_open_long_order_with_price_not_met() {
                 leverage: Some(SignedDecimal::from_str("5.0").unwrap()),
                 trading_asset: Some(ATOM_DENOM.to_string()),
                 take_profit_price: Some(SignedDecimal256::from_str("30.0").unwrap()),
+                stop_loss_price: None,
                 order_type: PerpetualOrderType::LimitOpen,
                 trigger_price: Some(OrderPrice {
                     base_denom: USDC_DENOM.to_string(),
@@ -377,7 +378,7 @@ fn pending_limit_open_long_order_with_price_not_met() {
         )
         .unwrap();
 
-    let order_id = get_attr_from_events(&resp.events, "perpetual_order_id").unwrap();
+    let order_id = get_attr_from_events(&resp.events, "order_id").unwrap();
 
     let GetPerpetualOrderResp {
         order: PerpetualOrderPlus { order, .. },
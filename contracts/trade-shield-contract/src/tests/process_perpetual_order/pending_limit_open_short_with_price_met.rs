@@ -102,11 +102,11 @@ impl Module for ElysModuleWrapper {
                         decimal: 6,
                     },
                 })?),
-                _ => panic!("OracleAssetInfo: notfound: {}", denom),
+                _ => bail!("OracleAssetInfo: notfound: {}", denom),
             },
             ElysQuery::AmmPriceByDenom { token_in, .. } => match token_in.denom.as_str() {
                 ATOM_DENOM => Ok(to_json_binary(&Decimal::from_str("12.38").unwrap())?),
-                _ => panic!("AmmPriceByDenom: notfound: {}", token_in.denom.as_str()),
+                _ => bail!("AmmPriceByDenom: notfound: {}", token_in.denom.as_str()),
             },
             ElysQuery::PerpetualOpenEstimation {
                 position,
@@ -365,6 +365,7 @@ fn pending_limit_open_short_order_with_price_met() {
                 leverage: Some(SignedDecimal::from_str("5.0").unwrap()),
                 trading_asset: Some(ATOM_DENOM.to_string()),
                 take_profit_price: Some(SignedDecimal256::from_str("30.0").unwrap()),
+                stop_loss_price: None,
                 order_type: PerpetualOrderType::LimitOpen,
                 trigger_price: Some(OrderPrice {
                     base_denom: USDC_DENOM.to_string(),
@@ -377,7 +378,7 @@ fn pending_limit_open_short_order_with_price_met() {
         )
         .unwrap();
 
-    let order_id = get_attr_from_events(&resp.events, "perpetual_order_id").unwrap();
+    let order_id = get_attr_from_events(&resp.events, "order_id").unwrap();
 
     let GetPerpetualOrderResp {
         order: PerpetualOrderPlus { order, .. },
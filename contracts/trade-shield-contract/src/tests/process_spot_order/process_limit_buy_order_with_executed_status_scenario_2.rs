@@ -16,7 +16,9 @@ use elys_bindings::query_resp::{
     TierCalculateDiscountResponse,
 };
 use elys_bindings::trade_shield::msg::SudoMsg;
-use elys_bindings::trade_shield::types::{OrderPrice, SpotOrder, SpotOrderType, Status};
+use elys_bindings::trade_shield::types::{
+    OrderPrice, SpotOrder, SpotOrderExtras, SpotOrderType, Status,
+};
 use elys_bindings::types::{Price, SwapAmountInRoute, SwapAmountOutRoute};
 use elys_bindings::{ElysMsg, ElysQuery};
 use elys_bindings_test::{
@@ -353,17 +355,20 @@ fn process_limit_buy_order_with_executed_status_scenario_2() {
     let order = SpotOrder::new(
         0,
         SpotOrderType::LimitBuy,
-        Some(OrderPrice {
-            // denom_in
-            base_denom: "uelys".to_string(),
-            // denom_out
-            quote_denom: "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
-                .to_string(),
-            rate: Decimal::from_str("0.258478").unwrap(),
-        }),
         coin(10_000000, "uelys"),
         Addr::unchecked("user"),
         "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+        SpotOrderExtras {
+            order_price: Some(OrderPrice {
+                // denom_in
+                base_denom: "uelys".to_string(),
+                // denom_out
+                quote_denom: "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
+                    .to_string(),
+                rate: Decimal::from_str("0.258478").unwrap(),
+            }),
+            ..Default::default()
+        },
         &BlockInfo {
             height: 50,
             time: Timestamp::from_seconds(600),
@@ -76,6 +76,14 @@ fn successful_process_3_of_5_orders() {
             reward_enabled: None,
             leverage_enabled: None,
             limit_process_order: Some(3),
+            min_order_notional: None,
+            max_pending_orders_per_user: None,
+            max_leverage: None,
+            allowed_pairs: None,
+            fee_rate: None,
+            fee_collector: None,
+            referrer_fee_share: None,
+            order_gas_limit: None,
         },
         &[],
     )
@@ -397,6 +405,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             order_id: 0,
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             order_amount: coin(1, "eth"),
             owner_address: Addr::unchecked("user"),
             order_price: OrderPrice {
@@ -424,6 +434,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -443,6 +455,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -462,6 +476,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -481,6 +497,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -499,6 +499,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             order_id: 0,
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             order_amount: coin(1, "eth"),
             owner_address: Addr::unchecked("user"),
             order_price: OrderPrice {
@@ -526,6 +528,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -545,6 +549,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -564,6 +570,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -583,6 +591,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -602,6 +612,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -621,6 +633,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -640,6 +654,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -659,6 +675,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -678,6 +696,8 @@ fn create_dummy_orders() -> Vec<SpotOrder> {
             },
             order_target_denom:
                 "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+            routes: None,
+            trigger_price: None,
             status: Status::Pending,
             date: Date {
                 height: 20,
@@ -46,15 +46,18 @@ fn successful_process_limit_sell_order() {
     let dummy_order = SpotOrder::new(
         0,
         SpotOrderType::LimitSell,
-        Some(OrderPrice {
-            base_denom: "btc".to_string(),
-            quote_denom: "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
-                .to_string(),
-            rate: Decimal::from_str("30000").unwrap(), // Rate at which BTC will be sold (30,000 USDC per BTC).
-        }),
         coin(2, "btc"), // 2 BTC to be sold.
         Addr::unchecked("user"),
         "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65".to_string(),
+        SpotOrderExtras {
+            order_price: Some(OrderPrice {
+                base_denom: "btc".to_string(),
+                quote_denom: "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65"
+                    .to_string(),
+                rate: Decimal::from_str("30000").unwrap(), // Rate at which BTC will be sold (30,000 USDC per BTC).
+            }),
+            ..Default::default()
+        },
         &BlockInfo {
             height: 50,
             time: Timestamp::from_seconds(600),
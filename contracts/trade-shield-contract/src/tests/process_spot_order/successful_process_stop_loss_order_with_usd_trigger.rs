@@ -0,0 +1,111 @@
+use super::*;
+use cosmwasm_std::{coins, BlockInfo, Coin, Timestamp};
+use process_spot_order::test_order_status::test_spot_order_status;
+// This test case verifies that a "stop-loss" order can be triggered on the ATOM/USD price
+// while the ATOM are swapped for ELYS.
+// - The trigger price of the order is 8 USD per ATOM.
+// - At 10 USD per ATOM the order stays pending.
+// - At 7 USD per ATOM the order is executed and the user receives ELYS.
+#[test]
+fn successful_process_stop_loss_order_with_usd_trigger() {
+    let wallets: Vec<(&str, Vec<Coin>)> = vec![("owner", coins(10, "uatom")), ("user", vec![])];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let usdc = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+    let prices_at_t0 = vec![
+        Price::new("uatom", Decimal::from_str("10").unwrap()),
+        Price::new("uelys", Decimal::from_str("1").unwrap()),
+        Price::new(usdc, Decimal::one()),
+    ];
+    let prices_at_t1 = vec![
+        Price::new("uatom", Decimal::from_str("7").unwrap()),
+        Price::new("uelys", Decimal::from_str("1").unwrap()),
+        Price::new(usdc, Decimal::one()),
+    ];
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let dummy_order = SpotOrder::new(
+        0,
+        SpotOrderType::StopLoss,
+        coin(10, "uatom"),
+        Addr::unchecked("user"),
+        "uelys".to_string(),
+        SpotOrderExtras {
+            trigger_price: Some(OrderPrice {
+                base_denom: "uatom".to_string(),
+                quote_denom: USD_DENOM.to_string(),
+                rate: Decimal::from_str("8").unwrap(),
+            }),
+            ..Default::default()
+        },
+        &BlockInfo {
+            height: 50,
+            time: Timestamp::from_seconds(600),
+            chain_id: "elys-app".to_string(),
+        },
+    );
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![dummy_order.clone()],
+        perpetual_orders: vec![],
+    };
+
+    let sudo_msg = SudoMsg::ClockEndBlock {};
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &coins(10, "uatom"),
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices_at_t0))
+        .unwrap();
+
+    app.wasm_sudo(addr.clone(), &sudo_msg).unwrap();
+
+    test_spot_order_status(
+        &app.wrap(),
+        addr.to_string(),
+        dummy_order.order_id,
+        Status::Pending,
+    );
+
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices_at_t1))
+        .unwrap();
+
+    app.wasm_sudo(addr.clone(), &sudo_msg).unwrap();
+
+    test_spot_order_status(
+        &app.wrap(),
+        addr.to_string(),
+        dummy_order.order_id,
+        Status::Executed,
+    );
+
+    assert_eq!(
+        app.wrap()
+            .query_balance(&addr, "uatom")
+            .unwrap()
+            .amount
+            .u128(),
+        0
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "uelys")
+            .unwrap()
+            .amount
+            .u128(),
+        70
+    );
+}
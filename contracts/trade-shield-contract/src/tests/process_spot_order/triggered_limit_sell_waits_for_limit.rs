@@ -0,0 +1,123 @@
+use super::*;
+use cosmwasm_std::{coins, BlockInfo, Coin, Timestamp};
+use process_spot_order::test_order_status::test_spot_order_status;
+// This test case verifies that a triggered "limit sell" order keeps its order price as limit.
+// - The order sells ATOM for ELYS once ATOM is over 8 USD, at 9 ELYS per ATOM or better.
+// - ATOM at 10 USD and ELYS at 1.25 USD trigger the order but only give 8 ELYS per ATOM,
+//   so the order stays pending and the ATOM stay in the contract.
+// - With ELYS at 1 USD the limit is met and the user receives 10 ELYS per ATOM.
+#[test]
+fn triggered_limit_sell_waits_for_limit() {
+    let wallets: Vec<(&str, Vec<Coin>)> = vec![("owner", coins(1000, "uatom")), ("user", vec![])];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let usdc = "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65";
+
+    let prices_at_t0 = vec![
+        Price::new("uatom", Decimal::from_str("10").unwrap()),
+        Price::new("uelys", Decimal::from_str("1.25").unwrap()),
+        Price::new(usdc, Decimal::one()),
+    ];
+    let prices_at_t1 = vec![
+        Price::new("uatom", Decimal::from_str("10").unwrap()),
+        Price::new("uelys", Decimal::from_str("1").unwrap()),
+        Price::new(usdc, Decimal::one()),
+    ];
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let dummy_order = SpotOrder::new(
+        0,
+        SpotOrderType::LimitSell,
+        coin(1000, "uatom"),
+        Addr::unchecked("user"),
+        "uelys".to_string(),
+        SpotOrderExtras {
+            order_price: Some(OrderPrice {
+                base_denom: "uatom".to_string(),
+                quote_denom: "uelys".to_string(),
+                rate: Decimal::from_str("9").unwrap(),
+            }),
+            trigger_price: Some(OrderPrice {
+                base_denom: "uatom".to_string(),
+                quote_denom: USD_DENOM.to_string(),
+                rate: Decimal::from_str("8").unwrap(),
+            }),
+            ..Default::default()
+        },
+        &BlockInfo {
+            height: 50,
+            time: Timestamp::from_seconds(600),
+            chain_id: "elys-app".to_string(),
+        },
+    );
+
+    let instantiate_msg = InstantiateMockMsg {
+        spot_orders: vec![dummy_order.clone()],
+        perpetual_orders: vec![],
+    };
+
+    let sudo_msg = SudoMsg::ClockEndBlock {};
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &instantiate_msg,
+            &coins(1000, "uatom"),
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices_at_t0))
+        .unwrap();
+
+    app.wasm_sudo(addr.clone(), &sudo_msg).unwrap();
+
+    test_spot_order_status(
+        &app.wrap(),
+        addr.to_string(),
+        dummy_order.order_id,
+        Status::Pending,
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance(&addr, "uatom")
+            .unwrap()
+            .amount
+            .u128(),
+        1000
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "uelys")
+            .unwrap()
+            .amount
+            .u128(),
+        0
+    );
+
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices_at_t1))
+        .unwrap();
+
+    app.wasm_sudo(addr.clone(), &sudo_msg).unwrap();
+
+    test_spot_order_status(
+        &app.wrap(),
+        addr.to_string(),
+        dummy_order.order_id,
+        Status::Executed,
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "uelys")
+            .unwrap()
+            .amount
+            .u128(),
+        10000
+    );
+}
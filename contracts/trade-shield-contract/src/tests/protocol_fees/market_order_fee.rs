@@ -0,0 +1,152 @@
+use crate::events;
+use crate::tests::get_order_id_from_events::get_events_of_type;
+use elys_bindings::trade_shield::msg::query_resp::GetFeesCollectedResp;
+
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+// This test case verifies that a protocol fee is withheld from an executed
+// market order and that the admin can withdraw it to the fee collector.
+// - The fee rate is 1% and the user sells 100 BTC at 30,000 USDC.
+// - 99 BTC are swapped and 1 BTC is kept as fee until withdrawn.
+#[test]
+fn market_order_fee() {
+    let wallet = vec![("user", coins(100, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::SetParams {
+            market_order_enabled: None,
+            stake_enabled: None,
+            process_order_enabled: None,
+            swap_enabled: None,
+            perpetual_enabled: None,
+            reward_enabled: None,
+            leverage_enabled: None,
+            limit_process_order: None,
+            min_order_notional: None,
+            max_pending_orders_per_user: None,
+            max_leverage: None,
+            allowed_pairs: None,
+            fee_rate: Some(Decimal::percent(1)),
+            fee_collector: Some("collector".to_string()),
+            referrer_fee_share: None,
+            order_gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::MarketBuy,
+                order_price: None,
+                order_source_denom: "btc".to_string(),
+                order_target_denom: "usdc".to_string(),
+                routes: None,
+                trigger_price: None,
+            },
+            &coins(100, "btc"),
+        )
+        .unwrap();
+
+    // The executed event reports the withheld fee.
+    let executed = get_events_of_type(&resp.events, events::ORDER_EXECUTED);
+    assert_eq!(executed.len(), 1);
+    assert!(executed[0]
+        .attributes
+        .iter()
+        .any(|attr| attr.key == events::FEE_KEY && attr.value == "1btc"));
+
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "usdc")
+            .unwrap()
+            .amount
+            .u128(),
+        99 * 30000
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance(&addr, "btc")
+            .unwrap()
+            .amount
+            .u128(),
+        1
+    );
+
+    let GetFeesCollectedResp { fees } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetFeesCollected {})
+        .unwrap();
+    assert_eq!(fees, coins(1, "btc"));
+
+    // Only the params admin can withdraw the fees.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::WithdrawFees { denoms: None },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::Unauthorized {
+            sender: Addr::unchecked("user")
+        },
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::WithdrawFees { denoms: None },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        app.wrap()
+            .query_balance("collector", "btc")
+            .unwrap()
+            .amount
+            .u128(),
+        1
+    );
+
+    let GetFeesCollectedResp { fees } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetFeesCollected {})
+        .unwrap();
+    assert!(fees.is_empty());
+}
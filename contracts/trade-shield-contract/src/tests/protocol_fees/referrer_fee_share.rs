@@ -0,0 +1,112 @@
+use elys_bindings::trade_shield::msg::query_resp::GetFeesCollectedResp;
+
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+// This test case verifies that the referrer of an order owner receives its
+// share of the protocol fee once the order is executed.
+// - The fee rate is 1% with half of it shared, the user sells 200 BTC.
+// - 2 BTC are withheld, 1 BTC goes to the referrer and 1 BTC stays collected.
+#[test]
+fn referrer_fee_share() {
+    let wallet = vec![("user", coins(200, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::SetParams {
+            market_order_enabled: None,
+            stake_enabled: None,
+            process_order_enabled: None,
+            swap_enabled: None,
+            perpetual_enabled: None,
+            reward_enabled: None,
+            leverage_enabled: None,
+            limit_process_order: None,
+            min_order_notional: None,
+            max_pending_orders_per_user: None,
+            max_leverage: None,
+            allowed_pairs: None,
+            fee_rate: Some(Decimal::percent(1)),
+            fee_collector: Some("collector".to_string()),
+            referrer_fee_share: Some(Decimal::percent(50)),
+            order_gas_limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::SetReferrer {
+            referrer: "referrer".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateSpotOrder {
+            order_type: SpotOrderType::MarketBuy,
+            order_price: None,
+            order_source_denom: "btc".to_string(),
+            order_target_denom: "usdc".to_string(),
+            routes: None,
+            trigger_price: None,
+        },
+        &coins(200, "btc"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "usdc")
+            .unwrap()
+            .amount
+            .u128(),
+        198 * 30000
+    );
+    assert_eq!(
+        app.wrap()
+            .query_balance("referrer", "btc")
+            .unwrap()
+            .amount
+            .u128(),
+        1
+    );
+
+    let GetFeesCollectedResp { fees } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetFeesCollected {})
+        .unwrap();
+    assert_eq!(fees, coins(1, "btc"));
+}
@@ -0,0 +1,143 @@
+use cosmwasm_std::StdError;
+use elys_bindings::trade_shield::msg::query_resp::{GetRefereesResp, GetReferralStatsResp};
+
+use super::*;
+
+// This test case verifies that a referrer can only be set once and that the
+// USD volume of the executed orders of a referee is credited to its referrer.
+// - "user" registers "referrer" and sells 2 BTC at 30,000 USDC.
+// - "referrer" is credited with 60,000 USD of referral volume.
+#[test]
+fn referral_volume() {
+    let wallet = vec![("user", coins(2_000_000, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+        Price::new(
+            "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65",
+            Decimal::from_str("1.0").unwrap(),
+        ),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // A user cannot refer itself.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::SetReferrer {
+                referrer: "user".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::StdError(StdError::generic_err("referrer: cannot refer yourself")),
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::SetReferrer {
+            referrer: "referrer".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The referrer cannot be changed once set.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::SetReferrer {
+                referrer: "other".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::StdError(StdError::generic_err("referrer: already set")),
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateSpotOrder {
+            order_type: SpotOrderType::MarketBuy,
+            order_price: None,
+            order_source_denom: "btc".to_string(),
+            order_target_denom: "usdc".to_string(),
+            routes: None,
+            trigger_price: None,
+        },
+        &coins(2_000_000, "btc"),
+    )
+    .unwrap();
+
+    let stats: GetReferralStatsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetReferralStats {
+                address: "referrer".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stats,
+        GetReferralStatsResp {
+            address: "referrer".to_string(),
+            referrer: None,
+            number_of_referees: 1,
+            referral_volume_usd: Decimal::from_str("60000").unwrap(),
+        }
+    );
+
+    let stats: GetReferralStatsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetReferralStats {
+                address: "user".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(stats.referrer, Some("referrer".to_string()));
+
+    let GetRefereesResp { referees, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetReferees {
+                referrer: "referrer".to_string(),
+                pagination: Some(PageRequest::new(10)),
+            },
+        )
+        .unwrap();
+    assert_eq!(referees.len(), 1);
+    assert_eq!(referees[0].address, "user");
+    assert_eq!(referees[0].volume_usd, Decimal::from_str("60000").unwrap());
+}
@@ -0,0 +1,210 @@
+use elys_bindings::trade_shield::msg::query_resp::{GetRolesResp, RoleGrant};
+
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+fn set_params(min_order_notional: Option<Decimal>, fee_rate: Option<Decimal>) -> ExecuteMsg {
+    ExecuteMsg::SetParams {
+        market_order_enabled: None,
+        stake_enabled: None,
+        process_order_enabled: None,
+        swap_enabled: None,
+        perpetual_enabled: None,
+        reward_enabled: None,
+        leverage_enabled: None,
+        limit_process_order: None,
+        min_order_notional,
+        max_pending_orders_per_user: None,
+        max_leverage: None,
+        allowed_pairs: None,
+        fee_rate,
+        fee_collector: None,
+        referrer_fee_share: None,
+        order_gas_limit: None,
+    }
+}
+
+fn unauthorized(err: anyhow::Error, sender: &str) {
+    assert_eq!(
+        ContractError::Unauthorized {
+            sender: Addr::unchecked(sender)
+        },
+        err.downcast().unwrap()
+    );
+}
+
+// This test case verifies that every role is limited to its own messages.
+// - The guardian can pause but not unpause the contract.
+// - The operator can change the order limits but not the fees.
+// - The keeper can force cancel orders until its role is revoked.
+#[test]
+fn role_permissions() {
+    let mut app = ElysApp::new();
+
+    let code = ContractWrapper::new(execute, instantiate, query)
+        .with_reply(reply)
+        .with_sudo(sudo);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // Only the admin can grant roles.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role: Role::Guardian,
+                address: "guardian".to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    unauthorized(err, "guardian");
+
+    for (role, address) in [
+        (Role::Guardian, "guardian"),
+        (Role::Operator, "operator"),
+        (Role::Keeper, "keeper"),
+    ] {
+        app.execute_contract(
+            Addr::unchecked(ADMIN),
+            addr.clone(),
+            &ExecuteMsg::GrantRole {
+                role,
+                address: address.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    let GetRolesResp {
+        params_admin,
+        grants,
+    } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetRoles { role: None })
+        .unwrap();
+    assert_eq!(params_admin, ADMIN);
+    assert_eq!(grants.len(), 3);
+
+    let GetRolesResp { grants, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetRoles {
+                role: Some(Role::Keeper),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        grants,
+        vec![RoleGrant {
+            role: Role::Keeper,
+            address: "keeper".to_string(),
+        }]
+    );
+
+    // guardian
+    app.execute_contract(
+        Addr::unchecked("guardian"),
+        addr.clone(),
+        &ExecuteMsg::Pause {},
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            addr.clone(),
+            &ExecuteMsg::Unpause {},
+            &[],
+        )
+        .unwrap_err();
+    unauthorized(err, "guardian");
+    let err = app
+        .execute_contract(
+            Addr::unchecked("guardian"),
+            addr.clone(),
+            &set_params(Some(Decimal::one()), None),
+            &[],
+        )
+        .unwrap_err();
+    unauthorized(err, "guardian");
+
+    // operator
+    app.execute_contract(
+        Addr::unchecked("operator"),
+        addr.clone(),
+        &set_params(Some(Decimal::one()), None),
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(
+            Addr::unchecked("operator"),
+            addr.clone(),
+            &set_params(None, Some(Decimal::percent(1))),
+            &[],
+        )
+        .unwrap_err();
+    unauthorized(err, "operator");
+
+    // keeper
+    let force_cancel = ExecuteMsg::ForceCancelAll {
+        pair: TradingPair {
+            base_denom: "btc".to_string(),
+            quote_denom: "usdc".to_string(),
+        },
+        limit: None,
+    };
+    app.execute_contract(Addr::unchecked("keeper"), addr.clone(), &force_cancel, &[])
+        .unwrap();
+    let err = app
+        .execute_contract(
+            Addr::unchecked("keeper"),
+            addr.clone(),
+            &ExecuteMsg::Pause {},
+            &[],
+        )
+        .unwrap_err();
+    unauthorized(err, "keeper");
+
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::RevokeRole {
+            role: Role::Keeper,
+            address: "keeper".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = app
+        .execute_contract(Addr::unchecked("keeper"), addr.clone(), &force_cancel, &[])
+        .unwrap_err();
+    unauthorized(err, "keeper");
+
+    // The admin keeps every permission.
+    app.execute_contract(
+        Addr::unchecked(ADMIN),
+        addr.clone(),
+        &ExecuteMsg::Unpause {},
+        &[],
+    )
+    .unwrap();
+}
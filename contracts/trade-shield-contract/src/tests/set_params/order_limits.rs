@@ -0,0 +1,302 @@
+use cosmwasm_std::StdError;
+use elys_bindings::trade_shield::msg::query_resp::TradeShieldParamsResponse;
+
+use super::*;
+
+const ADMIN: &str = "elys16xffmfa6k45j340cx5zyp66lqvuw62a0neaa7w";
+
+fn set_params_msg() -> ExecuteMsg {
+    ExecuteMsg::SetParams {
+        market_order_enabled: None,
+        stake_enabled: None,
+        process_order_enabled: None,
+        swap_enabled: None,
+        perpetual_enabled: None,
+        reward_enabled: None,
+        leverage_enabled: None,
+        limit_process_order: None,
+        min_order_notional: None,
+        max_pending_orders_per_user: None,
+        max_leverage: None,
+        allowed_pairs: None,
+        fee_rate: None,
+        fee_collector: None,
+        referrer_fee_share: None,
+        order_gas_limit: None,
+    }
+}
+
+fn limit_sell_msg() -> ExecuteMsg {
+    ExecuteMsg::CreateSpotOrder {
+        order_type: SpotOrderType::LimitSell,
+        order_price: Some(OrderPrice {
+            base_denom: "btc".to_string(),
+            quote_denom: "usdc".to_string(),
+            rate: Decimal::from_atomics(Uint128::new(40000), 0).unwrap(),
+        }),
+        order_source_denom: "btc".to_string(),
+        order_target_denom: "usdc".to_string(),
+        routes: None,
+        trigger_price: None,
+    }
+}
+
+// This test case verifies that the parameters are validated on update and
+// that the stored set is returned by GetParams.
+#[test]
+fn validated_params_update() {
+    let mut app = ElysApp::new();
+
+    let code = ContractWrapper::new(execute, instantiate, query);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    // A fee rate without a collector is rejected.
+    let mut msg = set_params_msg();
+    if let ExecuteMsg::SetParams { fee_rate, .. } = &mut msg {
+        *fee_rate = Some(Decimal::from_str("0.001").unwrap());
+    }
+    let err = app
+        .execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::StdError(StdError::generic_err(
+            "params: fee_collector is required when fee_rate is set"
+        )),
+        err.downcast().unwrap()
+    );
+
+    let mut msg = set_params_msg();
+    if let ExecuteMsg::SetParams {
+        fee_rate,
+        fee_collector,
+        max_pending_orders_per_user,
+        allowed_pairs,
+        ..
+    } = &mut msg
+    {
+        *fee_rate = Some(Decimal::from_str("0.001").unwrap());
+        *fee_collector = Some("collector".to_string());
+        *max_pending_orders_per_user = Some(1);
+        *allowed_pairs = Some(vec![TradingPair {
+            base_denom: "btc".to_string(),
+            quote_denom: "usdc".to_string(),
+        }]);
+    }
+    app.execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+        .unwrap();
+
+    let TradeShieldParamsResponse {
+        params_admin,
+        params,
+        paused,
+    } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetParams {})
+        .unwrap();
+
+    assert_eq!(params_admin, ADMIN);
+    assert!(!paused);
+    assert_eq!(params.version, PARAMS_VERSION);
+    assert_eq!(params.fee_rate, Decimal::from_str("0.001").unwrap());
+    assert_eq!(params.fee_collector, Some("collector".to_string()));
+    assert_eq!(params.max_pending_orders_per_user, Some(1));
+    assert!(params.swap_enabled);
+}
+
+// This test case verifies that new spot orders are checked against the
+// allowed pairs and the maximum number of pending orders per user.
+#[test]
+fn spot_order_limits() {
+    let wallet = vec![("user", vec![coin(4, "btc"), coin(10, "eth")])];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("eth", Decimal::from_str("2000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices).unwrap());
+
+    let code = ContractWrapper::new(execute, instantiate, query);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let mut msg = set_params_msg();
+    if let ExecuteMsg::SetParams {
+        max_pending_orders_per_user,
+        allowed_pairs,
+        ..
+    } = &mut msg
+    {
+        *max_pending_orders_per_user = Some(1);
+        *allowed_pairs = Some(vec![TradingPair {
+            base_denom: "btc".to_string(),
+            quote_denom: "usdc".to_string(),
+        }]);
+    }
+    app.execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+        .unwrap();
+
+    // The eth/usdc pair is not in the allow-list.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::LimitSell,
+                order_price: Some(OrderPrice {
+                    base_denom: "eth".to_string(),
+                    quote_denom: "usdc".to_string(),
+                    rate: Decimal::from_atomics(Uint128::new(3000), 0).unwrap(),
+                }),
+                order_source_denom: "eth".to_string(),
+                order_target_denom: "usdc".to_string(),
+                routes: None,
+                trigger_price: None,
+            },
+            &coins(10, "eth"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::PairNotAllowed {
+            base_denom: "eth".to_string(),
+            quote_denom: "usdc".to_string(),
+        },
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &limit_sell_msg(),
+        &coins(2, "btc"),
+    )
+    .unwrap();
+
+    // The second pending order goes over the limit of one per user.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &limit_sell_msg(),
+            &coins(2, "btc"),
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::TooManyPendingOrders {
+            owner: "user".to_string(),
+            limit: 1,
+        },
+        err.downcast().unwrap()
+    );
+
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "btc")
+            .unwrap()
+            .amount
+            .u128(),
+        2
+    );
+}
+
+// This test case verifies that a user at the pending order limit can still
+// place a market buy, which executes right away and never becomes pending.
+#[test]
+fn market_buy_at_pending_limit() {
+    let wallet = vec![("user", coins(4, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices).unwrap());
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let mut msg = set_params_msg();
+    if let ExecuteMsg::SetParams {
+        max_pending_orders_per_user,
+        ..
+    } = &mut msg
+    {
+        *max_pending_orders_per_user = Some(1);
+    }
+    app.execute_contract(Addr::unchecked(ADMIN), addr.clone(), &msg, &[])
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &limit_sell_msg(),
+        &coins(2, "btc"),
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreateSpotOrder {
+            order_type: SpotOrderType::MarketBuy,
+            order_price: None,
+            order_source_denom: "btc".to_string(),
+            order_target_denom: "usdc".to_string(),
+            routes: None,
+            trigger_price: None,
+        },
+        &coins(2, "btc"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        app.wrap()
+            .query_balance("user", "usdc")
+            .unwrap()
+            .amount
+            .u128(),
+        60000
+    );
+}
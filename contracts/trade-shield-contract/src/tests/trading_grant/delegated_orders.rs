@@ -0,0 +1,187 @@
+use cosmwasm_std::SignedDecimal;
+use elys_bindings::trade_shield::msg::query_resp::GetGrantsResp;
+
+use crate::tests::get_order_id_from_events::get_order_id_from_events;
+
+use super::*;
+
+// This test case verifies that a grantee can manage the orders of its granter
+// within the limits of its grant.
+// - "user" lets "bot" create and cancel orders with up to 5 BTC for an hour.
+// - "bot" creates a 2 BTC limit sell order owned by "user", a 4 BTC order
+//   would exceed the 3 BTC left.
+// - "bot" cannot amend positions, and cancels the order, refunding "user".
+// - Once revoked, "bot" cannot create orders anymore.
+#[test]
+fn delegated_orders() {
+    let wallets = vec![("bot", coins(10, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices).unwrap());
+
+    let code = ContractWrapper::new(execute, instantiate, query);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let expires_at = app.block_info().time.plus_seconds(3600);
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::Grant {
+            grantee: "bot".to_string(),
+            permissions: vec![
+                TradingPermission::CreateOrder,
+                TradingPermission::CancelOrder,
+            ],
+            spend_limit: coins(5, "btc"),
+            expires_at: Some(expires_at),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let create_order = |granter: &str, amount: u128| {
+        (
+            ExecuteMsg::OnBehalfOf {
+                granter: granter.to_string(),
+                msg: Box::new(ExecuteMsg::CreateSpotOrder {
+                    order_type: SpotOrderType::LimitSell,
+                    order_price: Some(OrderPrice {
+                        base_denom: "btc".to_string(),
+                        quote_denom: "usdc".to_string(),
+                        rate: Decimal::from_str("40000").unwrap(),
+                    }),
+                    order_source_denom: "btc".to_string(),
+                    order_target_denom: "usdc".to_string(),
+                    routes: None,
+                    trigger_price: None,
+                }),
+            },
+            coins(amount, "btc"),
+        )
+    };
+
+    // Without a grant, "user" cannot act for "bot".
+    let (msg, _) = create_order("bot", 0);
+    let err = app
+        .execute_contract(Addr::unchecked("user"), addr.clone(), &msg, &[])
+        .unwrap_err();
+    assert_eq!(
+        ContractError::GrantMissing {
+            granter: "bot".to_string(),
+            grantee: "user".to_string(),
+            permission: "create_order".to_string(),
+        },
+        err.downcast().unwrap()
+    );
+
+    let (msg, funds) = create_order("user", 2);
+    let resp = app
+        .execute_contract(Addr::unchecked("bot"), addr.clone(), &msg, &funds)
+        .unwrap();
+    let order_id = get_order_id_from_events(&resp.events).unwrap();
+
+    let order: GetSpotOrderResp = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetSpotOrder { order_id })
+        .unwrap();
+    assert_eq!(order.order.owner_address, Addr::unchecked("user"));
+
+    let (msg, funds) = create_order("user", 4);
+    let err = app
+        .execute_contract(Addr::unchecked("bot"), addr.clone(), &msg, &funds)
+        .unwrap_err();
+    assert_eq!(
+        ContractError::SpendLimitExceeded {
+            grantee: "bot".to_string(),
+            amount: coin(4, "btc"),
+        },
+        err.downcast().unwrap()
+    );
+
+    let grants: GetGrantsResp = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetGrants {
+                granter: "user".to_string(),
+                pagination: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(grants.grants[0].spend_limit, coins(3, "btc"));
+
+    let err = app
+        .execute_contract(
+            Addr::unchecked("bot"),
+            addr.clone(),
+            &ExecuteMsg::OnBehalfOf {
+                granter: "user".to_string(),
+                msg: Box::new(ExecuteMsg::PerpetualUpdateStopLoss {
+                    id: 1,
+                    price: SignedDecimal::one(),
+                }),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        ContractError::GrantMissing {
+            granter: "user".to_string(),
+            grantee: "bot".to_string(),
+            permission: "amend_order".to_string(),
+        },
+        err.downcast().unwrap()
+    );
+
+    app.execute_contract(
+        Addr::unchecked("bot"),
+        addr.clone(),
+        &ExecuteMsg::OnBehalfOf {
+            granter: "user".to_string(),
+            msg: Box::new(ExecuteMsg::CancelSpotOrder { order_id }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        app.wrap().query_balance("user", "btc").unwrap(),
+        coin(2, "btc")
+    );
+    assert_eq!(
+        app.wrap().query_balance("bot", "btc").unwrap(),
+        coin(8, "btc")
+    );
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::Revoke {
+            grantee: "bot".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let (msg, funds) = create_order("user", 1);
+    app.execute_contract(Addr::unchecked("bot"), addr.clone(), &msg, &funds)
+        .unwrap_err();
+}
@@ -0,0 +1,84 @@
+use elys_bindings::trade_shield::msg::query_resp::GetTradingStatsResp;
+
+use super::*;
+use get_order_id_from_events::get_order_id_from_events;
+
+// This test case verifies that an order cancelled by its owner is counted in
+// the trading stats without being counted as executed or failed.
+#[test]
+fn cancelled_orders() {
+    let wallets = vec![("user", coins(150, "eth"))];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("eth", Decimal::from_str("2040.0").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    let resp = app
+        .execute_contract(
+            Addr::unchecked("user"),
+            addr.clone(),
+            &ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::StopLoss,
+                order_price: Some(OrderPrice {
+                    rate: Decimal::from_atomics(Uint128::new(18), 0).unwrap(),
+                    base_denom: "eth".to_string(),
+                    quote_denom: "btc".to_string(),
+                }),
+                order_source_denom: "eth".to_string(),
+                order_target_denom: "btc".to_string(),
+                routes: None,
+                trigger_price: None,
+            },
+            &coins(45, "eth"),
+        )
+        .unwrap();
+    let id = get_order_id_from_events(&resp.events).unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CancelSpotOrder { order_id: id },
+        &[],
+    )
+    .unwrap();
+
+    let GetTradingStatsResp { total, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetTradingStats {
+                scope: TradingStatsScope::User {
+                    address: "user".to_string(),
+                },
+                from_day: None,
+                to_day: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(total.cancelled_orders, 1);
+    assert_eq!(total.executed_orders, 0);
+    assert_eq!(total.failed_orders, 0);
+    assert_eq!(total.volume_usd, Decimal::zero());
+}
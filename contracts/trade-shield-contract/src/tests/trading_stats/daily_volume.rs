@@ -0,0 +1,145 @@
+use cosmwasm_std::SignedDecimal;
+use elys_bindings::trade_shield::msg::query_resp::{GetTopTradersResp, GetTradingStatsResp};
+
+use super::*;
+
+// This test case verifies that executed orders are recorded in the daily
+// trading stats of their owner, of their pair and globally.
+// - "user-a" sells 2 BTC and "user-b" sells 1 BTC at 30,000 USDC.
+// - The global stats hold 90,000 USD of volume over two market orders.
+#[test]
+fn daily_volume() {
+    let wallets = vec![
+        ("user-a", coins(2_000_000, "btc")),
+        ("user-b", coins(1_000_000, "btc")),
+    ];
+    let mut app = ElysApp::new_with_wallets(wallets);
+
+    let prices = vec![
+        Price::new("btc", Decimal::from_str("30000.0").unwrap()),
+        Price::new("usdc", Decimal::from_str("1.0").unwrap()),
+        Price::new(
+            "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65",
+            Decimal::from_str("1.0").unwrap(),
+        ),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    for (user, amount) in [("user-a", 2_000_000), ("user-b", 1_000_000)] {
+        app.execute_contract(
+            Addr::unchecked(user),
+            addr.clone(),
+            &ExecuteMsg::CreateSpotOrder {
+                order_type: SpotOrderType::MarketBuy,
+                order_price: None,
+                order_source_denom: "btc".to_string(),
+                order_target_denom: "usdc".to_string(),
+                routes: None,
+                trigger_price: None,
+            },
+            &coins(amount, "btc"),
+        )
+        .unwrap();
+    }
+
+    let day = app.block_info().time.seconds() / SECONDS_PER_DAY;
+
+    let GetTradingStatsResp { total, daily } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetTradingStats {
+                scope: TradingStatsScope::Global,
+                from_day: None,
+                to_day: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(daily.len(), 1);
+    assert_eq!(daily[0].day, day);
+    assert_eq!(total.volume_usd, Decimal::from_str("90000").unwrap());
+    assert_eq!(total.executed_orders, 2);
+    assert_eq!(total.failed_orders, 0);
+    assert_eq!(
+        total.orders_by_type,
+        vec![OrderTypeCount {
+            order_type: SpotOrderType::MarketBuy.to_string(),
+            count: 2,
+        }]
+    );
+    // the mocked swaps are filled at the oracle price
+    assert_eq!(total.average_slippage(), Some(SignedDecimal::zero()));
+
+    let GetTradingStatsResp { total, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetTradingStats {
+                scope: TradingStatsScope::User {
+                    address: "user-b".to_string(),
+                },
+                from_day: Some(day),
+                to_day: Some(day),
+            },
+        )
+        .unwrap();
+    assert_eq!(total.volume_usd, Decimal::from_str("30000").unwrap());
+    assert_eq!(total.executed_orders, 1);
+
+    let GetTradingStatsResp { total, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetTradingStats {
+                scope: TradingStatsScope::Pair {
+                    pair: "btc/usdc".to_string(),
+                },
+                from_day: None,
+                to_day: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(total.executed_orders, 2);
+
+    // Nothing is recorded before the day of the orders.
+    let GetTradingStatsResp { total, daily } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetTradingStats {
+                scope: TradingStatsScope::Global,
+                from_day: None,
+                to_day: Some(day - 1),
+            },
+        )
+        .unwrap();
+    assert!(daily.is_empty());
+    assert_eq!(total, TradingStats::default());
+
+    let GetTopTradersResp { traders } = app
+        .wrap()
+        .query_wasm_smart(&addr, &QueryMsg::GetTopTraders { day, limit: 1 })
+        .unwrap();
+    assert_eq!(traders.len(), 1);
+    assert_eq!(traders[0].address, "user-a");
+    assert_eq!(traders[0].volume_usd, Decimal::from_str("60000").unwrap());
+}
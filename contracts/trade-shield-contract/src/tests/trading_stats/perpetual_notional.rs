@@ -0,0 +1,74 @@
+use cosmwasm_std::{Int64, SignedDecimal};
+use elys_bindings::trade_shield::msg::query_resp::GetTradingStatsResp;
+
+use super::*;
+
+// This test case verifies that an executed perpetual order is recorded at the
+// notional of its position rather than at its collateral.
+// - "user" opens a 2x long with 10 BTC of collateral, BTC is worth 3 USDC.
+// - The position size is 20 BTC, so 60 USD of volume is recorded.
+#[test]
+fn perpetual_notional() {
+    let wallet = vec![("user", coins(10_000_000, "btc"))];
+    let mut app = ElysApp::new_with_wallets(wallet);
+
+    let prices = vec![
+        Price::new(
+            "ibc/2180E84E20F5679FCC760D8C165B60F42065DEF7F46A72B447CFF1B7DC6C0A65",
+            Decimal::one(),
+        ),
+        Price::new("btc", Decimal::from_str("3").unwrap()),
+    ];
+    app.init_modules(|router, _, store| router.custom.set_prices(store, &prices))
+        .unwrap();
+
+    let code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+    let code_id = app.store_code(Box::new(code));
+
+    let addr = app
+        .instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMockMsg {
+                spot_orders: vec![],
+                perpetual_orders: vec![],
+            },
+            &[],
+            "Contract",
+            None,
+        )
+        .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked("user"),
+        addr.clone(),
+        &ExecuteMsg::CreatePerpetualOrder {
+            position: Some(PerpetualPosition::Long),
+            leverage: Some(SignedDecimal::from_atomics(Int64::new(2), 0).unwrap()),
+            trading_asset: Some("btc".to_string()),
+            take_profit_price: None,
+            stop_loss_price: None,
+            order_type: PerpetualOrderType::MarketOpen,
+            trigger_price: None,
+            position_id: None,
+        },
+        &coins(10_000_000, "btc"),
+    )
+    .unwrap();
+
+    let GetTradingStatsResp { total, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            &addr,
+            &QueryMsg::GetTradingStats {
+                scope: TradingStatsScope::User {
+                    address: "user".to_string(),
+                },
+                from_day: None,
+                to_day: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(total.volume_usd, Decimal::from_str("60").unwrap());
+    assert_eq!(total.executed_orders, 1);
+}